@@ -1,7 +1,8 @@
 #![warn(clippy::all)]
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use zkp_elliptic_curve_crypto::{private_to_public, sign, verify};
-use zkp_macros_decl::u256h;
+use zkp_elliptic_curve_crypto::{pedersen, private_to_public, sign, verify};
+use zkp_macros_decl::{field_element, u256h};
+use zkp_primefield::FieldElement;
 use zkp_u256::U256;
 
 fn ecdsa_sign(crit: &mut Criterion) {
@@ -22,9 +23,18 @@ fn ecdsa_verify(crit: &mut Criterion) {
     });
 }
 
+fn pedersen_hash(crit: &mut Criterion) {
+    let left = field_element!("03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb");
+    let right = field_element!("0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a");
+    crit.bench_function("Pedersen hash", move |bench| {
+        bench.iter(|| black_box(pedersen::hash(&left, &right)))
+    });
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     ecdsa_sign(c);
     ecdsa_verify(c);
+    pedersen_hash(c);
 }
 
 criterion_group!(benches, criterion_benchmark);
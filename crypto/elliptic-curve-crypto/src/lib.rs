@@ -50,6 +50,8 @@ use zkp_u256::U256;
 #[cfg(not(feature = "std"))]
 extern crate no_std_compat as std;
 
+pub mod pedersen;
+
 lazy_static! {
     static ref GENERATOR_TABLE: [Affine; 32] = {
         let mut naf = <[Affine; 32]>::default();
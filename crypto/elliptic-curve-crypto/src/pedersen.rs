@@ -0,0 +1,179 @@
+//! Pedersen hash on the Stark curve.
+//!
+//! ```text
+//! H(a, b) = [P_0 + a_low P_1 + a_high P_2 + b_low P_3 + b_high P_4].x
+//! ```
+//!
+//! where `a_low` are the 248 least significant bits of `a` and `a_high` the
+//! remaining four. The points are those of Starkware's reference
+//! implementation, so the hashes match the ones used in Cairo programs.
+use lazy_static::*;
+use std::prelude::v1::*;
+use zkp_elliptic_curve::{base_mul, window_table_affine, Affine, Jacobian};
+use zkp_macros_decl::field_element;
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+/// Number of bits in the low part of each input.
+const LOW_BITS: usize = 248;
+
+/// The constant offset `P_0` of the hash.
+pub const SHIFT_POINT: Affine = Affine::Point {
+    x: field_element!("049ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804"),
+    y: field_element!("03ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a"),
+};
+
+/// The points `P_1` to `P_4` multiplying the low and high parts of the
+/// inputs.
+pub const POINTS: [Affine; 4] = [
+    Affine::Point {
+        x: field_element!("0234287dcbaffe7f969c748655fca9e58fa8120b6d56eb0c1080d17957ebe47b"),
+        y: field_element!("03b056f100f96fb21e889527d41f4e39940135dd7a6c94cc6ed0268ee89e5615"),
+    },
+    Affine::Point {
+        x: field_element!("04fa56f376c83db33f9dab2656558f3399099ec1de5e3018b7a6932dba8aa378"),
+        y: field_element!("03fa0984c931c9e38113e0c0e47e4401562761f92a7a23b45168f4e80ff5b54d"),
+    },
+    Affine::Point {
+        x: field_element!("04ba4cc166be8dec764910f75b45f74b40c690c74709e90f3aa372f0bd2d6997"),
+        y: field_element!("0040301cf5c1751f4b971e46c4ede85fcac5c59a5ce5ae7c48151f27b24b219c"),
+    },
+    Affine::Point {
+        x: field_element!("054302dcb0e6cc1c6e44cca8f61a63bb2ca65048d53fb325d36ff12c49a58202"),
+        y: field_element!("01b77b3e37d13504b348046268d8ae25ce98ad783c25561a879dcc77e99c2426"),
+    },
+];
+
+lazy_static! {
+    // Odd multiples of the low points for `base_mul`
+    static ref LOW_TABLES: [[Affine; 32]; 2] = {
+        let mut tables = <[[Affine; 32]; 2]>::default();
+        window_table_affine(&POINTS[0], &mut tables[0]);
+        window_table_affine(&POINTS[2], &mut tables[1]);
+        tables
+    };
+
+    // All multiples of the high points, since they only take four bit scalars
+    static ref HIGH_TABLES: [[Affine; 16]; 2] = {
+        let mut tables = <[[Affine; 16]; 2]>::default();
+        for (table, point) in tables.iter_mut().zip(&[&POINTS[1], &POINTS[3]]) {
+            for i in 1..16 {
+                table[i] = &table[i - 1] + *point;
+            }
+        }
+        tables
+    };
+}
+
+/// Pedersen hash of two field elements.
+///
+/// # Panics
+///
+/// Panics if the sum is the point at infinity, which happens with negligible
+/// probability.
+pub fn hash(left: &FieldElement, right: &FieldElement) -> FieldElement {
+    let mut point = Jacobian::from(&SHIFT_POINT);
+    for (i, element) in [left, right].iter().enumerate() {
+        let value = U256::from(*element);
+        let high = value.clone() >> LOW_BITS;
+        let low = value - (high.clone() << LOW_BITS);
+        point += &base_mul(&LOW_TABLES[i][..], low);
+        #[allow(clippy::cast_possible_truncation)]
+        let high = high.c0 as usize;
+        point += &HIGH_TABLES[i][high];
+    }
+    match Affine::from(&point) {
+        Affine::Zero => panic!("Pedersen hash is the point at infinity"),
+        Affine::Point { x, .. } => x,
+    }
+}
+
+/// Pedersen hash of a sequence of field elements.
+///
+/// Hashes the elements into an accumulator starting at zero, followed by the
+/// number of elements. This is `compute_hash_on_elements` in the reference
+/// implementation.
+pub fn hash_many(elements: &[FieldElement]) -> FieldElement {
+    let result = elements
+        .iter()
+        .fold(FieldElement::ZERO, |result, element| hash(&result, element));
+    hash(&result, &FieldElement::from(elements.len()))
+}
+
+// Quickcheck needs pass by value
+#[allow(clippy::needless_pass_by_value)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_hash() {
+        // Test vectors from StarkWare's reference implementation
+        assert_eq!(
+            hash(
+                &field_element!("03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb"),
+                &field_element!("0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a")
+            ),
+            field_element!("030e480bed5fe53fa909cc0f8c4d99b8f9f2c016be4c41e13a4848797979c662")
+        );
+        assert_eq!(
+            hash(
+                &field_element!("058f580910a6ca59b28927c08fe6c43e2e303ca384badc365795fc645d479d45"),
+                &field_element!("078734f65a067be9bdb39de18434d71e79f7b6466a4b66bbd979ab9e7515fe0b")
+            ),
+            field_element!("068cc0b76cddd1dd4ed2301ada9b7c872b23875d5ff837b3a87993e0d9996b87")
+        );
+        let minus_one = FieldElement::ZERO - FieldElement::ONE;
+        assert_eq!(
+            hash(&FieldElement::ZERO, &FieldElement::ZERO),
+            field_element!("049ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804")
+        );
+        assert_eq!(
+            hash(&minus_one, &minus_one),
+            field_element!("07258fccaf3371fad51b117471d9d888a1786c5694c3e6099160477b593a576e")
+        );
+    }
+
+    #[test]
+    fn test_hash_many() {
+        assert_eq!(
+            hash_many(&[]),
+            hash(&FieldElement::ZERO, &FieldElement::ZERO)
+        );
+        assert_eq!(
+            hash_many(&[
+                FieldElement::from(1),
+                FieldElement::from(2),
+                FieldElement::from(3)
+            ]),
+            field_element!("00f9d95fbf356fbeda26538c92f7040abe51bf142350f73c9ee5ba7c660bae71")
+        );
+    }
+
+    #[quickcheck]
+    fn test_hash_reference(left: FieldElement, right: FieldElement) -> bool {
+        // Straightforward double-and-add over the bits of the inputs
+        let mut expected = Jacobian::from(&SHIFT_POINT);
+        for (element, low_point, high_point) in &[
+            (&left, &POINTS[0], &POINTS[1]),
+            (&right, &POINTS[2], &POINTS[3]),
+        ] {
+            let value = U256::from(*element);
+            let mut point = (*low_point).clone();
+            for i in 0..256 {
+                if i == LOW_BITS {
+                    point = (*high_point).clone();
+                }
+                if value.bit(i) {
+                    expected += &point;
+                }
+                point.double_assign();
+            }
+        }
+        match Affine::from(&expected) {
+            Affine::Zero => false,
+            Affine::Point { x, .. } => hash(&left, &right) == x,
+        }
+    }
+}
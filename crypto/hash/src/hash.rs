@@ -1,10 +1,6 @@
-#[cfg(feature = "std")]
-use hex;
-
-#[cfg(feature = "std")]
 use std::fmt;
 
-#[derive(Clone, Default, PartialEq, Eq)]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Hash([u8; 32]);
 
 impl Hash {
@@ -17,9 +13,18 @@ impl Hash {
     }
 }
 
-#[cfg(feature = "std")]
+impl fmt::Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Debug for Hash {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Hash(0x{:})", hex::encode(self.0))
+        write!(f, "Hash({})", self)
     }
 }
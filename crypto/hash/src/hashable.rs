@@ -1,10 +1,19 @@
-use crate::{hash::Hash, masked_keccak::MaskedKeccak};
+use crate::{
+    hash::Hash,
+    hasher::{HashFunction, Hasher},
+};
 use std::prelude::v1::*;
 use zkp_primefield::FieldElement;
 use zkp_u256::U256;
 
 pub trait Hashable {
     fn hash(&self) -> Hash;
+
+    /// Hash using the given hash function.
+    ///
+    /// Values that are hashed as-is can forward to [`Hashable::hash`], all
+    /// others must use `hash_function`.
+    fn hash_with(&self, hash_function: HashFunction) -> Hash;
 }
 
 impl Hashable for Hash {
@@ -14,6 +23,10 @@ impl Hashable for Hash {
         // `[Hash]::hash().`
         self.clone()
     }
+
+    fn hash_with(&self, _hash_function: HashFunction) -> Hash {
+        self.hash()
+    }
 }
 
 impl Hashable for U256 {
@@ -22,6 +35,10 @@ impl Hashable for U256 {
         // OPT: Figure out a way to get in-place access.
         Hash::new(self.to_bytes_be())
     }
+
+    fn hash_with(&self, _hash_function: HashFunction) -> Hash {
+        self.hash()
+    }
 }
 
 impl Hashable for FieldElement {
@@ -29,24 +46,36 @@ impl Hashable for FieldElement {
         // We hash as U256 in Montgomery form (which is identity-hashed)
         self.as_montgomery().hash()
     }
+
+    fn hash_with(&self, _hash_function: HashFunction) -> Hash {
+        self.hash()
+    }
 }
 
 impl<T: Hashable> Hashable for &T {
     fn hash(&self) -> Hash {
         (*self).hash()
     }
+
+    fn hash_with(&self, hash_function: HashFunction) -> Hash {
+        (*self).hash_with(hash_function)
+    }
 }
 
 impl<T: Hashable> Hashable for &[T] {
     fn hash(&self) -> Hash {
+        self.hash_with(HashFunction::default())
+    }
+
+    fn hash_with(&self, hash_function: HashFunction) -> Hash {
         if self.len() == 1 {
             // For a single element, return its hash.
-            self[0].hash()
+            self[0].hash_with(hash_function)
         } else {
             // Concatenate the element hashes and hash the result.
-            let mut hasher = MaskedKeccak::new();
+            let mut hasher = hash_function.hasher();
             for value in self.iter() {
-                hasher.update(value.hash().as_bytes());
+                hasher.update(value.hash_with(hash_function).as_bytes());
             }
             hasher.hash()
         }
@@ -57,4 +86,8 @@ impl<T: Hashable> Hashable for Vec<T> {
     fn hash(&self) -> Hash {
         self.as_slice().hash()
     }
+
+    fn hash_with(&self, hash_function: HashFunction) -> Hash {
+        self.as_slice().hash_with(hash_function)
+    }
 }
@@ -0,0 +1,295 @@
+use crate::{hash::Hash, poseidon::Poseidon};
+use sha2::Digest;
+use std::{fmt, prelude::v1::*};
+use tiny_keccak::Keccak;
+use zkp_elliptic_curve_crypto::pedersen;
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+/// A cryptographic hash function with a 256 bit output.
+pub trait Hasher: Sized {
+    /// Number of bytes kept by [`Hasher::hash`].
+    ///
+    /// The remaining bytes are set to zero, giving 160 bit hashes that are
+    /// cheaper to store and compare in verifiers.
+    const MASK_LENGTH: usize = 20;
+
+    fn update(&mut self, input: &[u8]);
+
+    /// The full 256 bit digest.
+    fn digest(self) -> [u8; 32];
+
+    /// The digest truncated to [`Hasher::MASK_LENGTH`] bytes.
+    fn hash(self) -> Hash {
+        let mut result = self.digest();
+        for byte in &mut result[Self::MASK_LENGTH..] {
+            *byte = 0;
+        }
+        Hash::new(result)
+    }
+}
+
+#[derive(Clone)]
+pub struct Keccak256(Keccak);
+
+#[derive(Clone, Default)]
+pub struct Sha256(sha2::Sha256);
+
+#[derive(Clone)]
+pub struct Blake2s256(blake2s_simd::State);
+
+/// The Pedersen hash on the Stark curve.
+///
+/// Input bytes are packed into field elements as for [`Poseidon`] and hashed
+/// using [`pedersen::hash_many`]. The output is not masked.
+#[derive(Clone, Default)]
+pub struct Pedersen {
+    buffer: Vec<u8>,
+}
+
+/// Packs bytes into field elements 31 bytes at a time, followed by the number
+/// of bytes.
+pub(crate) fn pack_bytes(bytes: &[u8]) -> Vec<FieldElement> {
+    let mut elements: Vec<FieldElement> = bytes
+        .chunks(31)
+        .map(|chunk| {
+            let mut padded = [0_u8; 32];
+            padded[32 - chunk.len()..].copy_from_slice(chunk);
+            FieldElement::from(U256::from_bytes_be(&padded))
+        })
+        .collect();
+    elements.push(FieldElement::from(bytes.len()));
+    elements
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Self(Keccak::new_keccak256())
+    }
+}
+
+impl Hasher for Keccak256 {
+    fn update(&mut self, input: &[u8]) {
+        self.0.update(input)
+    }
+
+    fn digest(self) -> [u8; 32] {
+        let mut result = [0; 32];
+        self.0.finalize(&mut result);
+        result
+    }
+}
+
+impl Hasher for Sha256 {
+    fn update(&mut self, input: &[u8]) {
+        self.0.input(input)
+    }
+
+    fn digest(self) -> [u8; 32] {
+        let mut result = [0; 32];
+        result.copy_from_slice(&self.0.result());
+        result
+    }
+}
+
+impl Default for Blake2s256 {
+    fn default() -> Self {
+        Self(blake2s_simd::State::new())
+    }
+}
+
+impl Hasher for Blake2s256 {
+    fn update(&mut self, input: &[u8]) {
+        let _ = self.0.update(input);
+    }
+
+    fn digest(self) -> [u8; 32] {
+        *self.0.finalize().as_array()
+    }
+}
+
+impl Hasher for Pedersen {
+    fn update(&mut self, input: &[u8]) {
+        self.buffer.extend_from_slice(input);
+    }
+
+    fn digest(self) -> [u8; 32] {
+        let elements = pack_bytes(&self.buffer);
+        U256::from(pedersen::hash_many(&elements)).to_bytes_be()
+    }
+
+    fn hash(self) -> Hash {
+        Hash::new(self.digest())
+    }
+}
+
+/// Selects a [`Hasher`] at runtime.
+///
+/// Proofs record the hash function they use, so it can be chosen to suit the
+/// verifier. Keccak-256 is cheapest on Ethereum, while other chains offer
+/// cheap SHA-256 or Blake2s. [`Poseidon`] is cheap to verify inside another
+/// Stark proof and [`Pedersen`] is the hash used in Cairo programs.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum HashFunction {
+    Keccak256,
+    Sha256,
+    Blake2s256,
+    Poseidon,
+    Pedersen,
+}
+
+impl HashFunction {
+    pub fn hasher(self) -> AnyHasher {
+        match self {
+            Self::Keccak256 => AnyHasher::Keccak256(Keccak256::default()),
+            Self::Sha256 => AnyHasher::Sha256(Sha256::default()),
+            Self::Blake2s256 => AnyHasher::Blake2s256(Blake2s256::default()),
+            Self::Poseidon => AnyHasher::Poseidon(Poseidon::default()),
+            Self::Pedersen => AnyHasher::Pedersen(Pedersen::default()),
+        }
+    }
+}
+
+impl Default for HashFunction {
+    fn default() -> Self {
+        Self::Keccak256
+    }
+}
+
+impl fmt::Display for HashFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Use `pad` so that width and alignment flags are respected.
+        match *self {
+            Self::Keccak256 => f.pad("Keccak-256"),
+            Self::Sha256 => f.pad("SHA-256"),
+            Self::Blake2s256 => f.pad("Blake2s-256"),
+            Self::Poseidon => f.pad("Poseidon"),
+            Self::Pedersen => f.pad("Pedersen"),
+        }
+    }
+}
+
+/// The [`Hasher`] for a [`HashFunction`].
+#[derive(Clone)]
+pub enum AnyHasher {
+    Keccak256(Keccak256),
+    Sha256(Sha256),
+    Blake2s256(Blake2s256),
+    Poseidon(Poseidon),
+    Pedersen(Pedersen),
+}
+
+impl Hasher for AnyHasher {
+    fn update(&mut self, input: &[u8]) {
+        match self {
+            Self::Keccak256(hasher) => hasher.update(input),
+            Self::Sha256(hasher) => hasher.update(input),
+            Self::Blake2s256(hasher) => hasher.update(input),
+            Self::Poseidon(hasher) => hasher.update(input),
+            Self::Pedersen(hasher) => hasher.update(input),
+        }
+    }
+
+    fn digest(self) -> [u8; 32] {
+        match self {
+            Self::Keccak256(hasher) => hasher.digest(),
+            Self::Sha256(hasher) => hasher.digest(),
+            Self::Blake2s256(hasher) => hasher.digest(),
+            Self::Poseidon(hasher) => hasher.digest(),
+            Self::Pedersen(hasher) => hasher.digest(),
+        }
+    }
+
+    fn hash(self) -> Hash {
+        match self {
+            Self::Keccak256(hasher) => hasher.hash(),
+            Self::Sha256(hasher) => hasher.hash(),
+            Self::Blake2s256(hasher) => hasher.hash(),
+            Self::Poseidon(hasher) => hasher.hash(),
+            Self::Pedersen(hasher) => hasher.hash(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Keccak256 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Keccak256(...)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Sha256 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Sha256(...)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Blake2s256 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Blake2s256(...)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Pedersen {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Pedersen(...)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for AnyHasher {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "AnyHasher(...)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkp_macros_decl::hex;
+
+    fn digest(hash_function: HashFunction, input: &[u8]) -> [u8; 32] {
+        let mut hasher = hash_function.hasher();
+        hasher.update(input);
+        hasher.digest()
+    }
+
+    #[test]
+    fn test_known_digests() {
+        assert_eq!(
+            digest(HashFunction::Keccak256, b"abc"),
+            hex!("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+        );
+        assert_eq!(
+            digest(HashFunction::Sha256, b"abc"),
+            hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(
+            digest(HashFunction::Blake2s256, b"abc"),
+            hex!("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982")
+        );
+        assert_eq!(
+            digest(HashFunction::Pedersen, b"abc"),
+            hex!("0022908f7e71efc7319a4b47a4f984515995d929048f4ad353f0c585e2a1e64d")
+        );
+    }
+
+    #[test]
+    fn test_masked() {
+        for &hash_function in &[
+            HashFunction::Keccak256,
+            HashFunction::Sha256,
+            HashFunction::Blake2s256,
+        ] {
+            let mut hasher = hash_function.hasher();
+            hasher.update(b"abc");
+            let hash = hasher.hash();
+            assert_eq!(hash.as_bytes()[..20], digest(hash_function, b"abc")[..20]);
+            assert_eq!(hash.as_bytes()[20..], [0; 12]);
+        }
+    }
+}
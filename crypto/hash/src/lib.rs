@@ -44,6 +44,14 @@ extern crate no_std_compat as std;
 
 mod hash;
 mod hashable;
+mod hasher;
 mod masked_keccak;
+mod poseidon;
 
-pub use crate::{hash::Hash, hashable::Hashable, masked_keccak::MaskedKeccak};
+pub use crate::{
+    hash::Hash,
+    hashable::Hashable,
+    hasher::{AnyHasher, Blake2s256, HashFunction, Hasher, Keccak256, Pedersen, Sha256},
+    masked_keccak::MaskedKeccak,
+    poseidon::Poseidon,
+};
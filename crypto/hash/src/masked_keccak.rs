@@ -1,13 +1,15 @@
-use crate::hash::Hash;
-use tiny_keccak::Keccak;
+use crate::{
+    hash::Hash,
+    hasher::{Hasher, Keccak256},
+};
 
-pub struct MaskedKeccak(Keccak);
+/// Keccak-256 truncated to 160 bits, see [`Hasher::hash`].
+#[derive(Clone, Default)]
+pub struct MaskedKeccak(Keccak256);
 
 impl MaskedKeccak {
-    const MASK_LENGTH: usize = 20;
-
     pub fn new() -> Self {
-        Self(Keccak::new_keccak256())
+        Self::default()
     }
 
     pub fn update(&mut self, input: &[u8]) {
@@ -15,18 +17,7 @@ impl MaskedKeccak {
     }
 
     pub fn hash(self) -> Hash {
-        let mut result: [u8; 32] = [0; 32];
-        self.0.finalize(&mut result);
-        for byte in result[Self::MASK_LENGTH..].iter_mut() {
-            *byte = 0;
-        }
-        Hash::new(result)
-    }
-}
-
-impl Default for MaskedKeccak {
-    fn default() -> Self {
-        Self::new()
+        self.0.hash()
     }
 }
 
@@ -0,0 +1,646 @@
+use crate::{
+    hash::Hash,
+    hasher::{self, Hasher},
+};
+use std::prelude::v1::*;
+use zkp_macros_decl::field_element;
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+/// Poseidon hash over the Stark field.
+///
+/// Uses the Hades permutation on a state of three field elements with the
+/// S-box `x^3`, eight full rounds and 83 partial rounds. The round constants
+/// are `SHA-256("OpenZKP Poseidon" || i) mod p` for the big-endian 64 bit
+/// index `i` of the constant.
+///
+/// Unlike the byte oriented hash functions this is cheap to prove in a Stark,
+/// see `zkp_stark::poseidon_permutation`. As a [`Hasher`] the input bytes are
+/// packed into field elements 31 bytes at a time, followed by the input length.
+/// The output is the canonical big-endian encoding of a field element and is
+/// not masked.
+#[derive(Clone, Default)]
+pub struct Poseidon {
+    buffer: Vec<u8>,
+}
+
+impl Poseidon {
+    pub const FULL_ROUNDS: usize = 8;
+    pub const PARTIAL_ROUNDS: usize = 83;
+    pub const ROUNDS: usize = Self::FULL_ROUNDS + Self::PARTIAL_ROUNDS;
+
+    /// The round constants, added to the state at the start of each round.
+    pub fn round_constants() -> &'static [[FieldElement; 3]] {
+        &ROUND_CONSTANTS
+    }
+
+    /// Whether round `round` applies the S-box to the full state, or only to
+    /// the last element.
+    pub fn is_full_round(round: usize) -> bool {
+        !(Self::FULL_ROUNDS / 2..Self::FULL_ROUNDS / 2 + Self::PARTIAL_ROUNDS).contains(&round)
+    }
+
+    /// Multiply by the MDS matrix `[[3, 1, 1], [1, -1, 1], [1, 1, -2]]`.
+    pub fn mix(state: &[FieldElement; 3]) -> [FieldElement; 3] {
+        let [a, b, c] = state;
+        let sum = a + b + c;
+        [&sum + a + a, &sum - b - b, sum - c - c - c]
+    }
+
+    pub fn permute(state: &mut [FieldElement; 3]) {
+        for (round, constants) in ROUND_CONSTANTS.iter().enumerate() {
+            for (x, constant) in state.iter_mut().zip(constants) {
+                *x += constant;
+            }
+            if Self::is_full_round(round) {
+                for x in state.iter_mut() {
+                    *x = x.square() * &*x;
+                }
+            } else {
+                state[2] = state[2].square() * &state[2];
+            }
+            *state = Self::mix(state);
+        }
+    }
+
+    /// Two to one compression, for example for Merkle tree nodes.
+    pub fn compress(left: &FieldElement, right: &FieldElement) -> FieldElement {
+        let mut state = [left.clone(), right.clone(), FieldElement::from(2)];
+        Self::permute(&mut state);
+        let [result, ..] = state;
+        result
+    }
+
+    /// [`Poseidon::compress`] on two hashes, as used for Merkle tree nodes.
+    ///
+    /// The hashes are read as big-endian integers and reduced to field
+    /// elements. The result is the canonical big-endian encoding of the
+    /// compressed element, so that it is read back unchanged.
+    pub fn compress_hashes(left: &Hash, right: &Hash) -> Hash {
+        let element = |hash: &Hash| {
+            let mut bytes = [0_u8; 32];
+            bytes.copy_from_slice(hash.as_bytes());
+            FieldElement::from(U256::from_bytes_be(&bytes))
+        };
+        let result = Self::compress(&element(left), &element(right));
+        Hash::new(U256::from(result).to_bytes_be())
+    }
+
+    /// Sponge over any number of field elements.
+    ///
+    /// The input is padded with a one and, if needed, a zero to an even length
+    /// and absorbed two elements at a time.
+    pub fn hash_elements(elements: &[FieldElement]) -> FieldElement {
+        let one = [FieldElement::ONE];
+        let padding: &[FieldElement] = if elements.len() % 2 == 0 {
+            &[FieldElement::ONE, FieldElement::ZERO]
+        } else {
+            &one
+        };
+        let mut state = [FieldElement::ZERO, FieldElement::ZERO, FieldElement::ZERO];
+        let mut input = elements.iter().chain(padding.iter());
+        while let (Some(a), Some(b)) = (input.next(), input.next()) {
+            state[0] += a;
+            state[1] += b;
+            Self::permute(&mut state);
+        }
+        let [result, ..] = state;
+        result
+    }
+}
+
+impl Hasher for Poseidon {
+    fn update(&mut self, input: &[u8]) {
+        self.buffer.extend_from_slice(input);
+    }
+
+    fn digest(self) -> [u8; 32] {
+        let elements = hasher::pack_bytes(&self.buffer);
+        U256::from(Self::hash_elements(&elements)).to_bytes_be()
+    }
+
+    fn hash(self) -> Hash {
+        Hash::new(self.digest())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Debug for Poseidon {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "Poseidon(...)")
+    }
+}
+
+const ROUND_CONSTANTS: [[FieldElement; 3]; Poseidon::ROUNDS] = [
+    [
+        field_element!("055bb55157ce2f0b0078eb78cf94c58c66f220ef41b1515811dd9b72927008a5"),
+        field_element!("052e1b2dcc2df9a32f9f5f48df4249ed84f93d37f91cef93429a55c477b4b8be"),
+        field_element!("07b24a674d2f229a15227f596b1153e71bd36075333f155f35d400f6f516df9d"),
+    ],
+    [
+        field_element!("05d0dd21a767f377c3c275b8a743adef3773b3821810959e3a072ebb01ee90a7"),
+        field_element!("02e351b5f68e82b6a2181c70545b52d03c4a654d711583b789b1139396d8bf1c"),
+        field_element!("05badca957787a0b1daf8f445ca56f0e71b3925c336d38a7a99952e22885d9d1"),
+    ],
+    [
+        field_element!("04ed935b65387b8321b98c04b137ea4c20d333fdc581b8a73d2ac0ce8594a87e"),
+        field_element!("01e2d0bf7d835e5aeb8a65bae2d80a026bc892c21f6a16f0a1a73a906d87b0be"),
+        field_element!("027252a3e11f814546550e7c8ad6f14c83d0301c38410c80a3440e234bed4ef8"),
+    ],
+    [
+        field_element!("0392212523ae2c3ebe2ff856f0e610a3df0c32d7fa3b453e8ad49678191d505e"),
+        field_element!("0161ee1b0988b3263779a3c9f2806eeb04531e8efdafeb29c765ace1ce3f6262"),
+        field_element!("000432d65b53ed7dd8bdb13d26bfb1548ef5ddadef6aad9744589b8fa0ede0ec"),
+    ],
+    [
+        field_element!("01b734a1b9ebe120a3a60fc470a4977a0790238360742ab8578a4cbdcdadc2f9"),
+        field_element!("0739bf53dc91e9f4e66268e452bc0e5639d1d75d1d6787e9c5bdaaa07bc66ee3"),
+        field_element!("057d9188209aef0b477243fa643519c6024eacdb19bac1f692b90d6b1f84aac6"),
+    ],
+    [
+        field_element!("05e690ae29650f72f6a95a5dda7f796b1f601d7df76afe37c7a166e32db03faa"),
+        field_element!("03a38c7734fe9005c6bd3af591f85230d40a8f512dfc8bb6e9d1c699aec88f88"),
+        field_element!("0135cfed7c16b82525b4b5ece42c8d68e2fca1753fa0d842a649040910600501"),
+    ],
+    [
+        field_element!("04f2301cdb02a8e109032e55b3be3bfb03811a79a9437d31c17a846fa4a37243"),
+        field_element!("04efe629ce92ad1eff62a81efb59f2de2104fca7044743135c152e137031c190"),
+        field_element!("02c49abc313fee2664ac464615fc7fd125acc0e386e07d64759ba3edd6eab49c"),
+    ],
+    [
+        field_element!("0299978956860dda5a10a0433143cb1047956d3b95ce26cf0b3c8f2bdb9a2130"),
+        field_element!("031d3cb73afa68e9e9cceff4a8db5ceaf83c89763c1f9514eb1198452fab7050"),
+        field_element!("061609c7d92a9e388aaf11aed8f8b8be815dee47d26be8487a9899723a835300"),
+    ],
+    [
+        field_element!("061e5c2a7cd43f802bb322ef07ac628ea63c88b5593e2d627fc5977a4f7d5c4f"),
+        field_element!("0224c2918aded3388144724aadd1dd8e259a579a98cac6887b715f5f6abdd2a4"),
+        field_element!("005c91535f03471d806bb051b0ce98a3ed4d04f050615e400de49f88b0903c1c"),
+    ],
+    [
+        field_element!("00d1ef162da5784618d6d2b01196ebc0555ede4cad10e2fe37eaaa47e2d54c96"),
+        field_element!("00de058d058b413747036cf58092433695dbf0b96a309aeed5c77bdb91db243e"),
+        field_element!("062673e25330dbe06967070934cb30ad78cdeba3b2a5e08ca70503d40b4fafdb"),
+    ],
+    [
+        field_element!("077279c3387e3ff392156317477093d0e4b8e732eadb431cac35f5dac47c47f0"),
+        field_element!("044ea8a401ad729f9e85889e85fb531101855c10487c8f9b7b0df4991937162e"),
+        field_element!("061e9155af1f3f72432eaf6ce1edbca0b1cac96b509ed97f6e47b1f3ebfd8e95"),
+    ],
+    [
+        field_element!("049cf00ab0526052151d3e30031384b835a2c47f02a8c9ab2375786c5f851624"),
+        field_element!("0728aba9905e2a31d214c7a541e9db1d599bd0bf747b4989e965140cf10910d9"),
+        field_element!("07af538e3e9d239943d1bac95c1faa42d5ef4aa0ca5f2119f0bf2ae79559b285"),
+    ],
+    [
+        field_element!("06b2fd5509ad37838ab92c7a474bbc0dfe6cdb2d6a0ef8b8587d77bdd3c5b324"),
+        field_element!("00ff4017e3ddcffdc142de370e1b52009343a5d68bb15a2d5114e78f563f9833"),
+        field_element!("02e3832c6e1a2b1ed44ac236674b1f8bbf1340500f85b24c25629899c18022a3"),
+    ],
+    [
+        field_element!("0747a7c555f04fd767e6a3951c795db5a69b6f0940d2353bda3b5affa8e912f6"),
+        field_element!("01afcf205d2c27fdb9f7f7d662dc715ee5d7389d7c9066b221e728179e83a651"),
+        field_element!("078759688baa0282021d71d2779ef06e4c2fd2b9de62d71d73ced29480c746ee"),
+    ],
+    [
+        field_element!("06e959c4962a56b7dca54b8d0bab385d7f677089b72adcd846bf5baca4340949"),
+        field_element!("0319d5dfc8552b1e4b95276d855542a4947c5172319786827de922632cad91b1"),
+        field_element!("02ca68cab5cb3bac7d155b340d20909d30e5318bce1b04c23a3afc9d5235a0aa"),
+    ],
+    [
+        field_element!("05642a47b3546ae6c94af6884a0f629165f6366085e473e303e37e6f71e3d919"),
+        field_element!("04949b951766af22fb694ad00d90bbbb002cede5ddb19a26885ac0322a14496b"),
+        field_element!("063dfc2f70a034d3b7b639e230b915e00fcc8c3bbb057cd9e091c7dc75389ba4"),
+    ],
+    [
+        field_element!("02b58c824fb4d8ecff897d731fbff2b3b328fdbf6ea4453b69f7dc6d809ec092"),
+        field_element!("04a8488b4f0284cfac788b1e1e89d8b5c2c5e0f975db381d823a1023b4d73502"),
+        field_element!("006a1a915d4095f608f4fce7e91d3ec8460462b60f21fba489d7a9749693fab6"),
+    ],
+    [
+        field_element!("02f3b24eacccddfd6adf06fd634ac3cc53c050821257b8efee00e80570a8b6ef"),
+        field_element!("0716933da21c8e756384567aae84b0f2b85a3342a21021328f1829414302ff46"),
+        field_element!("0323f7eecb035ea969aa41cdf5b139fba290d519b88dd7d8d30a8abcd37212d2"),
+    ],
+    [
+        field_element!("03e6de6998a1abb97408b38e510e7bfde0cc1063dac2497882e9a0bc96681e97"),
+        field_element!("00b9d8d6f85e77c718f87edac2d83cea434b3bb9e72ac109791516424cef9a14"),
+        field_element!("005a6b5d00c950e7eedce9209a16dfaa9d5866074a713fe241328d9cd7ed6a1d"),
+    ],
+    [
+        field_element!("012db112d7b905e6b3bac6bbd1fc58aff24c4d311726fb72965b7fb08b226654"),
+        field_element!("04e4e45b2ff706db10325d8081504105b8752609be11678ed090cb2e4516aa74"),
+        field_element!("02e19e36ddc36c5e0e21cd05dfb09c18ffb769411114d382c3a531b5cac0a9dd"),
+    ],
+    [
+        field_element!("05e2eb58f606216fb07a81e625a71632344968ee7ee8891c8afdbf3226645c85"),
+        field_element!("00d38d8697ade8ab025102341401d475710e002339e0e6d3283c866f5e071658"),
+        field_element!("00040f4ad29735ffd30887ad26344efce3f1e9a12833e7e4ef42099170c76578"),
+    ],
+    [
+        field_element!("01dac51158db0bd6fcbeecc3c60592bd6fb97092535d7896addbce208f467920"),
+        field_element!("0125d78b5fa6f176fcdde8968e58d810d02aab817c3b9f57663d3f7b7ac51e15"),
+        field_element!("06913ad585bc2cbaefa3d95019e98085582c0cf137399ddd8c7c7f8757ed0095"),
+    ],
+    [
+        field_element!("06c5f41af600cee6b84e81f95aec6c45ff0d9cb045ccdf477c78d17be323f5f9"),
+        field_element!("052a9150a2537029929bdd9d48b485b111ef9941c9929423a80e94dfeb4dbe0c"),
+        field_element!("04e09afbd1a34eaec3e5914228e34751936dd0c1933c1dfc93ef090403da4708"),
+    ],
+    [
+        field_element!("00e24bb3f6acee1c1b130bbe745eaf191a9078c9d4eedc0949dbad6e1a2e1e97"),
+        field_element!("06d20b7551a198fef27d93809c1472ace2d78ab1295529e8708c4fdd24ccc4d9"),
+        field_element!("00683350456adc639bcf294d8745245d7e1f82bd7fa10ad34493b4fe47c17dd1"),
+    ],
+    [
+        field_element!("00050ec6f4820c6fc6b239eb749e14d07dce733306c2965c7e0a0a5fceabf970"),
+        field_element!("0268f6ee8603e600628eabc6f058591cb8e8af554423d0d8804f83874cf3c9fe"),
+        field_element!("076432a0ab270f3f9fdd88a4a2482a68f211d304b10dd5bb2ec69afaecca58a3"),
+    ],
+    [
+        field_element!("06cc44e5e08f460cbcd8025230c76af0cf78dfcb5b24a92aa98b44d358b8d198"),
+        field_element!("043d5871283466ffbe41f584204fc1727fb26d7171e92ce063f628a377007985"),
+        field_element!("04d2bc3fa9089f7838ccd3a7217ae3e149c597402443c8d4f1b77d049b7da9b5"),
+    ],
+    [
+        field_element!("006094b2f4683eb1a6a29f433dab46199aca331838fb3d5f7210c1fc18373d0e"),
+        field_element!("04a0cf53b07195cd13d387e08b110a6f41a304dec837a0fcd9957a6e1250a8de"),
+        field_element!("0441cb41bb00cd09ebf4499b0da2465914af5501fd798666d548cfed264c3f2e"),
+    ],
+    [
+        field_element!("01518f890440f13285aadf8bbb8fef04a361fc505367ae4f11fcd6faf9f8bbc3"),
+        field_element!("07d213a508888969bb75d61db9bffff06092004e36222af440a05c18562fdd34"),
+        field_element!("06f11598741ce91a6982525e359971b948f1999668922f109aeacfdb9ae486f0"),
+    ],
+    [
+        field_element!("0079e8ec3c3b45584dfc874eee3dd97fdd01d9e6e7e3f151d8c42b0c045e7068"),
+        field_element!("0261ae90af57d604f85b47d1987f3cb7b4660a6f236df5231d7e0de32c37631f"),
+        field_element!("046c081ec80ac0ec503c713e88092c53a165df8d69ada258fbc7a7ee5b56a968"),
+    ],
+    [
+        field_element!("02a7350caccde0fcf1c1c0071f75228b7bdcaa5c089b879ad8da9b50fa0fb400"),
+        field_element!("05215d8ec77aead606cb0c4075a52721749470853b000bc5fc0bcfdb5d99209b"),
+        field_element!("04af50b3d8c830d2184522975af5fec6a10da8171f4bd3d92ed8d6005d0aacc3"),
+    ],
+    [
+        field_element!("077e29be2be383ce1f3e46b499d804a043b9f32bb7e789d30ca7d832a02a12ba"),
+        field_element!("053d2ca050822868727db518afaae6a358d2d0720d4ea7dfeb4783c2060b6202"),
+        field_element!("03a0e3272c32db918a259735b76776241c72de1943aaccc60761af247e1251e1"),
+    ],
+    [
+        field_element!("07e29c67a588626dd203fb0916b0923970dcc9bf89c2c8c9e6257a91560df31a"),
+        field_element!("02b35100753c0c8c723676860a71372f38b02634d9516c2eda50665c7bb0e8a8"),
+        field_element!("00562a6fa14a2ed70f349fa1dcf3428860fe5e39828d7f905c81ec49dbff79cb"),
+    ],
+    [
+        field_element!("04a4d9a86726591610cdc57a1affa6269d18cde958ef93c4ee26e684d61b66f8"),
+        field_element!("00003fc7d252b460bb9d9ada031bfaa551d2215e3c8f1fd7a1e9e70ca52b8c66"),
+        field_element!("04c2cff46809e37c072b791414ddf3ed26e00af4cccbfd2b22aa89c691108eb1"),
+    ],
+    [
+        field_element!("0337f516c51f61f0d8fc3a1709bec1e59bc4f96637db0d044daf5a2ed0b74be4"),
+        field_element!("010ff5de2bdc690273a8a56d73148592737362a5dcdde0a4b35f643b8eb89089"),
+        field_element!("025f5bfbba6db584b7167f5cd2a8eb7c932f9b57d03c28868a51dd7d3704152a"),
+    ],
+    [
+        field_element!("045824cbf18f2691b8b9c7f841360fcfebb8be307858f3e8084bbaff9f5ee6c7"),
+        field_element!("04aa0f97c9b25cd5d07d038d812244f00b04e62f3246c29303215507510d90c9"),
+        field_element!("071350a8320fbc28431c4bc806256259eb6f4fda8e5b7856ddde1f677fe3ea5b"),
+    ],
+    [
+        field_element!("07076044dcd83438658762707906bc36c7f83a34bc1bf2a2099e34dd08ec3b6b"),
+        field_element!("03921db865a1169556176ce52b25fcf9a443901fff793892304aadd03572d137"),
+        field_element!("045734a12449867cfced5b041ce8dc515b839f7ce9f5a3e88549aace22c73381"),
+    ],
+    [
+        field_element!("05ad3a98f7e6d3abb8dbf567dee38ea954bcd535981ce429ace96cb812f192da"),
+        field_element!("05c5b1bdc305707264a0f8b29472901bd3cfa8357fc0c2ea1c2fea12202fd547"),
+        field_element!("007cf5d63e49d4240627fc0747e70ec710a04f136522fe9580c25940fc05a2e5"),
+    ],
+    [
+        field_element!("05a541e84af5c58c72548f236f0b4817ee28a0bb6d77e9922a8dec5fdd17597b"),
+        field_element!("00d0b71c939ebb52e1c766c745953972369cd6cc8573d76e98eb8c798a75c659"),
+        field_element!("01dde5ae01c783c0a7be1e66526751e67b14c891358002c585a9c6e451741d0e"),
+    ],
+    [
+        field_element!("01f82ad60a21707b59f5cf79bcb2ec62610bd7a82f5ad551dc30bcd94fc5f1d3"),
+        field_element!("02fefe7dcfb60fa69d7c4400725f9799d996bb12734bb8d3236b48225958666c"),
+        field_element!("0516eb1b1fa9511bb5df92c1e870d53ce260d76c4a8b9d1b990660b4b4cfe65b"),
+    ],
+    [
+        field_element!("051f2ff2fb20f36a078deb98a06b61aa2739aa82fe33f97a5d8ef564538f35fd"),
+        field_element!("053779aa79ab74ade1c8f43ef4caebdb3ae8cb447852e4e65e3020e70c6ddd4d"),
+        field_element!("0387ef709abb18b4ab65f1904300d0a0e1dfe835b907072cce98bf2f5ca6069c"),
+    ],
+    [
+        field_element!("069976de47bf0a13153c433a8899802f119127cfd4bfa6a5358ca4b13750f3d6"),
+        field_element!("05d7ab90b7fb9e42c6f735928b32776d8c4071ce3362c70e289d3b5510c58678"),
+        field_element!("061c6adf6017becdde067a2e44a3c3e3f0ca5fcedd3160e7d4010a32eb725eb2"),
+    ],
+    [
+        field_element!("00eb4e62deb4799598b6dd2c29104f5566756cf99e8ac126903c49eb211bcb07"),
+        field_element!("01abf57be46b0ad8f359d8ed43d828e56d63bd2716cb16eb369264dadc86d479"),
+        field_element!("06a33151c75293bbdab9734f36422e468a43660da84f4070ca82cb7934ebdd4a"),
+    ],
+    [
+        field_element!("05c07ae4c3fb7ffcf638e045cdfe5d4dfe749d5ffa3cba87b4b72d9ce34983c8"),
+        field_element!("01c92cabb4e82495b0252d81a674be2a996552376d8932f7d8ac7636c4550a24"),
+        field_element!("04b1550ab2980e3824ddd27ad80cf5b0c2b64f87431843751920867441e47ef0"),
+    ],
+    [
+        field_element!("06b2d7aa5806199385373a2e94bd957ed630e3dacfa7ce038370c935664908a5"),
+        field_element!("0444d1ee6b556bc863bf8493242d5cf3d87d1e2f67fc73c9e2cb564ab8e1579d"),
+        field_element!("01bed147457c2ba5163da9dd38520df71d9e348fe79bd1c5b0d358e2f0da05cc"),
+    ],
+    [
+        field_element!("061a59eaa11458a32e6ff64940999568dbb6ca9ad7a68c68ea8c4da8af74f829"),
+        field_element!("02798f6d552eff50adbf364fd987723037a0b84d415d2187a694efcdfe677882"),
+        field_element!("05b22ed2a8d424b8b41890557d6cfe2c781639421772e930375724b60bbc912d"),
+    ],
+    [
+        field_element!("043bf3b35e776246000be4d28fe3eabd02a3ea447989ef05eb2adf370ce21d3d"),
+        field_element!("035fa9a59912f50704941c6e89ba8734ebb7ec18cc51d75b566087172e220e85"),
+        field_element!("03e9d1bfc55ce25257ed64b15e9c41d11195036d4614732f265ed90f6f551ddc"),
+    ],
+    [
+        field_element!("0623805177cceef88d57c5e4c9868aa4abb203f355f2e81bf34a2f5a529739a7"),
+        field_element!("023027125b378393f27b48148798c11321ebadeda95470ff08a445dda77570fa"),
+        field_element!("05a7978b30c26a06842f47b693065858ee01eb2db6112cd6ca06712b09779c75"),
+    ],
+    [
+        field_element!("039047948f10a8839cc37fd853a7e3b3ff6d132c2902754c8fc3474097f56d6e"),
+        field_element!("00c1e0ea0de2d4b694d89c1456adaa8b96b166635746bcbe0bf5ffbc75ccb81d"),
+        field_element!("07a01b5a63a3b7f10955ddc383d077b4d7cf8d85efc5d8b74ce87bbb9a21e212"),
+    ],
+    [
+        field_element!("00e27a5b75aeb6cebd44f05008a4798960b6c9bdb03d66fde9928bcb4c5df347"),
+        field_element!("0456c360d9c1c56542a2e9121b236eda5ef4c8fb0762b55c58712543dc788eb3"),
+        field_element!("031521d479575ac2a36e2efac64694988c19e68f7a10075794e932d6f89fc38d"),
+    ],
+    [
+        field_element!("079db4db6a07af037eb36d9c097c36d65bf2cd5d6cf5eff9ebe8078c9157b166"),
+        field_element!("01bb0a60f97c1a611060ac63a6150aca2bb933f81d5d19004cb76546b3701b44"),
+        field_element!("0310e982e0a19bad3ff489ba83b47773d38b77636b0e0a473ea00089fb206657"),
+    ],
+    [
+        field_element!("036df94c5cbbb6451de2b63592bdeb664b2765ed650c7331ce131c9d26598608"),
+        field_element!("05cc0b3501b33a58cb81e31996c8d2db2808741b51f80dd0d7859db409456593"),
+        field_element!("00ff8c017ab3333482fa728332058f287c2ffa025d526955ea4b0e45a8cf3391"),
+    ],
+    [
+        field_element!("05cfa0840e67b3f8b72e09b2af278b46c6ca5eb9fbb08582022ba74bd6ec286b"),
+        field_element!("017cb0014b869066e586757176325b6a107bea40d98bb1ac1a10fb8694ae73ab"),
+        field_element!("01a5b3dbdf51af07ab4a9dce45f711e67b16414c2e4f6b10fb7ed9db3dbc7adc"),
+    ],
+    [
+        field_element!("050a03567d9f486b8e94f1dc9d162c82bfb34619c28599e23500912d34bdec86"),
+        field_element!("06f3919cd1d4c661fb9c1ad5794de0989964b72ee92eef75f1c4291183e54a40"),
+        field_element!("06393cc11b7cc55b0892811297674fc24ab4bfa2c3e22d96b5f02d8521f77142"),
+    ],
+    [
+        field_element!("0740380dc6a453e34637db074c78266fb6ca97fd99b4303633c463b0c4e7a859"),
+        field_element!("000ac9681d88f76020137b260080dd2c6463d095cb90b1893170704fbbfc2df7"),
+        field_element!("0696ba46fe2aef89f5b464a29c0cb8f5ce0cbf0f5b6abf827b31d8c94a72543e"),
+    ],
+    [
+        field_element!("058f6f7b938f9b8c0e3f6c4ec24cef3413caa56539c14eb5735231458ed214f7"),
+        field_element!("04591a9417d9206f1b317ca987b7b530191c12ff147fd6b6dd2091c182cd927b"),
+        field_element!("01a9d6a59c265cf1b643023f49fc613de047cb06beeda1b123ea1550014ea5af"),
+    ],
+    [
+        field_element!("050ad2d6c3da6e872030470d7de2f69700cd75a2341defb9cc754a957f8122c2"),
+        field_element!("0423c2baf7be4a6ac5a262eb687ee265d826a18eab2d3232a9438e897936826e"),
+        field_element!("05c3f7497114efcf5b3f5b10726e376125bc3b84daf7e6b89790d37a09cf44d3"),
+    ],
+    [
+        field_element!("024066b78a2e74a5e9f3359f78dac56af9f047113ea1c4ce3f163f1aad084845"),
+        field_element!("031cc75ace2dfc8c5819217734a675997ec54a567b6e3498e90434dadfd003d0"),
+        field_element!("05fa2747ca304b4e93ed169cfc2d33fb0795339a3759ac40bf65c23bbc9fd3f7"),
+    ],
+    [
+        field_element!("043805045d8aedf007aa8f75f1c30a2a17532ee3ca1f854fd2357f90aaaec94d"),
+        field_element!("064ae4d7da664b229e53caa1c3bb4483e37b88d7711486f99872e6d4fba93f37"),
+        field_element!("0645cff116de45d590ae516c84f20fcf9dc1598e8776244c69f5b941cff3c2fb"),
+    ],
+    [
+        field_element!("01b83424c9ddfdde946a1b89faf0030ac22645f8bf61eff5cd4012bdd91e2859"),
+        field_element!("01ab46924acaba3277a2f78c47e26692069a1057c409c6745c817a1ab551407d"),
+        field_element!("04966cd5984c4c33ceff16b8ffe1c9a539883c32f24bafc07974a0b8f3b3d1a8"),
+    ],
+    [
+        field_element!("07065cf4bd53b163dbd9fcc63880ba54ff8db0280216619e904baeb5e681df27"),
+        field_element!("0671df99c5236ef35e9409b6c2399f6e4ac51d6f8a456d28cbf8bdcb9eff767a"),
+        field_element!("07431261a491be5337a798306263265d87ca25e9a07e6b8cc6bf8b9f4f189deb"),
+    ],
+    [
+        field_element!("0427da8d8d154694701a883f2d1085d638e2fd4c5ceb4b38b0f0ffaed7208bc3"),
+        field_element!("05c9aad9275d63474dbdd6ba8f1adff945f5267faed018145b7a268bd5f55824"),
+        field_element!("036f039e08d0267ba71d920522d7a515e885049ccd1bdbf6070fc2366546ffeb"),
+    ],
+    [
+        field_element!("024a0dbe22283f52e6481dec0fadec738f79610f6668f38633569a14079e622e"),
+        field_element!("07d1f51f0cc22da56417c031df12abae82b26257aad7b4c66c04ee4e4416b09c"),
+        field_element!("04d8d76cf31dad6c8301eaa16b584f86f9a319ff77abe47cdb001cb568bcc42e"),
+    ],
+    [
+        field_element!("0776b49395f283b9465bc484b45cb060592d42abb1771f02fbaf8295ba7fa6b4"),
+        field_element!("014662123bf8c18015d68f2ddf4129284a2cc19af84232deb76681da348ec5a0"),
+        field_element!("00004320e76859bbf8382aaf65c5c9d7d7555874acf5e0c89f8d5509f9b02bab"),
+    ],
+    [
+        field_element!("07377f5ce249f09044f3bf949331c41db06fc58564449e6729514c2f89bea928"),
+        field_element!("071cc6d03fde2d95b3be845e12ff7452e92a34c837b8ed46d09ea2f53e26c52e"),
+        field_element!("07da88d907305a5895bdd0869f70647abbec2d12bd2ab5d8f62708c2096376ca"),
+    ],
+    [
+        field_element!("06fafdb746d49c32b262a4cf8690ccd255bfa4af99d58a99065f9304a38f73b7"),
+        field_element!("00d82ce763deebcb6ffec33050d7d513fff513544a77fa4c12081e4cfcec5e3a"),
+        field_element!("05b6a0d4fec7601f43e074127de92eeb66f3698b92ea8180915f79b5b7bb90bc"),
+    ],
+    [
+        field_element!("065db6e5b19f735adec131dbc6886449536053878262de2392dbd45e0c193f57"),
+        field_element!("02e5b08fd049eecabb19ba1e99c24d50026fa9e9719ad1bf62240f86ffb5bd21"),
+        field_element!("064f3a9e61555333b5bd95caf3627332ad3972a392d511acfe211c2c2fd3fc85"),
+    ],
+    [
+        field_element!("07936ff421cce5ed105e2382e9fa2f357e6ef667d69d2aa9ec5ca784987845e0"),
+        field_element!("03ffaa2c108d8052540d9ed186e974934424a3aa6a8529c15806f6e7f3788dc4"),
+        field_element!("001d4268bfbb269e7f7e30b737380a5b3ef0348eba4265552fd16345e0983609"),
+    ],
+    [
+        field_element!("01942a3417738e3bc5b7fa1b01cc7bd985041f458d562759281c528a40e5df7e"),
+        field_element!("03247a18fe91ef207ef996679869ee5ae139a85eb2a3bfe95bfc923c53f3d473"),
+        field_element!("058dfc02e761305452c545c71bdbd954676743eba73e6b5766e62c1a3146c228"),
+    ],
+    [
+        field_element!("046e5aa703c8dac570bbed9c0a2052859fb2e9fca39a3665e3e8bf9fe34914b8"),
+        field_element!("058e24f26630cb6e84b43f3bb7b10febeca91e6085f0fc1d8a547eb295857244"),
+        field_element!("069ca077f92f4d7832cc33f5a4a5b035e4d30eb7d285e42bdb85f19bc222e3d6"),
+    ],
+    [
+        field_element!("031eac1eb72d06f8858940f5cbf33fe547ba4e02a38e0bc896195eef5150f746"),
+        field_element!("0133f440b0ca0723c4485a1e1d404edc1b7a2293b14d2bd4b2d8f0b0824efd74"),
+        field_element!("003bdb6769a6ccac94783c2eee7efd95cbcd360d5a93bdf4d4167f467c30fe09"),
+    ],
+    [
+        field_element!("06bfa455e7a45dc9a8463cc9bce73da121f12bd82b0d6d86882d82bf20c11bad"),
+        field_element!("061e5f7bd28311fce3efd22c93f6c29080005f68d395ec2658efefd2e67807cf"),
+        field_element!("0187d5e577dad1ad1dbd72343adac86727473af67be3f6cfa38cf6626e8c224f"),
+    ],
+    [
+        field_element!("00bf65f5cf92d0ae017ed0e94fd50ca144cffec8a9673c758ad9484c9227c761"),
+        field_element!("046dca7b475845326bb20449a73290491e550c5cfbfeb3816063b811145ad7e2"),
+        field_element!("000d6e6cee28c6cc8b46ce1dd0a163646d3eef6d49ba0670df1007cb29870492"),
+    ],
+    [
+        field_element!("0022a09a0a40e11c9d6317c799c1fb219cd62a00ab66c4c6546ad869c36b3666"),
+        field_element!("0612df4014543503cb697a7ca1e7775d2258a1b7bf24bd59e8d83309bd6eeb60"),
+        field_element!("03d020ec55c3e6090e8e84edc43e94ff1b53fbe83f8562a40c48a3b36fdfbfc3"),
+    ],
+    [
+        field_element!("007a2155f3e7c586d6b2f712399814a44db251d16d2296e972c09aa55091d063"),
+        field_element!("0785c864509739983ff78b94c4b319923c021a458d43134450b6aad892616d69"),
+        field_element!("074e31bb803fb3ee56a40a3eadc84d379dff4850effddf4fdb9f462d16d40cb4"),
+    ],
+    [
+        field_element!("05010f6e55c538b58096dc1380803418781cb88af2003a74d8ac2c08f0fb1a4c"),
+        field_element!("06de1f4a0c668099e84002e7c0853db6b596b734307aeef8b8e40128b9764004"),
+        field_element!("030984d13207b1610562725344c0bc90d696d4b9ffc5d5109c73f308a84e9b24"),
+    ],
+    [
+        field_element!("077fb8a70c38734d1ac3ea56e9689779eab8e704adbdc17d28446a82ea791280"),
+        field_element!("012c3d93043981e2d42e8b8f05007a24c121d33dd7860b559f25c5644957e44b"),
+        field_element!("061171e7d1daec92ae70d90ab3d76197718fe1c1e3f704a4356645665323c31f"),
+    ],
+    [
+        field_element!("00ebcf77fedf6a0ac52c956adf6d5ce36c48b75cf67703e871d919c0f355dfd0"),
+        field_element!("05979e9d78ea7fae533189e3ca7d8958fe110797341ac74e5831bdf17ec937be"),
+        field_element!("07f8c1fc35d7606f3814c7fbc25e3898cfeaccb0ad2133759a2da69d9cb1402f"),
+    ],
+    [
+        field_element!("04b2fd55252a5ce8e57154c6d2b2e0b45307f48d615092618adc90f55662e34f"),
+        field_element!("0595970e374001ef1a4fc9b0a6afe7ba5867b5aa72dffd27a1c772e61f7d97ab"),
+        field_element!("018d1c9976c820bff64f51b3242b745bbc3605cb7cf4639348ab021f6c8701f5"),
+    ],
+    [
+        field_element!("06ede4e08bc9b51aed7b45e700c37184a7b127f140d1fca9a4181d575fd53638"),
+        field_element!("0187b791fcb76b5397492e3e21c666d210c7532e2c5fe2508e491c2dbb4b71d1"),
+        field_element!("00b22fb7a810ba8cec4ab0f202866393f660dbd5be449a562a1c720041d2b786"),
+    ],
+    [
+        field_element!("01ac076f75a04f9a2c49bb5d7f248f8f96cdea25454ec2bca6b8fa70ae513b5b"),
+        field_element!("053f570fa00d126b0fdffa54a6dba29ae78df660e6d1b369354904a3a344b944"),
+        field_element!("054ef47efb9b1decbdeb4190a1cb80131f774f96589def5d0a6aaa49a36eeb77"),
+    ],
+    [
+        field_element!("03c16baaaa7f74551db04ca362ee072d97b13dbb6c35018763aa2f3fb6b289a9"),
+        field_element!("06bbb2d75020ea67f524ad1ef3449289b7cf33ffb57a990a8a1052ad6c37e7f9"),
+        field_element!("030faa000e666085a499f60987220d357336d316833cceb6578082d2162ca4ff"),
+    ],
+    [
+        field_element!("064fed22b86b47ecade86bd01eccf719fca5ab0a01710bc4f714b02e23873dd8"),
+        field_element!("008173921dc1fa306816162036e6cd23084952d61628b7a7aa3bf44df7a1ffe8"),
+        field_element!("051efdbeda7ee206f9e559d35d9337083fe543cdce937cdd710bc8d2910ce9fe"),
+    ],
+    [
+        field_element!("07d5ea843abd1382cf4c9f9e93c7764c5813eb32d41b16b73e27e67aa57f03e4"),
+        field_element!("0388752becd7809b5de2a579f4463b676c4f56be01c62d1c89956dfd3deae5fc"),
+        field_element!("0350fd0a570a19045a75af07c4c923da15ceb690ea71a16126c6d12518480352"),
+    ],
+    [
+        field_element!("05a9712662465b0f4df365d381e3261ca8082d99282a07e27ab27b712bac7d07"),
+        field_element!("05a83ddd86277bfe337b862707aee140b54139f9a8599276e2ec244ec08a2a61"),
+        field_element!("0556c9048c6f95556d2a3a4ebfe4728da77a56f93f0168b189fb2832fb82dfc6"),
+    ],
+    [
+        field_element!("03276ecfb90fc2f1954683367942eb2a48caddd4b18a7c5a2c25b74d1ac74ac0"),
+        field_element!("051b535060bcd5867a83e75c3ba80fa56518d39b882fe51a6838f9d714522556"),
+        field_element!("013db21697522384089a1b625dfd5061cbace78a4e11f17effd3aa545f025e34"),
+    ],
+    [
+        field_element!("029ca1a4642dd6bc9f66e877d991c57ce9c7a4f0ae1dfd126f24c9519c5fa161"),
+        field_element!("05000407d2af4a4a0225732004aa9e3de14f88dac13558acffa829be0a52671b"),
+        field_element!("023427344158960563a04c477a9b319f4a68ddee6bd4ced31869ac1dc44a3f65"),
+    ],
+    [
+        field_element!("042bcb8fc89d50f9c7864a6aff3f5683836f60a6be6fac3a6d71cf65acde5113"),
+        field_element!("00b91a1f28ed4ad1bf0e6862a9505ee41ea0b6de142cac1387e3689ea5f3ce40"),
+        field_element!("06a44b4640a9a92f6114ffc32f76b0e9690ecbb92a4d1bd782513002a80fb6a3"),
+    ],
+    [
+        field_element!("057c0d2e70ef94effccebcd10424d4717ae2c8febb5499a70077567370e04dab"),
+        field_element!("03fff567090c5b6800cf2ab9519fc169d830ebd4b2313827fbe093d9bed76de3"),
+        field_element!("02aef8bcccf83ab6615030e4fe36ec69e362961a50d41f3287c8677513258afa"),
+    ],
+    [
+        field_element!("01bc8f4192bb40efde050d00e215a1b164d7a740dd0440a7d6e682fad6362518"),
+        field_element!("03c40f0c81a755f9a9158f2d343e59c52a45265d067ede21431d28187979fad9"),
+        field_element!("00cad9319d9efc9e39c6137a3065fa88a9ba9336a1c622a86de60275f0ad44a8"),
+    ],
+    [
+        field_element!("02c726dc833f69942d6bd9b387eeb7c4547c32ef635925f37dd4f8f97ab97d2f"),
+        field_element!("007b63fca860dc41fb8ac59c0814235de844ab71d73f648f437fd7f3cf6593ce"),
+        field_element!("021591de6b58963a590bb766cd6301eb727b4b83d9514e41051f0e7979c48227"),
+    ],
+    [
+        field_element!("07c334b05b04ffbe10016ccc6a571331c887694c5b95b20bb0bf0cb2fbc462c9"),
+        field_element!("0758306db9a7bf545ce69286271632d9c90e85f60f37ed1d3864e66aed927103"),
+        field_element!("03fb7949e14c18182824d53ac86d9bb405810d339b6a79ee746994f002a4bf11"),
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permute() {
+        let mut state = [FieldElement::ZERO, FieldElement::ONE, FieldElement::from(2)];
+        Poseidon::permute(&mut state);
+        assert_eq!(state, [
+            field_element!("04fec97403fe8e50e54a81206d59c45469cdc7f159e8224f24ac6c1ebf83345d"),
+            field_element!("07a94e81422dec7ea80dbc70e11aacfbbdf07ee17dbbec7ca7743d871f88f0aa"),
+            field_element!("070b15e48429b7b0a32bd8c32a066bba7acd0e756f92500cefff66670c92db4d"),
+        ]);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            Poseidon::compress(&FieldElement::ONE, &FieldElement::from(2)),
+            field_element!("029e86d3128da5ce3a1b5944e89bf62519fb492143a1721bbae6bfefb1171385")
+        );
+        assert_eq!(
+            Poseidon::hash_elements(&[]),
+            field_element!("0025f51db0c028489b4953c7bee5aa29e571920a86eca7e9d7439319503780a0")
+        );
+        assert_eq!(
+            Poseidon::hash_elements(&[
+                FieldElement::ONE,
+                FieldElement::from(2),
+                FieldElement::from(3)
+            ]),
+            field_element!("021d773328f384b4530b46eb782bf37f08d0d7630146b0adb4b7cb635315a24f")
+        );
+        let mut hasher = Poseidon::default();
+        hasher.update(b"abc");
+        let expected = U256::from(field_element!(
+            "06d4754af157b882afa414116ebd78952b8f9dba306bb219f8c8ece08e4d2c65"
+        ))
+        .to_bytes_be();
+        assert_eq!(hasher.clone().digest(), expected);
+        // The output is not masked
+        assert_eq!(hasher.hash().as_bytes(), &expected[..]);
+    }
+
+    #[test]
+    fn test_compress_hashes() {
+        let hash = |element: &FieldElement| Hash::new(U256::from(element).to_bytes_be());
+        let left = FieldElement::from(3);
+        let right = FieldElement::from(4);
+        assert_eq!(
+            Poseidon::compress_hashes(&hash(&left), &hash(&right)),
+            hash(&Poseidon::compress(&left, &right))
+        );
+    }
+}
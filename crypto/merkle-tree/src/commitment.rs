@@ -2,13 +2,14 @@ use crate::{Error, Index, Result};
 use itertools::Itertools;
 use std::prelude::v1::*;
 use zkp_error_utils::require;
-use zkp_hash::Hash;
+use zkp_hash::{Hash, HashFunction};
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct Commitment {
-    size: usize,
-    hash: Hash,
+    size:          usize,
+    hash:          Hash,
+    hash_function: HashFunction,
 }
 
 impl Commitment {
@@ -20,9 +21,17 @@ impl Commitment {
         Ok(Self {
             size,
             hash: hash.clone(),
+            hash_function: HashFunction::default(),
         })
     }
 
+    /// Use `hash_function` for the nodes and leaves of the tree. The default
+    /// is Keccak-256.
+    pub fn with_hash_function(mut self, hash_function: HashFunction) -> Self {
+        self.hash_function = hash_function;
+        self
+    }
+
     pub fn size(&self) -> usize {
         self.size
     }
@@ -31,6 +40,10 @@ impl Commitment {
         &self.hash
     }
 
+    pub fn hash_function(&self) -> HashFunction {
+        self.hash_function
+    }
+
     /// Convert leaf indices to a sorted list of unique `Index`s and validates
     /// their range.
     pub fn sort_indices(&self, indices: &[usize]) -> Result<Vec<Index>> {
@@ -48,7 +48,6 @@ mod commitment;
 /// <https://eprint.iacr.org/2011/495.pdf>
 // TODO: Spin of to it's own crate.
 // TODO: Implement sparse Merkle trees.
-// TODO: Generalize over hash implementations.
 mod index;
 mod node;
 mod proof;
@@ -71,4 +70,4 @@ pub use tree::Tree;
 pub use vector_commitment::VectorCommitment;
 
 use index::Index;
-use node::Node;
+use node::{salted_leaf_hash, Node};
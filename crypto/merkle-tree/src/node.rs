@@ -1,4 +1,4 @@
-use zkp_hash::{Hash, Hashable, MaskedKeccak};
+use zkp_hash::{Hash, HashFunction, Hashable, Hasher, Poseidon};
 
 #[derive(Clone)]
 #[cfg_attr(feature = "std", derive(Debug))]
@@ -6,9 +6,60 @@ pub(crate) struct Node<'a>(pub(crate) &'a Hash, pub(crate) &'a Hash);
 
 impl Hashable for Node<'_> {
     fn hash(&self) -> Hash {
-        let mut hasher = MaskedKeccak::new();
+        self.hash_with(HashFunction::default())
+    }
+
+    fn hash_with(&self, hash_function: HashFunction) -> Hash {
+        // Two to one compression is cheaper to prove than the sponge, see
+        // `zkp_stark::poseidon_compress`.
+        if hash_function == HashFunction::Poseidon {
+            return Poseidon::compress_hashes(self.0, self.1);
+        }
+        let mut hasher = hash_function.hasher();
         hasher.update(self.0.as_bytes());
         hasher.update(self.1.as_bytes());
         hasher.hash()
     }
 }
+
+/// Prefix of the salted leaf hash input.
+///
+/// Internal nodes hash exactly two hashes without a prefix, so the prefix
+/// keeps a salted leaf from being presented as an internal node or vice versa.
+const SALTED_LEAF_PREFIX: u8 = 0;
+
+/// Hash of a leaf in a hiding commitment.
+///
+/// The leaf hash is combined with a random per-leaf salt, so that the leaf can
+/// not be recovered from the tree by guessing low-entropy values.
+pub(crate) fn salted_leaf_hash(leaf_hash: &Hash, salt: &Hash, hash_function: HashFunction) -> Hash {
+    let mut hasher = hash_function.hasher();
+    hasher.update(&[SALTED_LEAF_PREFIX]);
+    hasher.update(leaf_hash.as_bytes());
+    hasher.update(salt.as_bytes());
+    hasher.hash()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkp_u256::U256;
+
+    #[test]
+    fn test_salted_leaf_domain_separation() {
+        let leaf = U256::from(5_u64).hash();
+        let salt = U256::from(1000_u64).hash();
+        for &hash_function in &[
+            HashFunction::Keccak256,
+            HashFunction::Sha256,
+            HashFunction::Blake2s256,
+            HashFunction::Poseidon,
+            HashFunction::Pedersen,
+        ] {
+            assert_ne!(
+                salted_leaf_hash(&leaf, &salt, hash_function),
+                Node(&leaf, &salt).hash_with(hash_function)
+            );
+        }
+    }
+}
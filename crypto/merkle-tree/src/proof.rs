@@ -1,4 +1,4 @@
-use crate::{Commitment, Error, Index, Node, Result};
+use crate::{salted_leaf_hash, Commitment, Error, Index, Node, Result};
 use itertools::Itertools;
 use std::{collections::VecDeque, prelude::v1::*};
 use zkp_error_utils::require;
@@ -12,6 +12,7 @@ pub struct Proof {
     commitment: Commitment,
     indices:    Vec<usize>,
     hashes:     Vec<Hash>,
+    salts:      Option<Vec<Hash>>,
 }
 
 impl Proof {
@@ -30,23 +31,62 @@ impl Proof {
             commitment: commitment.clone(),
             indices:    indices.to_vec(),
             hashes:     hashes.to_vec(),
+            salts:      None,
         })
     }
 
+    /// Proof for a hiding commitment.
+    ///
+    /// The `salts` are those of the opened leaves, in order of increasing
+    /// index and without duplicates.
+    pub fn from_hashes_salts(
+        commitment: &Commitment,
+        indices: &[usize],
+        hashes: &[Hash],
+        salts: &[Hash],
+    ) -> Result<Self> {
+        require!(
+            salts.len() == commitment.sort_indices(indices)?.len(),
+            Error::SaltCountMismatch
+        );
+        let mut proof = Self::from_hashes(commitment, indices, hashes)?;
+        proof.salts = Some(salts.to_vec());
+        Ok(proof)
+    }
+
     pub fn hashes(&self) -> &[Hash] {
         &self.hashes
     }
 
+    /// The salts of the opened leaves, if this is a hiding commitment.
+    pub fn salts(&self) -> Option<&[Hash]> {
+        self.salts.as_deref()
+    }
+
     pub fn verify<Leaf: Hashable>(&self, leafs: &[(usize, Leaf)]) -> Result<()> {
+        require!(
+            self.root(leafs)? == *self.commitment.hash(),
+            Error::RootHashMismatch
+        );
+        Ok(())
+    }
+
+    /// Reconstruct the root hash from the leafs and the proof hashes.
+    ///
+    /// Unlike [`Proof::verify`] this does not compare the result with the
+    /// commitment, which allows reporting the mismatching root.
+    pub fn root<Leaf: Hashable>(&self, leafs: &[(usize, Leaf)]) -> Result<Hash> {
         // TODO: Pass leafs by reference?
         // TODO: Check if the indices line up.
 
+        let hash_function = self.commitment.hash_function();
+
         // Construct the leaf nodes
         let mut nodes = leafs
             .iter()
             .map(|(index, leaf)| {
                 (Index::from_size_offset(self.commitment.size(), *index)
-                    .map(|index| (index, leaf.hash())))
+                    .map(|index| (index, leaf.hash_with(hash_function))))
             })
             .collect::<Result<Vec<_>>>()?;
         nodes.sort_unstable_by_key(|(index, _)| *index);
@@ -59,6 +99,12 @@ impl Proof {
             Error::DuplicateLeafMismatch
         );
         nodes.dedup_by_key(|(index, _)| *index);
+        if let Some(salts) = &self.salts {
+            require!(salts.len() == nodes.len(), Error::SaltCountMismatch);
+            for ((_, hash), salt) in nodes.iter_mut().zip(salts) {
+                *hash = salted_leaf_hash(hash, salt, hash_function);
+            }
+        }
         let mut nodes: VecDeque<(Index, Hash)> = nodes.into_iter().collect();
 
         // Create a mutable closure to pop hashes from the list
@@ -76,27 +122,28 @@ impl Proof {
                         if current.sibling().unwrap() == *next {
                             // Merge left with next
                             let _ = nodes.pop_front();
-                            Node(&hash, &next_hash).hash()
+                            Node(&hash, &next_hash).hash_with(hash_function)
                         } else {
                             // Left not merged with next
                             // TODO: Find a way to merge this branch with the next.
-                            Node(&hash, pop()?).hash()
+                            Node(&hash, pop()?).hash_with(hash_function)
                         }
                     } else {
                         // Left not merged with next
-                        Node(&hash, pop()?).hash()
+                        Node(&hash, pop()?).hash_with(hash_function)
                     }
                 } else {
                     // Right not merged with previous (or we would have skipped)
-                    Node(pop()?, &hash).hash()
+                    Node(pop()?, &hash).hash_with(hash_function)
                 };
                 // Queue the new parent node for the next iteration
                 nodes.push_back((parent, node))
             } else {
                 // Root node has no parent, we are done
-                require!(hash == *self.commitment.hash(), Error::RootHashMismatch);
+                return Ok(hash);
             }
         }
-        Ok(())
+        // An empty opening is consistent with any root.
+        Ok(self.commitment.hash().clone())
     }
 }
@@ -12,6 +12,7 @@ pub enum Error {
     DuplicateLeafMismatch,
     NotEnoughHashes,
     RootHashMismatch,
+    SaltCountMismatch,
 }
 
 impl fmt::Display for Error {
@@ -25,6 +26,7 @@ impl fmt::Display for Error {
             DuplicateLeafMismatch => write!(f, "Duplicate leaf mismatch"),
             NotEnoughHashes => write!(f, "Not enough hashes to verify proof"),
             RootHashMismatch => write!(f, "Verification failed since root hashes don't match"),
+            SaltCountMismatch => write!(f, "Number of salts does not match number of leaves"),
         }
     }
 }
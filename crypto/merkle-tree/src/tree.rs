@@ -1,7 +1,7 @@
-use crate::{Commitment, Error, Index, Node, Proof, Result, VectorCommitment};
+use crate::{salted_leaf_hash, Commitment, Error, Index, Node, Proof, Result, VectorCommitment};
 use std::collections::VecDeque;
 use zkp_error_utils::require;
-use zkp_hash::{Hash, Hashable};
+use zkp_hash::{Hash, HashFunction, Hashable};
 use zkp_mmap_vec::MmapVec;
 
 #[cfg(feature = "std")]
@@ -19,18 +19,37 @@ where
     slice.iter_mut().enumerate().for_each(f);
 }
 
+// Utility function to hash a leaf, including the salt if there is one
+fn leaf_hash<C: VectorCommitment>(
+    leaves: &C,
+    salts: Option<&MmapVec<Hash>>,
+    hash_function: HashFunction,
+    offset: usize,
+) -> Hash {
+    let hash = leaves.leaf_hash_with(offset, hash_function);
+    match salts {
+        Some(salts) => salted_leaf_hash(&hash, &salts[offset], hash_function),
+        None => hash,
+    }
+}
+
 // Utility function to compute the first layer of the tree from the leaves
-fn compute<C: VectorCommitment>(leaves: &C, index: Index) -> Hash {
+fn compute<C: VectorCommitment>(
+    leaves: &C,
+    salts: Option<&MmapVec<Hash>>,
+    hash_function: HashFunction,
+    index: Index,
+) -> Hash {
     let leaf_depth = Index::depth_for_size(leaves.len());
     assert!(index.depth() <= leaf_depth);
     if index.depth() == leaf_depth {
-        leaves.leaf_hash(index.offset())
+        leaf_hash(leaves, salts, hash_function, index.offset())
     } else {
         Node(
-            &compute(leaves, index.left_child()),
-            &compute(leaves, index.right_child()),
+            &compute(leaves, salts, hash_function, index.left_child()),
+            &compute(leaves, salts, hash_function, index.right_child()),
         )
-        .hash()
+        .hash_with(hash_function)
     }
 }
 
@@ -47,6 +66,7 @@ pub struct Tree<Container: VectorCommitment> {
     commitment: Commitment,
     nodes:      MmapVec<Hash>,
     leaves:     Container,
+    salts:      Option<MmapVec<Hash>>,
 }
 
 impl<Container: VectorCommitment> Tree<Container> {
@@ -55,14 +75,47 @@ impl<Container: VectorCommitment> Tree<Container> {
     }
 
     pub fn from_leaves_skip_layers(leaves: Container, skip_layers: usize) -> Result<Self> {
+        Self::build(leaves, None, skip_layers, HashFunction::default())
+    }
+
+    /// Hiding commitment to the leaves.
+    ///
+    /// Each leaf hash is combined with the corresponding salt, which should be
+    /// uniformly random and secret. Proofs opened from this tree carry the
+    /// salts of the opened leaves, so nothing is revealed about the others.
+    pub fn from_leaves_salted(leaves: Container, salts: MmapVec<Hash>) -> Result<Self> {
+        Self::from_leaves_with(leaves, Some(salts), HashFunction::default())
+    }
+
+    /// Commitment to the leaves using `hash_function`, optionally hiding.
+    pub fn from_leaves_with(
+        leaves: Container,
+        salts: Option<MmapVec<Hash>>,
+        hash_function: HashFunction,
+    ) -> Result<Self> {
+        if let Some(salts) = &salts {
+            require!(salts.len() == leaves.len(), Error::SaltCountMismatch);
+        }
+        Self::build(leaves, salts, 1, hash_function)
+    }
+
+    fn build(
+        leaves: Container,
+        salts: Option<MmapVec<Hash>>,
+        skip_layers: usize,
+        hash_function: HashFunction,
+    ) -> Result<Self> {
         let size = leaves.len();
         if size == 0 {
             return Ok(Self {
                 // TODO: Ideally give the empty tree a unique flag value.
                 // Size zero commitment always exists
-                commitment: Commitment::from_size_hash(size, &Hash::default()).unwrap(),
+                commitment: Commitment::from_size_hash(size, &Hash::default())
+                    .unwrap()
+                    .with_hash_function(hash_function),
                 nodes: MmapVec::with_capacity(0),
                 leaves,
+                salts,
             });
         }
         // TODO: Support non power of two sizes
@@ -93,7 +146,12 @@ impl<Container: VectorCommitment> Tree<Container> {
             let leaf_layer = &mut nodes[Index::layer_range(depth)];
             // First layer
             for_each(leaf_layer, |(i, hash)| {
-                *hash = compute(&leaves, Index::from_depth_offset(depth, i).unwrap())
+                *hash = compute(
+                    &leaves,
+                    salts.as_ref(),
+                    hash_function,
+                    Index::from_depth_offset(depth, i).unwrap(),
+                )
             });
             // Upper layers
             for depth in (0..depth).rev() {
@@ -102,21 +160,24 @@ impl<Container: VectorCommitment> Tree<Container> {
                     nodes.split_at_mut(Index::from_depth_offset(depth + 1, 0).unwrap().as_index());
                 let current = &mut tree[Index::layer_range(depth)];
                 for_each(current, |(i, hash)| {
-                    *hash = Node(&previous[i << 1], &previous[i << 1 | 1]).hash()
+                    *hash = Node(&previous[i << 1], &previous[i << 1 | 1]).hash_with(hash_function)
                 });
             }
         }
 
         let root_hash = if nodes.is_empty() {
-            compute(&leaves, Index::root())
+            compute(&leaves, salts.as_ref(), hash_function, Index::root())
         } else {
             nodes[0].clone()
         };
-        let commitment = Commitment::from_size_hash(size, &root_hash).unwrap();
+        let commitment = Commitment::from_size_hash(size, &root_hash)
+            .unwrap()
+            .with_hash_function(hash_function);
         Ok(Self {
             commitment,
             nodes,
             leaves,
+            salts,
         })
     }
 
@@ -136,19 +197,29 @@ impl<Container: VectorCommitment> Tree<Container> {
         self.leaves.leaf(index)
     }
 
+    /// The salt of a leaf, if this is a hiding commitment.
+    pub fn salt(&self, index: usize) -> Option<&Hash> {
+        self.salts.as_ref().map(|salts| &salts[index])
+    }
+
     pub fn node_hash(&self, index: Index) -> Hash {
         if index.as_index() < self.nodes.len() {
             self.nodes[index.as_index()].clone()
         } else {
             assert!(index.depth() <= self.leaf_depth());
             if index.depth() == self.leaf_depth() {
-                self.leaves.leaf_hash(index.offset())
+                leaf_hash(
+                    &self.leaves,
+                    self.salts.as_ref(),
+                    self.commitment.hash_function(),
+                    index.offset(),
+                )
             } else {
                 Node(
                     &self.node_hash(index.left_child()),
                     &self.node_hash(index.right_child()),
                 )
-                .hash()
+                .hash_with(self.commitment.hash_function())
             }
         }
     }
@@ -181,7 +252,13 @@ impl<Container: VectorCommitment> Tree<Container> {
                 hashes.push(self.node_hash(sibling));
             }
         }
-        Proof::from_hashes(self.commitment(), &proof_indices, &hashes)
+        match &self.salts {
+            Some(salts) => {
+                let salts: Vec<Hash> = proof_indices.iter().map(|&i| salts[i].clone()).collect();
+                Proof::from_hashes_salts(self.commitment(), &proof_indices, &hashes, &salts)
+            }
+            None => Proof::from_hashes(self.commitment(), &proof_indices, &hashes),
+        }
     }
 }
 
@@ -244,6 +321,7 @@ mod tests {
             non_proof.verify(&select_leaves),
             Err(Error::RootHashMismatch)
         );
+        assert_eq!(non_proof.root(&select_leaves), Ok(root.hash().clone()));
     }
 
     #[test]
@@ -263,6 +341,87 @@ mod tests {
         proof.verify(&select_leaves).unwrap();
     }
 
+    #[test]
+    fn test_hash_functions() {
+        let leaves: Vec<_> = (0..16_u64).map(U256::from).collect();
+        let indices = vec![3, 5, 12];
+        let select_leaves: Vec<_> = indices.iter().map(|&i| (i, leaves[i].clone())).collect();
+        let keccak = Tree::from_leaves(leaves.clone()).unwrap();
+        let mut roots = vec![keccak.commitment().hash().clone()];
+        for &hash_function in &[
+            HashFunction::Keccak256,
+            HashFunction::Sha256,
+            HashFunction::Blake2s256,
+            HashFunction::Poseidon,
+            HashFunction::Pedersen,
+        ] {
+            let tree = Tree::from_leaves_with(leaves.clone(), None, hash_function).unwrap();
+            assert_eq!(tree.commitment().hash_function(), hash_function);
+            let proof = tree.open(&indices).unwrap();
+            proof.verify(&select_leaves).unwrap();
+            roots.push(tree.commitment().hash().clone());
+
+            // The proof does not verify with a different hash function
+            let commitment = tree.commitment().clone().with_hash_function(
+                if hash_function == HashFunction::Sha256 {
+                    HashFunction::Blake2s256
+                } else {
+                    HashFunction::Sha256
+                },
+            );
+            let proof = Proof::from_hashes(&commitment, &indices, proof.hashes()).unwrap();
+            assert_eq!(proof.verify(&select_leaves), Err(Error::RootHashMismatch));
+        }
+        // Keccak is the default
+        assert_eq!(roots[0], roots[1]);
+        for i in 1..roots.len() {
+            for j in 1..i {
+                assert_ne!(roots[i], roots[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_salted_tree() {
+        let leaves: Vec<_> = (0..16_u64).map(|i| U256::from(i % 2)).collect();
+        let mut salts = MmapVec::with_capacity(16);
+        for i in 0..16_u64 {
+            salts.push(U256::from(i + 1000).hash());
+        }
+        let tree = Tree::from_leaves_salted(leaves.clone(), salts).unwrap();
+        assert_ne!(
+            tree.commitment().hash(),
+            Tree::from_leaves(leaves).unwrap().commitment().hash()
+        );
+
+        // Equal leaves have distinct hashes
+        assert_ne!(
+            tree.node_hash(Index::from_size_offset(16, 0).unwrap()),
+            tree.node_hash(Index::from_size_offset(16, 2).unwrap())
+        );
+
+        let indices = vec![3, 5, 5, 12];
+        let proof = tree.open(&indices).unwrap();
+        assert_eq!(proof.salts().unwrap(), &[
+            tree.salt(3).unwrap().clone(),
+            tree.salt(5).unwrap().clone(),
+            tree.salt(12).unwrap().clone(),
+        ]);
+        let select_leaves: Vec<_> = indices.iter().map(|&i| (i, tree.leaf(i))).collect();
+        proof.verify(&select_leaves).unwrap();
+
+        // Without the salts the proof does not verify
+        let unsalted = Proof::from_hashes(tree.commitment(), &indices, proof.hashes()).unwrap();
+        assert_eq!(
+            unsalted.verify(&select_leaves),
+            Err(Error::RootHashMismatch)
+        );
+        assert_eq!(
+            Proof::from_hashes_salts(tree.commitment(), &indices, proof.hashes(), &[]).err(),
+            Some(Error::SaltCountMismatch)
+        );
+    }
+
     #[quickcheck]
     fn test_merkle_tree(depth: usize, skip: usize, indices: Vec<usize>, seed: U256) {
         // We want tests up to depth 8; adjust the input
@@ -1,9 +1,7 @@
 use crate::{Commitment, Result, Tree};
 use std::prelude::v1::*;
-use zkp_hash::{Hash, Hashable};
-
-#[cfg(feature = "mmap")]
-use crate::mmap_vec::MmapVec;
+use zkp_hash::{Hash, HashFunction, Hashable};
+use zkp_mmap_vec::MmapVec;
 
 pub trait VectorCommitment
 where
@@ -21,7 +19,11 @@ where
     fn leaf(&self, index: usize) -> Self::Leaf;
 
     fn leaf_hash(&self, index: usize) -> Hash {
-        self.leaf(index).hash()
+        self.leaf_hash_with(index, HashFunction::default())
+    }
+
+    fn leaf_hash_with(&self, index: usize, hash_function: HashFunction) -> Hash {
+        self.leaf(index).hash_with(hash_function)
     }
 
     fn commit(self) -> Result<(Commitment, Tree<Self>)> {
@@ -29,6 +31,24 @@ where
         let commitment = tree.commitment().clone();
         Ok((commitment, tree))
     }
+
+    /// Commit with a per-leaf random salt, see [`Tree::from_leaves_salted`].
+    fn commit_salted(self, salts: MmapVec<Hash>) -> Result<(Commitment, Tree<Self>)> {
+        let tree = Tree::from_leaves_salted(self, salts)?;
+        let commitment = tree.commitment().clone();
+        Ok((commitment, tree))
+    }
+
+    /// Commit using `hash_function`, see [`Tree::from_leaves_with`].
+    fn commit_with(
+        self,
+        salts: Option<MmapVec<Hash>>,
+        hash_function: HashFunction,
+    ) -> Result<(Commitment, Tree<Self>)> {
+        let tree = Tree::from_leaves_with(self, salts, hash_function)?;
+        let commitment = tree.commitment().clone();
+        Ok((commitment, tree))
+    }
 }
 
 // TODO ExactSizeIterator + Index<usize>
@@ -44,8 +64,8 @@ impl<Leaf: Hashable + Clone + Sync> VectorCommitment for Vec<Leaf> {
         self[index].clone()
     }
 
-    fn leaf_hash(&self, index: usize) -> Hash {
-        self[index].hash()
+    fn leaf_hash_with(&self, index: usize, hash_function: HashFunction) -> Hash {
+        self[index].hash_with(hash_function)
     }
 }
 
@@ -61,7 +81,7 @@ impl<Leaf: Hashable + Clone + Sync> VectorCommitment for MmapVec<Leaf> {
         self[index].clone()
     }
 
-    fn leaf_hash(&self, index: usize) -> Hash {
-        self[index].hash()
+    fn leaf_hash_with(&self, index: usize, hash_function: HashFunction) -> Hash {
+        self[index].hash_with(hash_function)
     }
 }
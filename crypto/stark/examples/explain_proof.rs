@@ -0,0 +1,71 @@
+#![warn(clippy::all)]
+use env_logger;
+use std::process;
+use structopt::StructOpt;
+use zkp_primefield::FieldElement;
+use zkp_stark::{inspect, Proof, Provable, Verifiable};
+
+mod fibonacci;
+
+use fibonacci::{Claim, Witness};
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "zkp-stark explain_proof example",
+    about = "Prints a section-by-section breakdown of a Fibonacci proof."
+)]
+struct Options {
+    /// Fibonacci index to prove
+    #[structopt(long, default_value = "1000")]
+    index: usize,
+
+    /// Flip the lowest bit of the proof byte at this offset before inspecting
+    #[structopt(long)]
+    tamper: Option<usize>,
+
+    /// Drop this many bytes from the end of the proof before inspecting
+    #[structopt(long, default_value = "0")]
+    truncate: usize,
+}
+
+fn main() {
+    env_logger::init();
+    let options = Options::from_args();
+
+    let witness = Witness {
+        secret: 0xcafe_babe_u64.into(),
+    };
+    let mut state = (FieldElement::ONE, witness.secret.clone());
+    for _ in 0..options.index {
+        state = (state.1.clone(), state.0 + state.1);
+    }
+    let claim = Claim {
+        index: options.index,
+        value: state.0,
+    };
+
+    let constraints = claim.constraints();
+    let proof = claim.prove(&witness).expect("failed to create proof");
+
+    let mut bytes = proof.as_bytes().to_vec();
+    if let Some(offset) = options.tamper {
+        match bytes.get_mut(offset) {
+            Some(byte) => *byte ^= 1,
+            None => {
+                eprintln!(
+                    "Can not tamper with byte {}, the proof is only {} bytes long",
+                    offset,
+                    bytes.len()
+                );
+                process::exit(1);
+            }
+        }
+    }
+    bytes.truncate(bytes.len().saturating_sub(options.truncate));
+    let proof = Proof::from_bytes(bytes);
+
+    match inspect(&constraints, &proof) {
+        Ok(report) => print!("{}", report),
+        Err(error) => println!("Malformed proof: {}", error),
+    }
+}
@@ -0,0 +1,62 @@
+//! The Fibonacci claim shared by the `small_fib` and `explain_proof`
+//! examples.
+use zkp_primefield::FieldElement;
+use zkp_stark::{
+    BoundaryConstraints, Constraints, Provable, RationalExpression, TraceTable, Verifiable,
+};
+
+/// Claim that the `index`-th element of the Fibonacci-like sequence starting
+/// with `1, secret` equals `value`.
+#[derive(Clone, Debug)]
+pub(crate) struct Claim {
+    pub(crate) index: usize,
+    pub(crate) value: FieldElement,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Witness {
+    pub(crate) secret: FieldElement,
+}
+
+impl Claim {
+    fn trace_length(&self) -> usize {
+        (self.index + 1).next_power_of_two()
+    }
+}
+
+impl Verifiable for Claim {
+    fn constraints(&self) -> Constraints {
+        use RationalExpression::*;
+
+        // Constraint repetitions
+        let trace_length = self.trace_length();
+        let trace_generator = FieldElement::root(trace_length).unwrap();
+        let g = Constant(trace_generator);
+        let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
+
+        // Public inputs
+        let mut boundary = BoundaryConstraints::new((trace_length, 2)).unwrap();
+        boundary.add(0, 0, 1.into()).unwrap();
+        boundary.add(0, self.index, self.value.clone()).unwrap();
+
+        Constraints::from_boundary_constraints(&boundary, vec![
+            (Trace(0, 1) - Trace(1, 0)) * every_row(),
+            (Trace(1, 1) - Trace(0, 0) - Trace(1, 0)) * every_row(),
+        ])
+        .unwrap()
+    }
+}
+
+impl Provable<&Witness> for Claim {
+    fn trace(&self, witness: &Witness) -> TraceTable {
+        let trace_length = self.trace_length();
+        let mut trace = TraceTable::new(trace_length, 2);
+        trace[(0, 0)] = 1.into();
+        trace[(0, 1)] = witness.secret.clone();
+        for i in 0..(trace_length - 1) {
+            trace[(i + 1, 0)] = trace[(i, 1)].clone();
+            trace[(i + 1, 1)] = &trace[(i, 0)] + &trace[(i, 1)];
+        }
+        trace
+    }
+}
@@ -4,7 +4,10 @@ use log::info;
 use std::{env, time::Instant};
 use zkp_macros_decl::field_element;
 use zkp_primefield::FieldElement;
-use zkp_stark::{prove, verify, Constraints, Provable, RationalExpression, TraceTable, Verifiable};
+use zkp_stark::{
+    prove, verify, BoundaryConstraints, Constraints, Provable, RationalExpression, TraceTable,
+    Verifiable,
+};
 use zkp_u256::U256;
 
 struct Claim {
@@ -20,22 +23,20 @@ impl Verifiable for Claim {
     fn constraints(&self) -> Constraints {
         use RationalExpression::*;
 
-        // Seed
-        let mut seed = self.index.to_be_bytes().to_vec();
-        seed.extend_from_slice(&self.value.as_montgomery().to_bytes_be());
-
         // Constraint repetitions
-        let trace_length = self.index.next_power_of_two();
+        let trace_length = (self.index + 1).next_power_of_two();
         let trace_generator = FieldElement::root(trace_length).unwrap();
         let g = Constant(trace_generator);
-        let on_row = |index| (X - g.pow(index)).inv();
         let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
 
-        Constraints::from_expressions((trace_length, 2), seed, vec![
+        // Public inputs
+        let mut boundary = BoundaryConstraints::new((trace_length, 2)).unwrap();
+        boundary.add(0, 0, 1.into()).unwrap();
+        boundary.add(0, self.index, self.value.clone()).unwrap();
+
+        Constraints::from_boundary_constraints(&boundary, vec![
             (Trace(0, 1) - Trace(1, 0)) * every_row(),
             (Trace(1, 1) - Trace(0, 0) - Trace(1, 0)) * every_row(),
-            (Trace(0, 0) - 1.into()) * on_row(0),
-            (Trace(0, 0) - (&self.value).into()) * on_row(self.index),
         ])
         .unwrap()
     }
@@ -43,7 +44,7 @@ impl Verifiable for Claim {
 
 impl Provable<&Witness> for Claim {
     fn trace(&self, witness: &Witness) -> TraceTable {
-        let trace_length = self.index.next_power_of_two();
+        let trace_length = (self.index + 1).next_power_of_two();
         let mut trace = TraceTable::new(trace_length, 2);
         trace[(0, 0)] = 1.into();
         trace[(0, 1)] = witness.secret.clone();
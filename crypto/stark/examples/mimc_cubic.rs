@@ -1,7 +1,8 @@
 use zkp_macros_decl::field_element;
 use zkp_primefield::{fft::ifft, FieldElement};
 use zkp_stark::{
-    Constraints, DensePolynomial, Provable, RationalExpression, TraceTable, Verifiable,
+    BoundaryConstraints, Constraints, DensePolynomial, Provable, RationalExpression, TraceTable,
+    Verifiable,
 };
 use zkp_u256::U256;
 
@@ -37,15 +38,10 @@ impl Verifiable for Claim {
     fn constraints(&self) -> Constraints {
         use RationalExpression::*;
 
-        // Seed
-        let mut seed = self.before.as_montgomery().to_bytes_be().to_vec();
-        seed.extend_from_slice(&self.after.as_montgomery().to_bytes_be());
-
         // Constraint repetitions
         let trace_length = ROUNDS;
         let trace_generator = FieldElement::root(trace_length).unwrap();
         let g = Constant(trace_generator);
-        let on_row = |index| (X - g.pow(index)).inv();
         let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
 
         let periodic = |coefficients| {
@@ -56,13 +52,16 @@ impl Verifiable for Claim {
         };
         let k_coef = periodic(&ifft(&K_COEF.to_vec()));
 
-        Constraints::from_expressions((trace_length, 1), seed, vec![
+        // Public inputs
+        let mut boundary = BoundaryConstraints::new((trace_length, 1)).unwrap();
+        boundary.add(0, 0, self.before.clone()).unwrap();
+        boundary
+            .add(0, trace_length - 1, self.after.clone())
+            .unwrap();
+
+        Constraints::from_boundary_constraints(&boundary, vec![
             // Says the next row for each row is current x_0^alpha + k
             (Trace(0, 1) - (Exp(Box::new(Trace(0, 0)), ALPHA) + k_coef.clone())) * every_row(),
-            // Says the first x_0 is the before
-            (Trace(0, 0) - (&self.before).into()) * on_row(0),
-            // Says the the x_0 on row ROUNDS
-            (Trace(0, 0) - (&self.after).into()) * on_row(trace_length - 1),
         ])
         .unwrap()
     }
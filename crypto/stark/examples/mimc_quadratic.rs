@@ -1,7 +1,8 @@
 use zkp_macros_decl::field_element;
 use zkp_primefield::{fft::ifft, FieldElement};
 use zkp_stark::{
-    Constraints, DensePolynomial, Provable, RationalExpression, TraceTable, Verifiable,
+    BoundaryConstraints, Constraints, DensePolynomial, Provable, RationalExpression, TraceTable,
+    Verifiable,
 };
 use zkp_u256::U256;
 
@@ -38,15 +39,10 @@ impl Verifiable for Claim {
     fn constraints(&self) -> Constraints {
         use RationalExpression::*;
 
-        // Seed
-        let mut seed = self.before.as_montgomery().to_bytes_be().to_vec();
-        seed.extend_from_slice(&self.after.as_montgomery().to_bytes_be());
-
         // Constraint repetitions
         let trace_length = ROUNDS;
         let trace_generator = FieldElement::root(trace_length).unwrap();
         let g = Constant(trace_generator);
-        let on_row = |index| (X - g.pow(index)).inv();
         let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
 
         let periodic = |coefficients| {
@@ -57,17 +53,20 @@ impl Verifiable for Claim {
         };
         let k_coef = periodic(&ifft(&K_COEF.to_vec()));
 
-        Constraints::from_expressions((trace_length, 3), seed, vec![
+        // Public inputs
+        let mut boundary = BoundaryConstraints::new((trace_length, 3)).unwrap();
+        boundary.add(0, 0, self.before.clone()).unwrap();
+        boundary
+            .add(0, trace_length - 1, self.after.clone())
+            .unwrap();
+
+        Constraints::from_boundary_constraints(&boundary, vec![
             // Says x_1 = x_0^2
             (Trace(0, 0) * Trace(0, 0) - Trace(1, 0)) * every_row(),
             // Says x_2 = x_1*x_0
             (Trace(0, 0) * Trace(1, 0) - Trace(2, 0)) * every_row(),
             // Says next row's x_0 = prev row x_2 + k_this row
             (Trace(0, 1) - (Trace(2, 0) + k_coef.clone())) * every_row(),
-            // Says the first x_0 is the before
-            (Trace(0, 0) - (&self.before).into()) * on_row(0),
-            // Says the the x_0 on row ROUNDS
-            (Trace(0, 0) - (&self.after).into()) * on_row(trace_length - 1),
         ])
         .unwrap()
     }
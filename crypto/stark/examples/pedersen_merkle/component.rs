@@ -1,16 +1,13 @@
 use super::{
     inputs::{Claim, Witness},
     pedersen_points::{PEDERSEN_POINTS, SHIFT_POINT},
-    periodic_columns::{
-        LEFT_X_COEFFICIENTS, LEFT_Y_COEFFICIENTS, RIGHT_X_COEFFICIENTS, RIGHT_Y_COEFFICIENTS,
-    },
 };
 use itertools::Itertools;
 use log::info;
 use std::collections::HashMap;
 use zkp_elliptic_curve::Affine;
 use zkp_primefield::FieldElement;
-use zkp_stark::{compose_vertical, Component, DensePolynomial, RationalExpression, TraceTable};
+use zkp_stark::{compose_vertical, Component, RationalExpression, TraceTable};
 use zkp_u256::U256;
 
 pub fn tree_layer(leaf: &FieldElement, direction: bool, sibling: &FieldElement) -> Component {
@@ -50,12 +47,33 @@ pub fn tree_layer(leaf: &FieldElement, direction: bool, sibling: &FieldElement)
         Affine::Point { x, y } => (x, y),
     };
 
-    // Periodic columns
-    let periodic = |coefficients| Polynomial(DensePolynomial::new(coefficients), Box::new(X));
-    let periodic_left_x = periodic(&LEFT_X_COEFFICIENTS);
-    let periodic_left_y = periodic(&LEFT_Y_COEFFICIENTS);
-    let periodic_right_x = periodic(&RIGHT_X_COEFFICIENTS);
-    let periodic_right_y = periodic(&RIGHT_Y_COEFFICIENTS);
+    // Periodic columns with the coordinates of the point for each bit of the
+    // left and right input. Rows past the last bit repeat the final point.
+    let periodic = |first: usize| {
+        let (x, y): (Vec<_>, Vec<_>) = (0..256)
+            .map(|i| {
+                let index = if i < field_element_bits {
+                    first + i
+                } else {
+                    2 * field_element_bits
+                };
+                let (x, y) = get_coordinates(&PEDERSEN_POINTS[index]);
+                (x.clone(), y.clone())
+            })
+            .unzip();
+        (
+            Periodic {
+                values: x,
+                period: 256,
+            },
+            Periodic {
+                values: y,
+                period: 256,
+            },
+        )
+    };
+    let (periodic_left_x, periodic_left_y) = periodic(1);
+    let (periodic_right_x, periodic_right_y) = periodic(field_element_bits + 1);
 
     // Repeating patterns
     let omega = FieldElement::root(256).unwrap();
@@ -124,11 +142,7 @@ pub fn tree_layer(leaf: &FieldElement, direction: bool, sibling: &FieldElement)
     labels.insert("right".to_owned(), (0, Trace(4, 0)));
     labels.insert("hash".to_owned(), (255, Trace(6, 0)));
 
-    Component {
-        trace,
-        constraints,
-        labels,
-    }
+    Component::new(trace, constraints, labels)
 }
 
 pub fn pedersen_merkle(claim: &Claim, witness: &Witness) -> Component {
@@ -293,7 +307,7 @@ mod test {
     };
     use rand_xoshiro::Xoshiro256PlusPlus;
     use zkp_macros_decl::field_element;
-    use zkp_stark::prove;
+    use zkp_stark::{prove, Constraints, TranscriptVersion};
     use zkp_u256::U256;
 
     #[test]
@@ -339,6 +353,7 @@ mod test {
         constraints.pow_bits = 0;
         constraints.num_queries = 13;
         constraints.fri_layout = vec![3, 2];
+        constraints.transcript_version = TranscriptVersion::Legacy;
         let proof = prove(&constraints, &component.trace).unwrap();
 
         assert_eq!(
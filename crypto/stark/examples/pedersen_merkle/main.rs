@@ -1,7 +1,6 @@
 mod component;
 mod inputs;
 mod pedersen_points;
-mod periodic_columns;
 mod starkware_example;
 
 use crate::{
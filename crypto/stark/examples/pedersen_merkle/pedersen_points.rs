@@ -3,6 +3,9 @@ use zkp_macros_decl::field_element;
 use zkp_primefield::FieldElement;
 use zkp_u256::U256;
 
+// This is the one-point-per-bit variant of the Pedersen hash used by the
+// Starkware example proof. It differs from
+// `zkp_elliptic_curve_crypto::pedersen::hash`.
 pub fn merkle_hash(left: &FieldElement, right: &FieldElement) -> FieldElement {
     let mut point = Jacobian::from(SHIFT_POINT);
     let mut left = U256::from(left);
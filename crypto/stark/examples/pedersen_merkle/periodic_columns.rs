@@ -1,1074 +0,0 @@
-use zkp_macros_decl::field_element;
-use zkp_primefield::FieldElement;
-use zkp_u256::U256;
-
-pub(crate) const LEFT_X_COEFFICIENTS: [FieldElement; 256] = [
-    field_element!("00a74f2a70da4ea3723cabd2acc55d03f9ff6d0e7acef0fc63263b12c10dd837"),
-    field_element!("00ed021e66d670608d65fa55597c3da99e143e17bc34a01dd32b352a028ec839"),
-    field_element!("05c8707c12896aed50aed74ccab0e11eb2bdf909946e6b6e81c0d2828b476496"),
-    field_element!("04d2550233879ef0d7b5b954d88534ff34619e3ef9d4aafb04d4d3d6695098ed"),
-    field_element!("023d3c5a0a4937c2db8384f1ec9c8a50bf4f8c8398462576fc6dab3539ec755b"),
-    field_element!("00293de4c0b03a5f72dfcc36a2426f159e42229f10bccb8fad1e85688b854173"),
-    field_element!("01632e11ccbdee1b0cef5064bfa964642d95525b1cbba2da3b7d1f643d299ebc"),
-    field_element!("074bfd772feaa22b3488b0b5ec3b68ecbef7ec91c29739264f67071222955e2c"),
-    field_element!("007121b7e548cd5850feb6534d599be411342e9bcf858cc036c7f3a78359bdc0"),
-    field_element!("07c83a09664f16634d039af747a439c966cb826e1e6ce56fe2ca32fa95756b0a"),
-    field_element!("03ca9222a4e764de5a0414db0037f36e95aecf04dc9fdce9df0d40d98a263136"),
-    field_element!("01750b281b640e2264f37b7f80a74fbb3d29faf9b73cf383f4e88b3b7e998779"),
-    field_element!("00831bf753892914f58f1511f69c950ea2853eff5a1787f9e80cc65e59633c67"),
-    field_element!("02d357332d061d578534a3aec19f71ea58b03d7095c63241042dfdaf332dbee7"),
-    field_element!("04c4ba96d27adbd92213f6bbf38cce6005c4557afa50866c0d2d4274d886fde7"),
-    field_element!("03044a9dfa132a11d195a7b46ffaff131941a62cc5e675aa30d8ffb5c0b36019"),
-    field_element!("0659fd39e0651a2d1622f524ce397ab179b2ec9dfec8c1076fe2a8c46c00ff42"),
-    field_element!("074d5503bfc7c28cb08e2f8bfdfc65ff671f62afa36ad26f6f1b65d7850369cc"),
-    field_element!("03b91bb1905ff60ac5068e0ffee9f3a35977a894525717a8c9ed600eb2a1d438"),
-    field_element!("02744b08fda6c05cf1295f6de7355d721886dfc77476d4ce36a1736c9e89c5fb"),
-    field_element!("03d18c0fac30b0ebf20b3b6c1c4b7d82ab77749a5f7446221b188fc38d2836a7"),
-    field_element!("07bcf3a1cd6a4bec11e215f814fbbf69e634579102e0220eb4db51f4d158366d"),
-    field_element!("01f1ec34374f555a4afc97d09af1c010028ffee0833eb0926dbe43ee96d1a258"),
-    field_element!("026092f7fde398226a4eff02a59e35d2750270bf422c5c47f77c8b994e6e2f00"),
-    field_element!("042ffbd55e2901c42570740e323ca7ad71df18813136584f4696836d4b70277c"),
-    field_element!("031681ead57b167f296b176700b372f3fb77becfafaaf29a42bd61d74b5cf839"),
-    field_element!("034096f164304059300aa25d050ff033589e223d220123a85ac1b34959c87267"),
-    field_element!("00d4ded3b2ad9e2e47ce09396ab294c35b05f7c4335e3a37dd1cf692d99f0a0a"),
-    field_element!("048b9714f4c55b27b15d861cc4bec8bbc309d9f53a2abaa271f98eb4fe4aa26b"),
-    field_element!("03751ed12cc60ef7ce9bd757d35484f59b03097c59ba5c45036b65750408f483"),
-    field_element!("078d5ccba44d6ca1b78f5943cc7058f2e31b52dba98903e93cd26b9eb73bd75a"),
-    field_element!("038ebb3a7808e92a6f3b4484d5edf5ce9a0933e1c95dba2b5e78fab8fcd1478a"),
-    field_element!("00b642861538fab992aeae4b4d895f1023440975345d0a5f8bf5554fbe997943"),
-    field_element!("03b8878c7490a57720d7a12feebc3464dfcb9a0a333761f0b1b31148b924ef1b"),
-    field_element!("01ac69822b7f18d026fb66f5d2fd4b9f131bc3df1a71e9972ca461bf4e3bad83"),
-    field_element!("0346a7ba290a081302abd258dfc4efc5b15d42e792434df73178c63561d2bde2"),
-    field_element!("073904d320d9beb16c2f949a2b97efb1f3c89b8fdb96dd3ea52643b012fcf35f"),
-    field_element!("0533cc56e887dd229a70223207e039681752821e5776b8c52784c23acecc3505"),
-    field_element!("06ef60aac4b7e261cf69b51212fef2b8c36eaae6ec8aebcf386c153e1525831b"),
-    field_element!("0753bed1a2af9e00dd8b11e098fa49b3697c41cd8e8c2b7b9e4e9b9bc5ccafb9"),
-    field_element!("00a7a400be7e52ef9601cc8798882b97fff855ac7ae8c0f9b3f9883b951fb57a"),
-    field_element!("01b438f43354a06a8a21c4bd09cbcc7c3dcbab899f464e8cfc6249946ac3b742"),
-    field_element!("03e5865adb4110c8b35551f1ff356fd2a46cb5a986ab2a823b610d23d24486e0"),
-    field_element!("065dad862b6410a140d638d7d9106239b4fbcb4b6fe5c2d707b70ab097cf1151"),
-    field_element!("0655e879e220d013d3d31d2f317c424322591d14f94148abf5cc1f34860ba86e"),
-    field_element!("006f477070d30b150b98f871bed32aef711856e24324f7cf62d15ea35333c7b0"),
-    field_element!("0593a3ecd5ba6795d7ed9842784b85b8e7d9bef76a3eda0d688d35d98165ce96"),
-    field_element!("01de5f8a47945ba0fd8cd8e9a3bf563e940b746a1dfc2c893566c252b230c496"),
-    field_element!("029a23e67b7a265242982f5e09e74d443882b71b6e7e2df59bca2208e0a89b9d"),
-    field_element!("0446c63fde4e4180a39c5321e98873f4acd9898ae84219b2e0f8601b573c3406"),
-    field_element!("077a9bb14b1031021c80770d67cd3a98f37250b46a2559f39fec72d0f6f2999d"),
-    field_element!("075364a4dfa99572171b561a8ddf0103fddc33d66eb85a7feb5ec33feb1de170"),
-    field_element!("016a40882d59f97d59c4b9eeb5d6b9476e10c6807eeaa712a4de312dc79bd0ea"),
-    field_element!("05e030fee6f6366c09de0702f90860a2d651332d790fce53ccc10e6aca6afa4c"),
-    field_element!("07577a214fdae34d254429f79f6575de2a4124d7da2de032d78d01f3bd640d75"),
-    field_element!("033705af636f06462c246d7b00a46a01f0972648657df19807f7283eb3b432c1"),
-    field_element!("0379bd4d9842c4db69d26c986353f5b4d59f912793beab5cebb50537bf210c57"),
-    field_element!("019580197cdab83f407746d5f8592a6a9a42ef4c81a0ae9137c52c622c72dbff"),
-    field_element!("01c5212fff50ecb2634770718e1d0332c7a2219f8cbba14d4a5deefbd025d9a8"),
-    field_element!("04a10fe8472f4c4a48eb30a26c8ed45016d5e53ec216da63adcfc868872e2c18"),
-    field_element!("04eb2f6482f922e2d90c1d9df0495b6ac67342b40f7662e201ed717e9cfe8663"),
-    field_element!("0014a50583be4526a55ab154d4dd463b26b55a6dc9d0973582e1470a8faecceb"),
-    field_element!("04eae53193f3efb0c1e732f36548254e16bfb47ce75c1ef3bd553d44142f501e"),
-    field_element!("03e0d6dc8f543b33592f54bf5a51f686f7877d76b73bb4b3879f04c3a2417c72"),
-    field_element!("04226e3091c0d5c5d2c8db960ba8782498d04b8ea27b30d6d725d3b355318fb8"),
-    field_element!("048cff91d2a6a98f17f5b0b6ed489c54fec59402060f4a34ea3662f7cffdefc1"),
-    field_element!("038c49c598516fc4ad4b1ef0186b3cf4ac0d308430d2c322a2292d54d1eb062a"),
-    field_element!("07b987f67d23e03deaa818bd32d9cdbe1c9edc753b1035bda12af7437655167e"),
-    field_element!("0290d997f5b6010315904fb6c2d81d4dc2199b1223773b3883f5e432f408c81e"),
-    field_element!("0162e24764c1bb4fa5f66497b13127d0d32c4fd609075b0b69447880fa0ece9e"),
-    field_element!("01ce90d9d258b853aa76fff454f56074ea394c46a8d79e01cdacea7967569349"),
-    field_element!("077714979e29bae677b2da9c2e63b4236cad9d99af0e3cb08b468a7ff7c8f104"),
-    field_element!("00d576c461448ffbf726cffd161f363dd782e413eae04a909adda1889ee69310"),
-    field_element!("07021f6387584c09bc8d160d43a7d1810c1def6f8c370efe53a56ea42e21775c"),
-    field_element!("02bab5d534646f6abc1d682d25c6e96f6e368eeb799cee9f5460d8fbb6c7197f"),
-    field_element!("0471be0aabd6943fc47bab955497890de301c7e9b7fb970da50a25b83103737a"),
-    field_element!("044c8ee29adcb17e0b1e0a0bbdba7d91b12949549c8605a1666aefbca3eb9673"),
-    field_element!("07146de2c6d21fa1984aa54cd94305fb8f8c07e25fec94c2688abb6df665cbe0"),
-    field_element!("073f7523088bca4a78f4c3e4664e11fba5d0b30807fcd606a7e942345b6bb2db"),
-    field_element!("051d08a3da7127d517bb964864741c853263aa4f2ae50999dd47afff767eecad"),
-    field_element!("048a3888bade3bf6fe309eeadd59d13fe485d92f6a270231bc96e4ff47a1ec6c"),
-    field_element!("02713b83c5fb47cab89498b1975ecdfe28459e2a407a3d3e7108cef62d80d3aa"),
-    field_element!("058d0652bf1ae8c01be8800a8f5a5ad714824408b2e2a5cc7cf266914d01ca68"),
-    field_element!("0321f127ae1c799cbd45908d7e40927769e6584c5880390f69db411bf1941d9b"),
-    field_element!("008990a324b4aa1777db32d07a8cb4420686f4406fc5c4b904da0827987ae675"),
-    field_element!("05f8bb2da7edd6778c634efd430375609fc21677576ba7b3323cabf42a9eabfc"),
-    field_element!("03eed362c0589ded1a31fb70b7ef988183e5778acf2ade07b732419cdc700cbf"),
-    field_element!("05bf5b8addcfadfb03f4da240252a2e58068049c1f4bdac5f7de62a6c241fa28"),
-    field_element!("000e6b7c2641a87b807b69bff2098a6efe841d09c109655b0c5db00f7507f610"),
-    field_element!("012d7607c7ac0177b6627824ecbed1d4775a6a65e263eb40b2b8addd9c64ceb6"),
-    field_element!("07f656dcd2e01fe3a114dc8b6cab25f18c51ec1971b449ea4f0d2420285f2cf1"),
-    field_element!("03dacae915c6927f251cd4cc42477027db4672aa799569c6a122402fc2a4d921"),
-    field_element!("031330591c71f59dfcd89140f0b800a7f649f552847d460fe84091b46dc4dcc9"),
-    field_element!("0571078f6a6ca11b649df1634820f755300443c13b3a0411d3fed348b5cf031e"),
-    field_element!("02d53c5c812dfe422422fea8b0ea69c3309728b679ecbfe0b1f152c978534c60"),
-    field_element!("0614efc0f3e08fec8cfcc355f3043fb6f411ee302da59f4b0255cde8332459a3"),
-    field_element!("04c0e4bf73ab0e263a674b7cc5b047b006e9848bde82d80d6940a7d90c50cd94"),
-    field_element!("05d9208830a3fc7a4f4f5ba158fe5ba6c3163f484a75b9a1f6da68363d5fb56b"),
-    field_element!("05c90eb0374507d8056a89d6d8b3f2bd1bc3a7711682c99b0cae336ef8227cfc"),
-    field_element!("037eed4d34f34cce326ec665e1339bf94af6be83e7f08427a3a0bcf63f24ffa6"),
-    field_element!("03ea22d2c4cdf3f5c6ae30807a159859118d370290f47adcea9af1c782f23d48"),
-    field_element!("01f36594c56eb1984a6886d572353647ea38fffb24c2427bfe3613a97ee78186"),
-    field_element!("0530d74ed30307e48975a7da69e1961b7e70a89ebf8fae7973a15bfa1aa2fa9d"),
-    field_element!("06e64c759432600e49647bf5bbde380ec3e2d7654f7a76e630d534e0ad0652dc"),
-    field_element!("077c5784ee6f8c779f44bd1fdf3c82226d8b93fb12610442c94ede1afe0cdf26"),
-    field_element!("012d4a9993345b954cfa1aca1a54ca48c20f363de9d8dfab902dafdbd53dec51"),
-    field_element!("0214fabede1f1baddd115ba466b754de19d6545d93b14202a5357d4e8e3f0a37"),
-    field_element!("01113bab27042129816436df1504a556979f0051f4a0ab7d20fe375190b7c57a"),
-    field_element!("00b11e4d1e377d2035bf97766616d114c0c1d9e4b75348a887056cbc6ac2ba33"),
-    field_element!("03a1815f7f083b90150c024c4c14060eafbb09dfd2b07bd768638595ed11763f"),
-    field_element!("03904ba0a6e93fc1ddae983976083b7eddd4fcc42006f2a961ce96f24172e965"),
-    field_element!("072e5d33fdad1ad92975875b68f8c319b726dacb3895d3ff802d01bb851eaf9a"),
-    field_element!("02918550fa366b3b61b221cfd88f4938a11dd06f43972c884bb6df626b348cad"),
-    field_element!("067b73f9860e3bac6da91abc690ba630645414dca40fdddc490e85ae198dfbe2"),
-    field_element!("05f2dd5545e8a8e957303543db96c68f1e5ec954b51b5f9bb54ba2c885c5b9bd"),
-    field_element!("0181ecb69d33a912e27d3a835ddc18e0f01aaf7209e7b99b029b0424fcca4165"),
-    field_element!("017a3d1d0bcf12ac334083cc20f7c066314b928860720ad8fe48a6d1506f45ad"),
-    field_element!("0204ee36896401ccf2f12d2d01e6196cd496af3a78947016852a4687b3992b34"),
-    field_element!("05cf5bdb5ad5a669bdf206bc64efb9a9bfe706a783e7467795d6c08f062cd1d7"),
-    field_element!("02e64cf74037cc255c580769cef1cee03b1a133ea2b30d3256611840553002b9"),
-    field_element!("05d6edd0644bc12d45209b61afc2abec8316ee88b2131909b7638f04b1612d8d"),
-    field_element!("02c6cf2cbf866d863133ac4f7c9097154a1c092c1d1777b025149e3dca2e8737"),
-    field_element!("074cd6c88e9c81b83cfa84d8d6255a0ae200e09df06d9ffc94982137ef7b20a0"),
-    field_element!("012583400d604ef0fef0359335e8ea5dad6f9ab9bf3162cc49ca62bf23c4af2e"),
-    field_element!("05e678d7e625903ad8c32b5615c371b34fd5f40b262a5da3f4687514cd0eb163"),
-    field_element!("03c37c0283e5616fa7dc664fe1efbd6a77eb7cd02722123371388712d27e717b"),
-    field_element!("0487466d3255d2f5217c04d0ba64b568abc820e672acd2fce521210479d8232b"),
-    field_element!("067de569ea4e70c9d281a7dd00e395f3d57a65fd57f57889991cc5d8060dfde4"),
-    field_element!("007be7a90cd16138b5fe780c71b5564b800445b3a8fb1b813d6ed9c1ddf8594d"),
-    field_element!("050efbeb1b316b084d55d77d8cb6db08f2da9328ed3e621023610a074758bf71"),
-    field_element!("062ef2a55e90d1228323cf076824226c53d97ec3036d856da64b82c4f5fa6190"),
-    field_element!("003a050bf2427d97b146b4611932602250396df51d5c92416b950d30ecbaa13d"),
-    field_element!("073dd0064dbd46373f070aaf01b87018a970dedfb6ef3cc9e03e5ae023a3dbc5"),
-    field_element!("07801c6701338c830209b5851c4798be38838c2ab8113cce727e8e3622351f55"),
-    field_element!("016ced3445edda61c9a64c1b3cd40f06628c95bac66077550ba07127cf854e0b"),
-    field_element!("064fc7ed7a2bcad9c3d5e3a6036102094dcbb2a0191cb6baa980e01348a09ff9"),
-    field_element!("07920655afcc3502ace8aed813e2754d15433e104b56d0de10786566f4bc0b58"),
-    field_element!("04eb66e48ee708a126cafd8310883c75fbcdeadce7d57960bbfb75469ec4d206"),
-    field_element!("064875a0fe0af94f7135a591d01d64aafc16e96244bf875d43face6e2811a4db"),
-    field_element!("07ec8e019e223298de51334eba3d1bab6d8178f8947d5b4d3a26eabd5c9fa795"),
-    field_element!("02869bd13c3b7b20c99a316d90c69040b930bde9784d482817208b5db78cb4d8"),
-    field_element!("017fb3a385b4c4615ed8ef08e017945c1ec9f111ad9c056d60e4bb28e061da23"),
-    field_element!("043dd21229cc4156791ab056625b87cec279e3ea1d9b734924365302d5f137bf"),
-    field_element!("018481afdb0b26f0ea08f2284185f44d1b25496612b8dbb435c3c3adfa5e5b9a"),
-    field_element!("003854cde96253dc9593dc11df930a62111620711f0d86f48d40f077a4863d1f"),
-    field_element!("02f6c33df8ac0696dbae8c72208716624f4c7912972742ec232edb35688d8257"),
-    field_element!("015410cdb353cf459df37b205d0c75960b9b7a9789d7ea4d2687257bea68edec"),
-    field_element!("02d4f88489395761573a7bf984b2c51d7e2f9f0e7a552406f2b6eae72b5a7d8c"),
-    field_element!("005706a7e505e4c334ad5c27d6602f113430b75ccdccfc1a80ef8d8aa9074395"),
-    field_element!("073e98197f5fc3924d4022731e758e12cbdcad41dca85bbd4597157b984c6ac8"),
-    field_element!("037f5b3cbda349a7dbae200410e22bcebfc4cd1f60e61a5963da0f4bb681c654"),
-    field_element!("03fd8d65484e3efb467a07196cb01812ef18665ce404a8b9f2bfe36a949db9b3"),
-    field_element!("043b560acd0414eb3216d6704baed37b92ba492abd722c87d554d7b0ec720b98"),
-    field_element!("0227612ed23121ed543369cb2b7f309dc33d2069474cf40ec56e59106ed6876f"),
-    field_element!("07d57a3edb63419de67a3ed6b5874f194cead9706fbc088ed5ce5ab8bbec5036"),
-    field_element!("006f4c6e57ae332f45efbfe10a512d335b48295dc80c2ec86dea247f4a16e7cd"),
-    field_element!("065d4824ae8d719e6cab377eba62d94d5d09d2008c2c29ca68021767daf02627"),
-    field_element!("049f9114af8d524433577290ef6678fa723db1b2b820b44151bb655530c7de11"),
-    field_element!("013c616d92eb122d32afae5dfbd3d80e4ef10f05ab5df71973e3341e41600db8"),
-    field_element!("022936dba47541f097f13cc663dfd109da014610c8c86bc27774df05fbef6121"),
-    field_element!("00baaf4e9030426c695b7070e87ff3a860eece735758f553283fe09b0212dd9b"),
-    field_element!("020f4bdecd223a805fad14c4f6eca0b94d0363c813d065289ee3e8b6ca33b352"),
-    field_element!("06fcc11576a1ac9b302752a5c7e1e81ce455e39d61c23b6f128637e94cba2197"),
-    field_element!("0797141adf61925ecc4920f29396e33aa4a2e9505ed0a193e82e83f6064fb580"),
-    field_element!("078c035a5f7660d05c4cb4123b261a6d9a9ee9ecff7e137aa36377052bd492ba"),
-    field_element!("05f04321bd447ddd4431a2495cf64e35e34891df499bf2f1a4b16e11cf0fabff"),
-    field_element!("005425f3900484fdefc0a0d2d43422259dc1710a481c0731710ae57e4740aeae"),
-    field_element!("000d06e1a570617205b67bfa747c9ac996f6bccd4663c5fa81b2eacc6dad34da"),
-    field_element!("0631a11a3a34b6963abe38f49857a88c9a5e4a6e5fc7b8582f93c836ca9a2aff"),
-    field_element!("02f877d3104a26e92b909ec4dc3a162f08d070e820730c21dc862fd5321419eb"),
-    field_element!("01b51aa18816a7dff387afde2db408aab37ccaedc1e125c238cb4390cbf2fc1c"),
-    field_element!("07b3c9b1e7da963beb5ae112224a77cf17f01ed29aa9877fcca6a54b37fe637d"),
-    field_element!("0719c5b6c502e0c056bbcdd02e36700f30957124e0b15d58f847367ea9b8c83f"),
-    field_element!("031be5a91ba48aef42519390fcb46b62d6c7e3d0647d041b2f17b7024b025be5"),
-    field_element!("05d9467aa3811a153acd1714eb0fa056c0dd1b3b700ab90e0fa40b83e9378ccd"),
-    field_element!("02668e947a180ee7bc278a8da6510c069b9a88c6b6a9460d9a84d12641ffad68"),
-    field_element!("067c7baae7f471557dea9942bdcfeccb035947d08145c26d45ead9b5d22e29d4"),
-    field_element!("0462876dc24499ffe133193867c44986cdaa97fe7154319d10d3f5d5c3c743a4"),
-    field_element!("026a7a5a11792b39b4c8ffe43c421320ede1e420769b796a973e9a59875d622e"),
-    field_element!("05d9c315ee45fe66cccccb32b72a8e9ba3d1cfa9b3bf1af843f2c28877bccdcf"),
-    field_element!("02c2b6a09b0a6b8cd619a0ef22b69c4ec922182bb109f566f4935259176b6003"),
-    field_element!("05bb597e2b19793ad7f29b8c98a2fef4daf7eb0a13717e28f178409410164db0"),
-    field_element!("014696e3a312c597318a16528a6476085cf220e42f9af179ee6cd3ccc460c8bb"),
-    field_element!("040c98847086e3b074bde695b18c85ed3b9ce4e648214bbd51eb5f0a8a81c3bd"),
-    field_element!("0456fab31f6dc7d1a175fbf2e1d65613e3df7d144f9f0b613e6298ced8e657ec"),
-    field_element!("028413b44fc579467410464867b54c03971a7e5f7ff0a4fde874bb086a0fd36f"),
-    field_element!("07aa5c71c08490ed5b9de6ac15c43c0d972456cc4e65f2c3a1deab32211d44e3"),
-    field_element!("0318c45a4a5f6e6d0c190bd44848e06da38475324bec62854a8ab64df9f569d7"),
-    field_element!("04d6966bd5f8c4a4a5017a386557ceebc6db7c2c1045981dd187305041104791"),
-    field_element!("03c000dd4a46d381c56def898cb15bc872e94d5ec867d6d2205641a0bfba18da"),
-    field_element!("01b0c1b8dd6f6798bc36cf469f1057c6e795bb1cc18078a68ed6a93914dc0954"),
-    field_element!("06a443e8823453e51f7503400edf45941af5321d138e8070584f8c7330927713"),
-    field_element!("060b37236f0ed078cdc437d83f1392ee3fef0eb9f6dc20930dda9ae30b030bd2"),
-    field_element!("06d6443e7bc84fc40e52d45a84296c0ad319e5891e8c5753f6a5405ae870d1cb"),
-    field_element!("035bd2442ae0ef7a32afc84819c3e9cbcf6d1c7163bd01f2f64ccb1e84b63974"),
-    field_element!("071ba4a5d79955a5bf92f30fe5f6189859afaf4f0ea1c859b7ce37025e0fd567"),
-    field_element!("04ee859b077f53b84fb1f77908fb99f16c5211320e63ab41865ecbbc813d7ca5"),
-    field_element!("017a6256b1debd98627a8db46918565bfc0e94327f39756c2ae5a9661bb2c10e"),
-    field_element!("0692192449684121c8a415356a2ee298d6e1b05fe74ab1ae5a9c394da703ed48"),
-    field_element!("02949e7f27f76c84991fc366c874454d9067d67b0657945952b8404841be3357"),
-    field_element!("066bddd0534893626d88d4a4985eac1fb1976cedd786ba88fdf36e3a4e569316"),
-    field_element!("0507cfb3b15ec2aee4ce602789abac0eafb7aa82d8f4c04ff5ecdaccaa7a64c3"),
-    field_element!("0195570f8a4a2843e0529e738233861c7a9729c74c2049f651609bcae334908c"),
-    field_element!("01fa81f5d48fd33293c64e099b4cb65f850889f19b612099051dd278d7462d1d"),
-    field_element!("052311a9120914ad2acb79cf6c642631ae560e9ce5e4a93c71c6857b10d981f5"),
-    field_element!("022dfd0e55e51c22ddf0a315b51af14d59501f4ea3d89c02f7f111acd101f36d"),
-    field_element!("03e37f00cb35d3df987d00a151fc1228d4a2fad6c6bd27a10307cd1d5ef30acf"),
-    field_element!("038325b27f8f913b6821c681d9b7493cf3550443888fdc465f05e4fcd71816c8"),
-    field_element!("0316499f87cef0fd4d6afe57acd0afeaa3cb0108f2ddc061a9a16cba598a742c"),
-    field_element!("05f909637d33598d1409d5a1bb2bf0058efa935759aac4eb8b344d98be244559"),
-    field_element!("02de87e529a675552e4822bbacb4a19929cd66509f0d11e3f7bc88c9f597d5cb"),
-    field_element!("02720a045644a8860ddcaa0311159cc75dd26b75482041f84aee4f9c3d52060b"),
-    field_element!("04a0805f285fe8d18a13a8f142d5b2071f48db34f5763e6dd44fc177b2e87e08"),
-    field_element!("0382922b391d0fef371d58b8924ebe7214ceb9d54334808475a19ad6c904aa02"),
-    field_element!("0476edce9e3a8787d6bb95ba85abc97618e4e1cb95cbfb87ac27cf650631d429"),
-    field_element!("07946b0fbc37234d7b362cf2ff64b2f38fc988ee8aaf3ee0552905b7e7bb9d05"),
-    field_element!("06b03d22ddfcc183109a7171aec65e1ebd761ff0efdb7f1c17b73eb74e88b61b"),
-    field_element!("03ff3d03deb5773946e661f452d3c477cbf00461e834f5619b550a35ddf08e05"),
-    field_element!("01f8aff42ca6f373052e55db47440e0be8644aec2d8aeb2fcdf48115dee88680"),
-    field_element!("042a524cbfd7c5568ba33eda2896a648a1fd75e3202b4179dedccae513024315"),
-    field_element!("03123d7ceb2e371b87e4d19382b05358fec0f189dbbdbca3e7cea314ba61d336"),
-    field_element!("03859019e9ff943a43d1f02469f825692e78a848b64097ca4084742e65b7e82c"),
-    field_element!("06b14e8c006cefb26628a998fac448035434ae0af51812f3d7a8b5a76777ce3d"),
-    field_element!("07633c89f4b612aff7273bf7db45bcaa152d18d3023a9f9572a68a751b8fdeeb"),
-    field_element!("03ca8d416169c60955e6521c05f78d937faba32aa0c2817ae205f726f7663272"),
-    field_element!("02e50e35d95fdd95ca63c50c844bab23982e217b399b29294d3f7a49a835166c"),
-    field_element!("013aacdb23bff1b18d23fcd8bf27d7fdc5dade88116fb80f2683662a8d946463"),
-    field_element!("0606dc9de2c5c1dc3f463e0fade84f8d118e8b13b67fd29cf26c6c093c170fae"),
-    field_element!("01f0cf296e0378db13a6a0e089004acbdcfc29052ef951f9f2bea380c13209cd"),
-    field_element!("02e845f036de04dfbeba0a18d3ef3c03f6bb92ac55798697c708d8ff5986edc0"),
-    field_element!("04b85616ce94c81e2b4604b36b703c9f8285434deb81d46d3b8361213388afb7"),
-    field_element!("06b9c601cf9190b5f9cce97790c4e4e9a8adf578a121b3a50cbbf21f19524052"),
-    field_element!("0181d53d1c83fba0dab11e76ac7e23a32f45604866496f893afeabb306dfce4e"),
-    field_element!("01ef93dcd3ed83b033d8348ad193317e504d38db1bd0e0bc4b7e58985a852b7c"),
-    field_element!("0419689552488a7244f183d2e06c54d89ef51e1fc90508c8b58419734756fbc1"),
-    field_element!("01a9f902004b0107591065f433264f6d6d21feb6c7d385e64be29df28bc83696"),
-    field_element!("05bd2fef813e44b9a6c0140d9431de28ac37945be80f835103467bbe98bcb403"),
-    field_element!("01b5ca0e333f00781e2d6c4b469e38352e07a18fd6856a1c522e64057acc9cb5"),
-    field_element!("049085f4c9c40b36034c2534c5401b544c05b2d6df8b5a18aed4521236c80e67"),
-    field_element!("0606dbe9297d3c8621bc3375d28be398a0bac7a94eb1d1164894cfb5be8ef19b"),
-    field_element!("04c1c84d5f28a807334cbd344591e71a148406a31dfa545849c52bb3f7b3903f"),
-    field_element!("03a5f7f5345525e618de5b48b5dfba54bdbd65e75456fe25da82ee767e10ebca"),
-    field_element!("07ba4c1c08659a52064e0196a55fd137f3aaa9b07993559c0b516e9ca2352235"),
-    field_element!("04729f1cf2ca1cbe25829482248d20a9957d061e05eb4b680d36b7814a113cef"),
-    field_element!("070e5e86163fff68f138867e78f9d56537aec82cc76800bee55f7e6fe7c06e6a"),
-    field_element!("034e32ce2b8151cba24f80c8b227d22cbd4fc6ae9c61dfb25964afa3f03765f6"),
-    field_element!("044fe4d189307fb1d6be5f74e64583fd4f6bd0e8bb24beb12ddb5ca11097a92c"),
-    field_element!("06a53aa743008cba794587543a09acfec8351bc93c92e206e04e18d7a30e532a"),
-    field_element!("051c8fcf7289062da59c446deeb8b3b7cd99492c2088d4cb0f9e01a8aba67dac"),
-    field_element!("030d0ab68a9cb63ac8040d61c8a56f4f93a8044c49cd516e61672e4b4ce93ecb"),
-    field_element!("046a8533b650117651e3bddd4232718721ab84f58a6adc8b63c14da04c0a46ba"),
-    field_element!("04299f3960c9aee289702b18d2ea4012eec5109a00773d8e692c025f8a9e65c0"),
-    field_element!("06d1eaac0c2fc0c3897943d710451812970111dc17a39318d3e2dd1fb9ac18a7"),
-    field_element!("068c2ae860cb2766d34bd024b6b41e1d9dd75048451c538a1bd4fc5268580b3f"),
-    field_element!("02f93968825da5ca78e673678d6425e9aee29ff219ec7eca75cbde5773cea1a0"),
-    field_element!("03766419edb980788fffaf2bfc82ff62f78778f1a1b91e172c1520bbf534f085"),
-];
-pub(crate) const LEFT_Y_COEFFICIENTS: [FieldElement; 256] = [
-    field_element!("022b14b55b21a19104eb60a5c9ad06bbff2018993780280dc89306a2affbc0e2"),
-    field_element!("0591b6ce9e114999202e5f289beee0d4f9dc09d232560588d500d118f8881347"),
-    field_element!("02b73305f31ce15198264495086f7f0fb831ce40dc6ed4cb0d1e762f5b8a2e1d"),
-    field_element!("05fc1b278375274d31746a5053bf31053231b499f7e12ac00688ce78724e239b"),
-    field_element!("0059ff8e282494cb8c80b69bd409e5f57b698887545838b9f4ab8b738a25f974"),
-    field_element!("0738d23e06112a577538ba17a6ae8ac3e3e951b05815497844ab16d79bc9a308"),
-    field_element!("05092b7756d71bbc1578011b7a8ab821ef0f76ea577c17977cbe766f29648a69"),
-    field_element!("07d668e6d2e48ac3dddca2231a9b9f5541f9d6b7d472816540b058d0392805c7"),
-    field_element!("06c713a9088a7c89a731947a34ec34d6e84ec492d58a2ceb8b9cde2944850c7b"),
-    field_element!("0643ad6657b0304f1f63af6e84e46180e4049edb6f4667e7fa8c34d6c6705020"),
-    field_element!("04e41489a4f207728d8884c461a99c926ca0f08729bc651a9d279eda8120ed45"),
-    field_element!("04d965ac11ead366bed0af29046690ac97f85703422c5efdae1b5ed461f4a429"),
-    field_element!("04c30d209b948350a464377b67879cde7df2587a90df2a7139e528008c70f76e"),
-    field_element!("042ae77a04e7ac0112218e5dc86b0e3c62c32ceec216b32da38245139f8ee9ae"),
-    field_element!("031a267192540db85b112ac1ed4d8fd7b4ddcdf80aae13727a035c33bb9c5002"),
-    field_element!("01b46de3b5a9f6337fda87d8fba2c9a3b6539e364d8d62a0fefcd6008237f18f"),
-    field_element!("04cd886844907b8cbca4594272717a42d054485d9b82dc0762e3ca127956aff6"),
-    field_element!("0230b0feecf77288c66c0e998d94fce93ee32e99ef0fa614215e28e8aad30ea5"),
-    field_element!("0551db8362b480c4fa335bb42d0c79528300b2d31994a8f70ad9eafdc3d0f1e8"),
-    field_element!("000333147fbdba2e33407ceab8d866d853c4f78793cc6aacad29587e140c32ff"),
-    field_element!("03fa49ed0554df298b10494907c2b5f74cec7fb3d72fbb746e219fc21900d07e"),
-    field_element!("0428ef505feed45625aae577a29aa86e63f40c968a1cf30d686ebf5c96842684"),
-    field_element!("05fe85c86f24a104ebd8dd2795cdab5c953cfe3f724a41c916d86aa82ec1a00c"),
-    field_element!("000f5d14078a27c0d7d8975ccf0a798ce14f5374e51700b29511d3b704379411"),
-    field_element!("006809d828415e2093d020aecb3ed392f300cfe981b78206422b935941ca70b6"),
-    field_element!("0350cb61b5e9fbc644c6c8e58768e6ce53251da9a2316dc7aa262b40d3119e37"),
-    field_element!("06ef2eb50e8dc3d1275d95f3a7b72563ae5c29ed3bdd768b1fba9be3fe90d577"),
-    field_element!("055f3c91be3f07a51d17b9e78c4f64506ecdf9f8ae7517a5916b7c64502a8bef"),
-    field_element!("01d76631171293d890726850ab1430f08a250736c2d54b2b75c006aa64fb60f3"),
-    field_element!("0410ec1535a9248531629d30ea0ac3be5ba994b9f5c45f220447f469013111a2"),
-    field_element!("0183cd0479f5c60f5ff3e2542c58dbe19739614655055d47ec19c690c87eb986"),
-    field_element!("06a9d91e3ca06a012da4f070d3e087b0835ed642b3098f17e3e49ab512511600"),
-    field_element!("033cb824029dbd3a13104ce1cf991808e8582b049445f3bf497802bdd91c5689"),
-    field_element!("063b967c8613360ea23a65fc006bf0a8f71622deeb0892f73f71d31b008b876b"),
-    field_element!("06b4df494654467b09d2db8b79c63c6c28b9c7ae1b5bbeb3f85bab92fa7054c5"),
-    field_element!("0650df0a8fa6612d5980db3a3e91fafa436220c1112041805a1bbb9ca6e337c2"),
-    field_element!("073e6b93e502e4bb0a0a95bb5963d16c9af71f7dca94f22f374f228832f97326"),
-    field_element!("00b46669c3d951ba3e19d539bf07ac25e3ad30bc7ae95bfc4ceed088385ce6f9"),
-    field_element!("0165d35df3cca022da14fce57d0ffa3b6fabceb1ec36f2a9262d27892bd03308"),
-    field_element!("0117a591ab359d722afded7f4194516910bb1ab0d0320bfa2be334d840629acc"),
-    field_element!("055a6091cd423ae92ea96549908cfdf452ccb6076bb21e7f821b46e12a9a980a"),
-    field_element!("0606eb086ed0000ac5f3ce5b0bacb0e6904dcca0f50b2071a11b92785fe0de6a"),
-    field_element!("049e8734e91e15b10fe6a3875f7555fb9ef15a193c44a5ec5d04fb5d3f673f90"),
-    field_element!("03e8fdffd1cbe1513a526763e90effa4251c60b95ce14a17a0a656f43f3b5280"),
-    field_element!("05ec01f9881d56a68eb8a6995abd9c9ebe864595ffbe1b6cc04cbe393a32eb9e"),
-    field_element!("00f98fc98db20746ddc3580cee37be7677bb83a7573b372c4532192dc4fd3738"),
-    field_element!("07bb82b211c1729922bf22bf426c6951eb8a6551abbcf4031e4e3f5858dc0729"),
-    field_element!("0372e4de0b1e3a6aecafbfcc215857538ad9141ff8545778acc51180137219f8"),
-    field_element!("03739dff55cac5e413620a9e4c768b2a8b0614506d8657f427a456624afa1837"),
-    field_element!("077536d0d9f39c6f563bd8d15d23fd966e27ef7fa6e14e33a0acc7775edd96b3"),
-    field_element!("034732c10f3e233d867ee2ae2c59daefc07e5536a854857b06c88cd859cf28c7"),
-    field_element!("0018e1df0f0bacce7b5cf826c7efc2baf07d895d1ba04c0c9507cb96091f4892"),
-    field_element!("007970ddb78bd04eaacc8bcd539e91c8567cde9a08a3bf40e319d6a4e9385d30"),
-    field_element!("07b2ec0cd2a578ea9f852ddf72371fa580ad62c0f70d14f2932e724155b7b110"),
-    field_element!("05afa0ad5a1fdd924ff4942c246dd87ee721588643e5fca91f95409d63e1dd2a"),
-    field_element!("06072eb120242b6e732f09d159ced22aa60addb7344d9f37b01328820a47577e"),
-    field_element!("0478dfb4ea151e65110880819c521cd241aeaa34a234342d76838c850e463714"),
-    field_element!("036d172da65fc8623eddbfc90a09fe960a41f1cee55571fb85dea06ea200714e"),
-    field_element!("02dba9f3cdd3658ca5eff1626bac92387fcf6180be792364ca29caef2d4059eb"),
-    field_element!("036d39d60e7215bcd5390f5dfabecc59e05cd7b637d0907e7849b40e35d34ce6"),
-    field_element!("02ca4a479f4d69221df84c16283a5f2469d6df7f2cb4edd97e7570592753c314"),
-    field_element!("03be312bac59cd325b4b346efa2e5f0e579cabd16b87945a9d384df05b405a74"),
-    field_element!("038298c23431f685d3492483aabf39297b8df51b14163800bdf1131d8ab5a2fc"),
-    field_element!("022bbbcfe4b152baf54a1d7ee85fda91ba0ffda18d4efc98dec1a9605ee02b51"),
-    field_element!("0670aa216f2c93e28923bfb3700e72f4fd9718862f6876c9f83d77f13aeb3d26"),
-    field_element!("0741e09d43cbdd21dfb236e6a47e03bc9cb182c9908c05068b7add97e4977cb6"),
-    field_element!("015154087276cf6afece0579c8b290e9c9821ff8021c776bc25023c6b0ac9757"),
-    field_element!("0785100e04ec3fbcd3de536aee088fc4a25397af388657c78c7f46c293fa3fd1"),
-    field_element!("03c228407dbcc2e5f39418f9a432d1d69e66997d72ce2740c33e99a4bbce0ead"),
-    field_element!("057bfd8f58bd4ae80dc2338f6cda1c6e93a834bbacaf691b91d1c81aa42f44c1"),
-    field_element!("06b097f67573191f9bc24f106798ea17016589a3c08ad9ec411a0ff7a3ac6c89"),
-    field_element!("03cc5643c575626305f338eacfe0896c8d49f627f16824afce76f53f5da46158"),
-    field_element!("05e60b1d790345391bd754c50a007fbf260b73cf56f0c57668a847d1db110b7d"),
-    field_element!("0542497262d7deaaba9c1f84191da3e43e7a178f5547d63e0786ae99b8db0953"),
-    field_element!("04406410bf3dd261fdd0605dbe16cdfaa91c803b7bc9301b29fdd524b6469c1b"),
-    field_element!("06f3bc1382b475d1a95a11c111fc8455081262928d5fccdc6cb7c699359343e0"),
-    field_element!("0466c57793215efe5e0067f5a664680990c023b8e6dc1df9475cff0e1f2543a0"),
-    field_element!("056a8f9f01b8f8f4203cc8cb2a10fe4e776a116df2c3e1bb199193a88e78b418"),
-    field_element!("04e7645d2f91b184465b9e97c04279ef6531853c6de9ca7d44d641b1aa77cb66"),
-    field_element!("06a4e097aa839d6e3eabfc265999d12896b0937b7dad72acd6a09eeba844f8b7"),
-    field_element!("01a7a1ec197109ae0bddef3d071988a4e5e7e035b226e6854a60bf729de06054"),
-    field_element!("076a00a42a35488b85e9ec61c2df3e8793126680d0a4cdccf9f51765d00dce25"),
-    field_element!("033eb60f392cd8790e5ece8031d8db0b97a07050005577790dd38a214b98baf1"),
-    field_element!("045eeba6cd9ca6a9ad879555a9dd1634a58697f5f61f76232df7741145b530df"),
-    field_element!("016ebe97bb1b8cafe8a27e8ff49bdc1bd994fa446d0ec444acb572c86cb57518"),
-    field_element!("0263ab31563026b0d8f9da0ab5e235d3f153b4ea172cedfab5192894d269cae6"),
-    field_element!("029d771385372f94a8821569110166680603117c089a7e974446d0a1dd1eaa9f"),
-    field_element!("001e48183fc0a512e34724e8d70bc66a264c826c61fec887f2d120c318d28896"),
-    field_element!("05ecfff67c8d179257443619ca19aa2d8467545afe59e7fe2e73d0e2d68762f3"),
-    field_element!("06d467483675d57ef129a773ebc9c4f4c08fdc5d13755fd4c657b1b00c1531bb"),
-    field_element!("00b5121dcf6c4b4791318609b0ab57d5b7b9d4d038ac4e2d89e27cfc870d7da0"),
-    field_element!("02e6fea0290eb93294b2ad396b0863461cbf3387e01177f0ae7573b7db8b3a65"),
-    field_element!("03c104646b86104f0fe3ae4cca43410fb47b1e21a85fffa68f6280d96f752638"),
-    field_element!("0470743ab8abfeb43390c5059194386488bf26d5c19a8792fb670e335a173ab5"),
-    field_element!("0765a5a4c7ed30a94b82ed54a0eda65081b39797947a02ba6e266c650b0b76fa"),
-    field_element!("01ce7be40695b57081e29787c43ef942eb17fdef27d0637df45edcb30b6d106a"),
-    field_element!("0407018cdaf1751aaa433233ceb086ed5ffd74907c4e04728336e43d68c43170"),
-    field_element!("016c06cb89cf03c880542fa7c97d6d6566ecaf47c35944498361be99fc81c16f"),
-    field_element!("00cf70443b17b8cf9f83760fa59231c38bbf8af78ed59efde3a25b2ea6fe5432"),
-    field_element!("0565461a113894cd5f6071a5328f8f18d5f8f5bad44c33f853e4a232daf2d541"),
-    field_element!("00c5397e1b1265900fd0d94df5763339078604268561b7f1613b24fe35ef2f6c"),
-    field_element!("05fc6c052ff8b6224a7351c49ed97aeb6249c3ededcb395c97858e282e3972e0"),
-    field_element!("013dc115ddd32824bbd88d238adb0ae6ea4221f333906da02c38c2eabfbcc619"),
-    field_element!("07a1898d8aeb7e8d563d882c52f33f002f8f9cee7e62d4020ea1e576be9ade68"),
-    field_element!("030258f1cfb9402cae53c036873b4065aae5c124a18d2eb3b4a1f37620ea7693"),
-    field_element!("0605654a8f94bad21a931c90b3c9e81c834efe4f10e54b787ffc65e36eb7ba3b"),
-    field_element!("051e16e88d449b35f43c987809bd59cc709a2d01c33f06e5ec13df3cd7c49cd2"),
-    field_element!("02ce9d3ed6d434fd72199f8189e7e87e743d8e69b00ef192b575013a0c5e1d82"),
-    field_element!("04f2532e37fb65208cb140906b13066c281b7b41a7b4a0fa26cf911defcecae5"),
-    field_element!("002022cfa64b38c741b57f49c20fdb98c8321e02fa28f8b195ec77063e3e29b0"),
-    field_element!("05413dc33bc347a7f7ce2e078c5ee4aca78a34f5e9b67935db4d15364a98ad69"),
-    field_element!("04e2d86a135ecca1f31a8b96970a9585bf81c2a88ccbbfc8a231fc6411fb4e4e"),
-    field_element!("06a2c71f6f2a2067a2c290074ccac6891586255c394211e46374c2e0316efe4e"),
-    field_element!("07cadc3711485dabedd12772aaee6796a438bb4529737ad231170bffddd6c8bf"),
-    field_element!("05c95b6b1420fd6a82bf27aa461f824891918ead7bdf1157b1db254c7b5bb9f9"),
-    field_element!("00bb4199f826a2b4654f5f4617d02dfa91ba0a54b801333f38b06a112885df35"),
-    field_element!("030d8c1c11079032f2ee6805c956cc142e9db4d76c0057b1f9a27941e9afa1b2"),
-    field_element!("03c1798f35131cdb770d3fa036fe450f63a5f693a011c6838cfab49f2e988d29"),
-    field_element!("039d1d3bd19ee5d7f498d64a1fbc3e7b17ba486386c983b025b65aca9fe115bd"),
-    field_element!("06fc3024dda84aa1b77873b604d2db05646b07313b1722a65b06787acee8fbf8"),
-    field_element!("01cd86169cf8eb29d1b85cc18bfbc64768acd5666aeebefe2f4a2d7b2964a26d"),
-    field_element!("0259802c1fb7128b850d64ddac3c0adf48254404572cfe85345c0e9119d0402e"),
-    field_element!("05859daf49ddbdc8b6487f312bf6edd10b607925325dc3ae7c962052f31e3211"),
-    field_element!("0142252e39309df6f41d37e73ea37751eea7b0cfa0562849945475d1a873eaae"),
-    field_element!("028fead7c510c51e49d4f64fa2bf94b0630c12cc205f293b6bd8aeda006d1309"),
-    field_element!("03620d6d6400ea00dd6a03808763fe87bf1d78ccfdc257ade2dd8ffbc1e0f806"),
-    field_element!("015bfacef59c15005b18720fb13342ea3853c18f293ab4fee3e07e7ab216d8da"),
-    field_element!("0639e188c8ea61d393c8b98ecd4262edfff840a7ee47b282129ea06dc244fc5e"),
-    field_element!("0324df025a64692be68b78d8735cddbe203e7a5c871b58c469fc9eb40cad63a0"),
-    field_element!("02a431af2c7c7c8132b66f8da8e7b4b3f2149b8d81a4a7484d6a85383f06ef1f"),
-    field_element!("06d8d8a5c5efaaa34b378a46fc3291c11f376b8110218a93f392ba24f51b2d0a"),
-    field_element!("04afd990b1564233cf2a02d83f57470077f8c70d2321d46f70da0b1ee5e1e85d"),
-    field_element!("034bd9a186d18830edecdcaf363909b198ea53ef09cfc3968251716044413c34"),
-    field_element!("015060482705756026b9801e7bafb591bedf2dc8045e48edfa64ba7973807720"),
-    field_element!("004571a1ebf75bb861a82b5d544d0a1918aa4cde495f49e919c71f741778bd34"),
-    field_element!("03a78b1c1dc96b5076252755e4156dfb35933165f0031b8e2e7dfa80c2e6cf38"),
-    field_element!("0697ad2de83ddb3f5568a4f49e2eac5334d35746e2d8ccded0fe70ffa1a9c946"),
-    field_element!("07065e60856fb331446b4106586c10cc7c10f6f911ea83b7f2634923926ce59f"),
-    field_element!("0053d014eee278f2048de0383c383e65288ac819d097baec09b91ba69a08f99e"),
-    field_element!("027d5faf86ade9eafa3a4b973e63a7fae86399f01862a124430425e26a1256d0"),
-    field_element!("000360ccf214c7c7ec5e64df8b6ee748e1e0430df382a3bf2a88fd9e5e615117"),
-    field_element!("02b7c6407b0406250f26ca34b1460156b251066b1a2dd25de4cc9b2204d0bd23"),
-    field_element!("05fa5346148f0408895934c923d2be49eccc1a4d35b2a4158103350dd56576c8"),
-    field_element!("05e2340de63b250d8df0796e2b32abe32b6b6f07b13b3a0cae24eb1a620e0af3"),
-    field_element!("07b1740b862ebbe3eaacbcf6403c9948eb0335c520d9b299218dce0cdfff77d3"),
-    field_element!("06c63aded8fe093f2af250eff7a3f628ded0f34fe80751e18d39f8faf65f30bd"),
-    field_element!("07a6940f217cd74f06969bbcf7d0ea003e9ddff09a8a5a16b4331aef66d05bcc"),
-    field_element!("06da0ac16d2db0c3cf18db1c604f9248767fd88a1cba11d817e09e20a6f47bab"),
-    field_element!("074f83d597c6662db4c75aca4dd05563aa5792f10cc1231408d5146ffd0883a5"),
-    field_element!("0563f483f5fbb37c11978e253b04ed0d0d2c4d5b96bab83b1ad400ec65a0f0b1"),
-    field_element!("03f890644d823edefe45faf27e7ab9a3e92a87002db5da751e1d455f3938e469"),
-    field_element!("045a9418eb89616a3280e03f8d487f600bdd11705af57d95cef117fd0792b96c"),
-    field_element!("0541f015d8c61eec64fccb6b4ea31f97e492c615523bfc0c3fc762266e630caa"),
-    field_element!("010ecff715811f217a579c390379181d0d775ddd16850bb56ad1dc834c9fead9"),
-    field_element!("03febefab3e138467d14db3c8c5081c5252421408ad1ac4a23cbbbe36d8f528c"),
-    field_element!("065bc1e190488b0773468d514e9c64e62c996ea7f1d0231c58a7efa6d7b28043"),
-    field_element!("0392f849d1f7689c1f85b6063539b355dc7f896405c970077fe5e8c085d3120e"),
-    field_element!("0161eee8be835e07010d3e6d181e81bf090998d0d685bf90cec625a5a823e18f"),
-    field_element!("06aba3bbfe1d29b6ea9b7903bbf9b082466041fcab5d2101ead92c51f9d6eac3"),
-    field_element!("03d959239e67ff12593cd50b18ade4565b60f4f804697a471e6d89b3d6ce6adc"),
-    field_element!("06b67a4a8a64d4135f883fbe5facd2e193a5bff966003419f8d145178c8e9009"),
-    field_element!("0666e01f5235f64b0ec61f6590586616f3b6390ff5b532feec01ce6cefb4f995"),
-    field_element!("03feb84a319749b3f6ca19fd78e97f322f632167f0e33835c41b30bc17aa9e55"),
-    field_element!("0138576c19bd5f6087ea1a572ac4ccbebd37d4b00282247fd9b3e62718e8726c"),
-    field_element!("07d9e3817b870ee62cb46d8e9111db520a38ddc13b19f3694e51e93128d45fa9"),
-    field_element!("074f1fb80d3d7468cb7d889cceab8ce8e2038125f1fb23812b6aad76c69fc0a3"),
-    field_element!("06598c8ffb0317ca143f9ef5ff0c1061ad0f7b93fdd3b1e9779a8e592496f6a7"),
-    field_element!("06a9e3afc05c435929c37dd8f0dab124fb86eac8b7122dfc8450c9b579735a39"),
-    field_element!("03bfa5c40753ca3e90485e3c73d05d02aa51637fcf542fc1fa53bc5552d554be"),
-    field_element!("0670f629104890175a36a90fcd8078543f7c771504e4d5485f98cf1364a98476"),
-    field_element!("06df5e4cbe055d458f11dd68e46e25a4015c5c7e9d460a849cda69ad17c9b063"),
-    field_element!("0750ee4a60b6295050186a860f21177697d07ed8dfc08056f7108c28a562c87f"),
-    field_element!("00867bd82aa111a4ee760fefded1a5a63ab5fc0125ebb2a021ac439ee5213c97"),
-    field_element!("05602545421f0086ed37aa21a8ec4ebaa46fb4e3a79d0a8788832ebe6220e936"),
-    field_element!("0702c31281f60c127d5cfb2c43bf8ae01274877a022a7e5be4079ff59774738b"),
-    field_element!("0096e57083e77104c5c8a58a175c48f52475eb88dda753e8d21cf15da4ea0447"),
-    field_element!("02229e7cf325b3a1daff2d8c1f8c35b6afdcc2fdb6e53d9130c800ffb886a04e"),
-    field_element!("0141f7d84b0560b195bfe0b1475b35e3e1dafc252112e1157dbb028946911d47"),
-    field_element!("003041117d3bf6cef4504fe3886b549563e52c30953b0b2bcf6f3d2968b1972b"),
-    field_element!("0298e99bd657d16cf0ad23cfa928b0a181d43a3074ef6416215b591bf0a37638"),
-    field_element!("06a1bcb1bdbfb33635c8aa218172f9bc6c771fed9d74f597b24190f22ab36d8d"),
-    field_element!("06126bf6bd7574078571cb4132b9e7f37bad89e493d2d277f7e136584af089e0"),
-    field_element!("07dad04e65486a46e6e888fd2e3e75d7c482f1db5f6d41c3f9a5098b0209a0af"),
-    field_element!("05f81f336df657d657934a75ff7e51edbb34f4d5fdde83ee378992bc6f0a1676"),
-    field_element!("00c5feccf8dc6335298e312abd9aeaf0983f2acbc3308df7de418cb58463ece3"),
-    field_element!("05f281dd730319a2b5b2f757875c5c33466b808eead0ad4c07728a3beef034ef"),
-    field_element!("02f0160ad0fb0f55b834d7fb0ff6694836c1adc4580ace96986006ccea287c1d"),
-    field_element!("00f3889425c96f50d30b6bc7b0478ffb3ed6afc67039ecdf9a917cb0f551e2f8"),
-    field_element!("041f245db0143f3c5301037257b140b35f7040312a09387a9096f1b5317982a3"),
-    field_element!("02288cf95b81a87ef80fc848a313859c2452e2b42fbe3071ddbee4b53215279b"),
-    field_element!("00440b0dc25fa618ca9047f62615de46364ebe3c2bae85ee60bc66dbb9758897"),
-    field_element!("07a8e552375b01a4563f527b3bb7339eb880e2aaa89bf5f9f11d4eb845928328"),
-    field_element!("015a2368e7c141c779373988c36ff5864bd04e61b53d791d3c9585cfee941aed"),
-    field_element!("042b0c04d599c8f7ac3ca7796ecf7c9bceebacfeb7ebb13eb00c447762b7515d"),
-    field_element!("02e94c0eb9e6028732e4ee3063df71a6cc5294f05972091034c985a9892c7e0d"),
-    field_element!("01bed14cf3097df00bcb8fa4845d91e12c884309f179a9173c9ab4132540086b"),
-    field_element!("070e7aebbbe4ed43fc40dcbb718716f4493fac207866adddff69051d052ec195"),
-    field_element!("04b48c5727b6a0cafac51e221f5b43e52cb21584ba80cfe612f83f08a30abc1e"),
-    field_element!("0136c78883ef592797393f900416101a111b9cb4908dca5be54bb0ad6625e6fc"),
-    field_element!("064dddd0f734ea0065cbebd363b0425cb9cb65965335eabbd951c764c6ec8d4b"),
-    field_element!("0374089f1e3efd30c0204e5324d22cd20b1ecb5668be65786997233ab49a606f"),
-    field_element!("013663a9d49796401bf2b193fc946c2737e65255e09854393119a6b97767c2f4"),
-    field_element!("05f1b324b75653ca7cbcf41fce19a097f45ab74757d6c9682f8cdb9863c2aee7"),
-    field_element!("029746608b0a35ec4441fc8de37937cebe04e2c5107114958adc43083dda42d0"),
-    field_element!("049dda028018094580ae64a6556ff629f9af0288814bbc4ca205d052d670805c"),
-    field_element!("074ff233d60d79d724b5aeb5baa04caf22b01cadda2a4bfa94c5403d5c4b927e"),
-    field_element!("0022c1950ab651e010b29dad439875b7e49782c0e23f3ebe1abbafb6a72d03f4"),
-    field_element!("00f383ed467111fd995bac11ba0f38f20bd13e7e74cdd5903fa362a432c9d76f"),
-    field_element!("00155b69dd2ee3d472b31f787b612aebade76727c9f2aa2d87549eac08f6f64a"),
-    field_element!("02327b9748f589d272fb135201acef0447454e42475413e14179b8a42b407d93"),
-    field_element!("01af8540c563c8781eb5d842b4ec1c0fcc782d711155da9a7470b0af5af2a430"),
-    field_element!("02439841606fbb94eb1b74f8052d8b78909c829a3a0d1de22abc3ed1eeb294e7"),
-    field_element!("0427e8746b786c6210596c2d030f4d7357972b1cfe96dd1447c45f732f230ea8"),
-    field_element!("03bc52bb71dcf28f679faa3d923fa039f68cfe9781bcf13d7e56f8126b0359fc"),
-    field_element!("052a81ea950c50127932532edb2345c091215397efcff3aef14839059a7c25de"),
-    field_element!("03f71ca81c3b68a696f66d1cbb0429fbc10e7f9b81c0b966d2b34bf1d3f3407c"),
-    field_element!("046a549f08c30c642052b0b7ccb79af73ecfd128c25d5b7f9e006bb9c35ca131"),
-    field_element!("04894cb34f2b2a1d3f86c980dd488e1a70e42975d84f77d23c8ab0c88b77a6b1"),
-    field_element!("021fc88069fc41ac4ed3f5792df6932acb18181ea7f52fdd97749648966dd1bf"),
-    field_element!("06ab6470719ddff83076171595fc8553f4731cdcc7a3acff3cfa19c6db132459"),
-    field_element!("01c9592287c2fe6dffdf21b6763e236eb20c63a7426418aeb9e4d35182af778a"),
-    field_element!("05a7b0b4fd3aed0d929f375abc2da340ecb698325890d9310b67ed038760d435"),
-    field_element!("066febcad531a29f3d00c649a4b7f9028f4a7e00304a58691c926a2ef51047df"),
-    field_element!("06ad58c6d311e80da1d9e6199e0eb08b196fc042d7319b77b98637c3ca3ed813"),
-    field_element!("02045ca2f088254b2a0b80b737db854863433d4e96b3d2db52028d8635c687c7"),
-    field_element!("077e7111de2850ee53788d50a475aac3b150dc3eb6d821c54569c4ef2a083f99"),
-    field_element!("014367032aeae5218faef7bf6666789c245e57b81c4f36d92aa974f3ce99f190"),
-    field_element!("048b48677e2265c7b2b5aeebaa6a7c1e8eb2a6d42e1fc034456d9cdb3e52fb5b"),
-    field_element!("04bc6c9b7cc8e1698901985ce2c4bc574a14d79a1406d098c79e97b5366b0796"),
-    field_element!("03eb432f8af0c7df74ae538d9b651ef9c5f77f4d61610545d9d8f4ce18140c2b"),
-    field_element!("009c89591b4838ab39c60afb98a486dadef37c8a3e11969e777ef27670938489"),
-    field_element!("03edef57c5dd348f6645e455735f56fddd1bb520afa63240110f23b853daf3bd"),
-    field_element!("00df47262c9cf300b90435390c2a2b3ccf26b448bbe2f66e842717d2e496abf1"),
-    field_element!("07ca28ba8b7c1469d8d367de1c47b36493dd1e0dd469dd09757582c732a7fe1d"),
-    field_element!("061eec30bacd905d006427a85fc064b2b3aa15a1e6b9ed8361d63c46058781b4"),
-    field_element!("07b4e3935e820086e81666a1f32cf6ac5a500756885b83839e5bbc35aa675538"),
-    field_element!("069861634eef1dc199e1c50c108e0f3e48fface7ff0806419da4eeaf8198f3f2"),
-    field_element!("06b3ff5718d246c2361dbd7a334b8540a97d748f893666bcac220c9385ba3e60"),
-    field_element!("01dbe68e73cf856feafeb8e42c1d5d0e349028633e3554fef24b001fad4dff1c"),
-    field_element!("0552f8e85d401056fbdac0bbaf53c414f6c8f7889e1805fa53d02d2fc0b8d5d0"),
-    field_element!("078be447c2bc70cfecc6bbf16dfce813ce526ff95d531a2aa03d12bd1898a60d"),
-    field_element!("01291c6eaf66439a00108ad713c0653ed854753a55c44d4f5f5ba873e00274aa"),
-    field_element!("072a6064fc049a90b671ce2b55213811b40e68c44becba0d6566f42b5cf8c88e"),
-    field_element!("0394a1c1dcf53919f8f9525d02b4d6dc81f48dfbadc0b8ee7463f7faec48aef5"),
-    field_element!("06fb0d635433ca3efbc2756676b613868e3344c0a8bb32b4cd527055976488e5"),
-    field_element!("06b89f8c9b4ba93e9b608624e8cd2b12487caf28f6186a04f5b1b6b1c5084253"),
-    field_element!("0081e1451e43a13b96e219c6ccd4b40706468301b795c4c10047a093ccd9d4f1"),
-    field_element!("03014b42ddecf025d24a849c210141416adc2c452aac820473d363913e7acc0c"),
-    field_element!("0170a0fb49f66a49176f39d0ac315b680cc1156a13dc0a0e64bbefbbc3e7ff4c"),
-    field_element!("02e502058500e3e2460ed1792379f66cafe8a43d82c9fa4247a6472593e84d53"),
-    field_element!("043ef38eaf4a1d07919b8cb730e32694e254c1915886a11b3695b8aa7a89e853"),
-    field_element!("06ee75c431550ecd0d5cf6a97fcc38bd00ed55c6a7076a52492c9f258009c708"),
-    field_element!("00125f7644d2a846d78194d9327d4e99535b8224bb073a87ed13b0e91044d809"),
-    field_element!("054a0c26ca04c14e1e26f0eb3b75b9ab2abb30cb38d547afd362d5d22332ad68"),
-    field_element!("01d0aafe2191714d6adeba6bb8b4bb98acc27d06950bf03f85249c3a7a925f8a"),
-    field_element!("008e17a355fb39c4b0ebb4ff0756200225a5f0eb374ecc865a94279c46a5188f"),
-];
-pub(crate) const RIGHT_X_COEFFICIENTS: [FieldElement; 256] = [
-    field_element!("06f603035259d287960449386b86d4a1855050363e0828841729992cf0548ae7"),
-    field_element!("07dc36046cac3352a4cc274322967b84313e4abb45d1e8fa62600a70ed3feb3f"),
-    field_element!("06edc50a9d7260c36677aa47b2c43b98a4293dc25df0c36f9da18210038f9989"),
-    field_element!("0523e73654b15d9eccd3c1aae96d6a1f8b0d3ad7a122c1ab8d52de6ce8203e15"),
-    field_element!("0707ccc845f3519c58cada218e1a0e3dac013ab7a9d0b5950d9d29e450353e5b"),
-    field_element!("06bcd92758c9f17db99d423f4794842742ca07f172111e3d22f81bea9e488fdb"),
-    field_element!("02a7ea1397abde80125ff7b909b9b9229f0ff6f908662d1b8df887044bd1643f"),
-    field_element!("0594307b78409a41f032fc55cce8bb033fcc81d557d7ec60fac82648c9044ed1"),
-    field_element!("011cbe0703f35cff9ac8089902773fd51e47e15e00f2b1c7c4f9a32ffc770195"),
-    field_element!("06a8366ead522ea52328d59bcb13a01a8bb4cdfb655a633fff1a69e1fb5379cd"),
-    field_element!("02a933577aab9b22533cbcd6323208a6e5eb124876c294ae17afc14d7edbf3fb"),
-    field_element!("06e2452bd38ab1df669c5e6823528ae0a016f95417fedaf16457bf8ac607b203"),
-    field_element!("030d02d832b987cb7706ea845997ea7a47b89acf9dba925d931ca4a077236bde"),
-    field_element!("06bc4ee155d74d52258283a047adc7765350635aa02b37bd75ef60fbfe2aca01"),
-    field_element!("0131931432e8ce4421e1efc113c96dd96ee1dab6bf83dc8ebe17c05cd055d234"),
-    field_element!("062e9ded31f36dfa620f582e7db2817fb7534b98bdbf195ae7688434867200dd"),
-    field_element!("072ffb30857ef93e176bf55859f4ff2f40172d46bf65fa1a3ee501c09361f7b6"),
-    field_element!("029f261424a7ee3cfe5a2ed315deb727d74a8ac5ff964f3b05467aff2118b637"),
-    field_element!("04df8937156a7710ecb2b7d4019cacd64c07053d35460194a5366cbd81ebe8c3"),
-    field_element!("016afc79cc8c5c63dab3b9ec57335fa9c6a4341979fca58f6c07a06464adbead"),
-    field_element!("0257511a4990a74e14072545b15b28bb91c65f88e4e488a58b421511b4c0cb63"),
-    field_element!("00ea8c202f7f5679f078dd6d4990505d6e8ec13097c6bda3f21c6f83a1d5c5c6"),
-    field_element!("059959c51b473ee2adb499c7ad329822fbd18e2ff74841c99f2249c5624875bf"),
-    field_element!("01524800151e6a1add1f0f1a1b64bca16199a66a15d4945a3c91bf5251111aaa"),
-    field_element!("05e31adba670cfff4b422285d587450960d23639ab232ad6ac1dfe00da417a1c"),
-    field_element!("00f13938563c415c39cd8da767b99efc6e3756ef6250df2ff3796cfebbfaecec"),
-    field_element!("00e18a7b852061e53cbd354a7e2af384325ac4bb729757e0c5b3035eb40bc680"),
-    field_element!("02e2d8b89a30883f3f518e87633f8f51f0a7ba59c4fb228803f42a08bd8be61b"),
-    field_element!("03a8f5648083bbb8b12ea4506174f9b821b1fb942b6c80cb748e3cc43e3d10ee"),
-    field_element!("05720eece545ddaba72b1873f9bd00ee6f839c4452bdb7e54dadfd2a0adf2213"),
-    field_element!("01d160bd2e7cbf8ab40940e8aa76efe21fe3500c6cd3472fdb8f3b13c1fb7885"),
-    field_element!("007288ac803012a2209fb9e1b37348df321dc0b7b7538adb62547b3b55749623"),
-    field_element!("05e4dfde1f3f9c12f1754cf11661c3b2ea604933d39d7cb797667a5164e4366f"),
-    field_element!("007f5f6545e0ce829a39a8991d5ba99f4d6b0ad1fa96b724083d1183be8ada76"),
-    field_element!("06b16cc3dc189e4b6194fc96f1933f8e3c4e79da41a60b67ce87e6c113ea8418"),
-    field_element!("065579a96995ef97d3083a11a23e64c375f50c3168df728d7e0b0085c93baa57"),
-    field_element!("004056b8ce519f391aa89b53f0f075281677c26ba2eae8ce9ed2ed67d209965f"),
-    field_element!("046031dd658511988bcda85686b40aeb3a6d587d23c6ac53958e47c77c015192"),
-    field_element!("079973c4cf1f78c2de59c49603d2dae3094ae2e55386c2d948ab0069ef8d388b"),
-    field_element!("00e69befc46cdde0cdc4735d73c99a192f96226af9b689e81edaa7424480033e"),
-    field_element!("03b8fde3c952e68d27af622ebfe89066c25ce302a405ee550bf90b2cdbd37371"),
-    field_element!("0700c36b20c835870a281ddcdee5a1357978c2cfdbf64e7b19f7f2145c624b9f"),
-    field_element!("050b1bdebbc4477ca2488fec78262e075ea7c5a6d8dd0ed926442c6bca46c163"),
-    field_element!("05daaaaa16a751fda0632207aeb95fabcf70cb11ca01b2980dfaf807f821df6d"),
-    field_element!("01c13f33001c514e10f4db4cc19535d4aee7f35c0580826ba7ea74cd377ffdc6"),
-    field_element!("00acb62afce29aa156f508c6a6d52ab5da7d5b12c091c4592a0bd14949479a95"),
-    field_element!("061868e66493a140e809452dfde9ff5e3f88a323c5224dc0119ed4a682a82424"),
-    field_element!("025a439e645fb7479f34e0a85829c8c39e09b9b4bf87f84f332e1c4648f462f0"),
-    field_element!("03d9ab7fe769634009dd859557e0062cb9e5bc9942fc509c8a8c77a3a6333de6"),
-    field_element!("038841d93f653e45ef16223c7cf6d04560aaf0e3f1cdf8e4405f0ad5e71732bf"),
-    field_element!("01e2a6330be1ff8f44505f272310627a78faf74062148909e1e721815e95770e"),
-    field_element!("04354d1f60555d2cb7ec187b6cc087d26bff39eb5984df3d8ab40d6d1f5dd54a"),
-    field_element!("05c8d172a1eddbacd97d2b65aab630dbd4fc4cb875127e37f680283e8938c8db"),
-    field_element!("004c99fe6c0824eeb1c644e8b43d07bc65429a719e4ead4d22c1a359adff2d44"),
-    field_element!("00b6784de5ff3b2bdb27f18ca5a8fe7f60885859f7857fa509d8807602a86bab"),
-    field_element!("023a6abe588efad83ef83f4193ae6a430810d5349840a38a7c7baad93876f07c"),
-    field_element!("01bc6b37b7ecb8637e979c1360dd829075398460497b42235480129265ffe856"),
-    field_element!("0057689da5ea450cb81c60b4b4eeeadb044b85ee4acf871653c6abe4d8e67075"),
-    field_element!("07bffc4c94fa7b30cbb3a92a27e6127d3af0df508b36e7e9f29d7b9a9f54545c"),
-    field_element!("01e43ddb8fdb17b7cf7787825de8d5f4db9398257f3b56eaf3617c45fdf0aa53"),
-    field_element!("0610507234648ea81fea6ee4ca5e66568dfbee2fc069e1a1f63d6e9c197585cd"),
-    field_element!("04080e743b3d83bc3dd2e6ce3833f0bfa95d1ea8b03c8217490769950c6dc97e"),
-    field_element!("07aa97a0400519e16c4680fd5a315c6965c4f931a274055fb3906e0d71a4e623"),
-    field_element!("06334039866a2fb8cece4ec51b2bcad4d4853acbca6ca59a56e68692cfc163f1"),
-    field_element!("0131931a207fbe822bc76f256c0b5229129bc4a843bb64da190fdc17abbf286f"),
-    field_element!("0011652504e03eab5f709a58974de936541c48953c0bd75d8895555d0af3dc5c"),
-    field_element!("033d704c1b5c26f7a3e010d9daac68e8c8dee3f7ed47ea3b8b1fc6a61e1dfc7f"),
-    field_element!("07af59a9664ef15abfb2e0d28057f5d1c5bdc2e83a9448423fbad2dc13330e46"),
-    field_element!("070ce77db5b2c619799e4a0694631f6a8581bc25ff6e825b406c550b83c5c26d"),
-    field_element!("0073872a26ec4d40fbc4f7b22125e5a70756fa5df994abef249f27436854ee1f"),
-    field_element!("059a8588551955d9ae833092b4752f7be78f58cc22b80b8255a5690df1db4548"),
-    field_element!("0751e96b89dfcf746c7c63aa841e48bb2f10d3e2915713ae444961335316f773"),
-    field_element!("049def861051660704ad03ebbea664bb9403037e3d6d5dd548d0bd6174f646a9"),
-    field_element!("03ee030db9f62b46511b71d694ad31dc1518c8f65197241dcdd49773a4c1c5d6"),
-    field_element!("009030caf932e5364699f7f4fd78bfc41242aafb23bfe0c7335aaa95ed85a160"),
-    field_element!("07e0cf28dff178bcdc9088171eb4498f73e9bb7e58fe5438128ef271c2cd5d3b"),
-    field_element!("0796b6766e256ad560d93636d18584b0b2a5d1558e05568b1bba94ac6be29332"),
-    field_element!("0169cc3bfc005c8892fdc70ad7edf5f0db05d90f4b8c71eb16efb07ff86d21a2"),
-    field_element!("021eac65b77c01701e21f54888cf863c9c202b84fc88feb170b7afd7ac60c1e2"),
-    field_element!("02d0e9112318c49b94c15149fd001e4d7aa3a3368301a147a2dfaf82b4d48ade"),
-    field_element!("00b86e23ea194c33addd20441b9bdb8a86ad4683ba3326504f5725b66c1a95d2"),
-    field_element!("0121710bba07f4772d3194d24f9e72a0fb444914237f4f9f6c7f2cfd0a85961c"),
-    field_element!("04989e7f98a4c94566c061da4cffd094a259e46aa7f71611c4962e3214888e96"),
-    field_element!("056ff0a9cd82e9b101c592675908efdb84d49d10b18d8fe9d14083d99ea60dc0"),
-    field_element!("0312f1b360df9122c969c932c0f966076325e6a9f48c4f0810866c9ae46afe14"),
-    field_element!("061a6f840e35a3a37b6be3e6a20d16fdc006129493e514222df2e99d1bc1ac96"),
-    field_element!("049da0451f1dd6f909f1fe0df587eb5277953831909bc8b284e456b502ca0d5d"),
-    field_element!("053608b8c858e80c2f03b0493a7b12241defe96cb7e3d32f1505141d64495814"),
-    field_element!("077796abc22de11912e815cb2064113110d1411b8e5bc5ee3bf5fee9e0eb0a48"),
-    field_element!("039e6976740184e5b5aff616eee344014072ce5771a77fa9a0a5aaed28c16c06"),
-    field_element!("054992144f13acf04d34719a881da26836e3b512e8b690b7e89a57ea363a814f"),
-    field_element!("042bd6ce3dcbf0795eb4bd179e0f7e407fb8b2da1fac3faa940fff8b77f3567d"),
-    field_element!("01658362a9525362333e5b6a05a1be778b45092baa3e5fc9b0667e0c21e14a6f"),
-    field_element!("05edf3ea09d62d3f9f4a88b51efcaa2d4e718601523a5126deff5fbc50746d2d"),
-    field_element!("00e36445839514289d199f003abc45b50a2160b137efeed425b819d597c69706"),
-    field_element!("01a7e52bd14da5890c19021e156cba32ab0b635df1adf9f40f4606494bfb5895"),
-    field_element!("05e45ebbd2ae56bc2c1dec6844f2bdd9895ada9b173197284006ae0a82a639bc"),
-    field_element!("016ee266c3e55e6f12955f1e9b53ca8509f34f1d30b354311d581b8fbd813bad"),
-    field_element!("058a83c0bb612351931321c13731c4ec743542fe6c4980046931fac213dfb269"),
-    field_element!("00add7fe3a3533c5d5c6eb748f6dcc89d3b68554630c8fe1d628a14547fdda1d"),
-    field_element!("03573c95d66867c02105a22d878c8f2b076c1432c49b92328845580e4e297429"),
-    field_element!("05d0888897702452825954a713696ed2d1496dd9f31c13a72fbd3e413f43fb1b"),
-    field_element!("04f89ff519258436f4856d4b68ea1aabcb4c21e1b734ad429fc0ac64f64db7ef"),
-    field_element!("01ed3f0b59c33595028b636d0609bad5b51cc4717c2d18e590906a88028bda26"),
-    field_element!("02c8866175c05a81ccbe2320776621b2d31e20c1afd8c43e0d3e10e38a70c77a"),
-    field_element!("07350e5bfee552209cd4c32331725655117552c8364424a38918d2bf72db7a85"),
-    field_element!("04f2bbf4855a19eb8024b190af9a915e286355e95c6838719cdd7c15bf5b3f26"),
-    field_element!("00bc1c85241ee7516d271887bdb0306a2361691ba0d3a04ba80a409b9080a114"),
-    field_element!("0436ebc7ee3b6b8cfd15145305ba7c9a1667454e6602b91625a8245ebf9aae38"),
-    field_element!("05a2f5b2a8898cc5d28274c31ec29491e58eb7e11aaa65313a975ce78c75ee6f"),
-    field_element!("023d6395496230f90beee7aa4e40915cd1133e608fbd635e45f229748adbe8bc"),
-    field_element!("05ead481d9c8396f4a13d98a91c1bc9015ac72e67c2c1cbbb8491707407e4caa"),
-    field_element!("0438cc60723f694314bef8f3cab4465e864970ab63ff4c389b3923ab9be72497"),
-    field_element!("0523e74a8777c76a9f2d01adf738fedf4f462484664ed9a01e2067eab12685e7"),
-    field_element!("038121277eb2a973091839b173f315f97a39b827b9f5102e7e779ccfa61c11ec"),
-    field_element!("0371d21f1e846efb141fa47beb3cef04297a3cf20da48adcbe6a71cba6987b60"),
-    field_element!("06adb4731f30b890a9f652158495f050635ac7d48c69d54af96b10d3c8e9570c"),
-    field_element!("032cfd5ed8308bb8be3962168d5234fe6e4a5a83444675e65d492af33dfb9373"),
-    field_element!("0731a3f6a74a7026dc71df86a95c60613ff273dca5016174d316fc71b6020912"),
-    field_element!("040f806d2d7428920e664458563c9995fa971f79b78e7cd0ed321d13b3b6f819"),
-    field_element!("069e0316055a8ffcb4c2b59947047e566ce3250ebbf0850845fe15e470539728"),
-    field_element!("05fbe42fef09dad5fb3db612f9330c36bf2d57bc8b7ba77b820778510858d563"),
-    field_element!("011cb334b9d544ee4dda46fca4ac60f66f5e79931ff9e833e07ac60287a0ecad"),
-    field_element!("04df0e35a5462bc012a8ab7941ed864f8cdfed4a0d61f9be23415e2ff32ba782"),
-    field_element!("00d5b405097c4273b535b6146c9a34dc0b4f6916127669433e1f8589689e5766"),
-    field_element!("026335702fd2c9f6ef0e5defbd103e1262b62c0904a56bc4e7b332b3c8ff9633"),
-    field_element!("036f942847a6c247739faf375415216b5fe91f25a8c4072f45ca9ba584aec9df"),
-    field_element!("008b38a0397038508e5f1d0974ab9df926bfab875922c8569e9c8ff884114dc1"),
-    field_element!("054b02cc59aaccbffd9de68ac671289d4847c20da2c201f875798f66f366d42e"),
-    field_element!("02fb58e1e954129e74b5f8d01d0ea2100c1b2597e746e86f1f3e358fb0eb62a7"),
-    field_element!("004366e2e1f1fd489fa2f2ed967d89fe9bbccc51ec4168d0ce52b0e05326dd93"),
-    field_element!("04448b9b443589261f3b6c038e18871aa1f6548bfe3dc6ca3b0bd7f89452c6e0"),
-    field_element!("04156b89bc8439cf3b0a505d4e141ec815edc0343d8635f3697720b3e75a7e5b"),
-    field_element!("0699f166838c5f692e4e06422d2d3bdc665e69e518acb3060625d972e7ce4b65"),
-    field_element!("02318ac43d8417525344ce73a5990f200c7ddb2729c377c5e1671ff934763aa7"),
-    field_element!("04b0c99c58cfb5ab03e3a70e739926b6547e544ae5aec921a89b5ba364e9ed57"),
-    field_element!("016e75ce7a8357de86219dfc8ad0b2d0d1b6c40e781609aaf8e6968efeea4c19"),
-    field_element!("04cd319b2e69efb133b01361d7a8337c47c775241091bd8e48824ce64bb859a9"),
-    field_element!("038e86fed4bf2482243e40bb6a07db93a33293c668f85d400c2f0c930477f5dc"),
-    field_element!("0623f4b5edc59b68e4c6d256ff80680deaf77c12f3b5828e19cfed818c5674be"),
-    field_element!("043a7b0c192f3f18826bc44dcc5dcf1057bbdfe28a612e0665f0a9d6770d9bd7"),
-    field_element!("043ac40880ab2b3245cfe7bc1ab1cd42d008a26f3dabe9b519cf437b03c613c1"),
-    field_element!("05d66a0bdca5875be87ab523f3d7f9d63012a80845bab512bb23db3d18562952"),
-    field_element!("006a445fff3c060b491e2e2498a410336e68fd204985933019c778c3af3868ec"),
-    field_element!("07e87d4f1fe5567444d7af09070558d281467c3adc84658d3537b9a58172d63f"),
-    field_element!("037f7500a25fe04cc813abbea83839af448022395d2265328cdb05f2d6dea0a8"),
-    field_element!("07df7dbd274ac85727c5990d5bb8f7da9d499863211418d3a796f6fd40fc9d68"),
-    field_element!("04e71599e47b66c6f603910f44788c077bbcc9f572f111f4e74a1492600c824f"),
-    field_element!("05e762cf2f7de366082f00ce0e02d6da0be88ecac57710f064d6906b10ddf49c"),
-    field_element!("04943d8e2fb2005e9cf0ba712ae483b8a7a1b9ef10a786f3d85fa6f5cb7ac90a"),
-    field_element!("034f0b72285bd8fa4b21bb1cb86934bf31f2dd2b104321c9aceb44c7f3a9d713"),
-    field_element!("029e38f52fce18673f38dc7c6a7a05b11f1d668e3886af7f7edb40accc8b389f"),
-    field_element!("03c3f7065d88e2e7acecf183bf19aecc976c6138a3a4fb96487f1a0616c34054"),
-    field_element!("0080e50c5dd40d0d763cd517f407f6693643c523f60deece96bc2282aedd565d"),
-    field_element!("0431aa7014b6a7cf832cc670269194fb55336341527daf00663cfbe29a474e85"),
-    field_element!("0762643c63e659758c16934aeac5b290e323f2840d2f918c06b7db3b323f063b"),
-    field_element!("0731250eb2ca2f795e621f808dc956fb66609b1cfc4c255dc57fba2b3723d8cd"),
-    field_element!("02e14572250c2913f5e2b699dd2a8a206533a9ac25e52ebe3d9605fb6d28efd1"),
-    field_element!("04af8217bd295be4e499ae883eb05b205dd08ad370c26ef305e1c805e2973dfe"),
-    field_element!("002b8f5741021cce068fb66706d83e9799ef968b92b22cacc88a1638b9ab6e3e"),
-    field_element!("05b44da04dc35f1bd96d415ae779ac801591c99247ef0a9076e96d843b31e633"),
-    field_element!("02bbb60161b2ecd968e770ff1e0106685e79486ca702abaf431f862c830f96ce"),
-    field_element!("02ee38d9275eaf605f3bedb66f1f6c4087276c721d293e928594aee53373d3cb"),
-    field_element!("057263cd932d2442a3e6b6f350f515f8a8766350ebccc50fa10528ce87058216"),
-    field_element!("00370cbcfe3f0c7c5836637a7511cdc58f2573ab6a9b653d85a6b8834fc63e0d"),
-    field_element!("007eebc25103b14517e923c292b7bef622d651c9f3a6bbd5fad1931a2543bf7a"),
-    field_element!("05f94b39cc423041bb7c1f78fd74d25b0fa9ff0d02618109db67b3215f64d840"),
-    field_element!("05d3e1f3de7299c958de3759bdc1a03f3c38a5b764a3dc80c6495aa877189564"),
-    field_element!("076e79fbe3725eb007d8719147c2eefc8c31782350ff07416bc073e3d2f0257c"),
-    field_element!("065e816f4ea64f1c285be566a1b3fb3f7fdc4e9c871e835a27c5a53e9462e795"),
-    field_element!("039e167c6f0ed771d930a913cadf03af0a9d76f2b3f708228f18962cc578455d"),
-    field_element!("07aa8e696ce66b05036d9317811b0f991ed869669ea3381b19a333396df1c9cc"),
-    field_element!("06b7956c5b5ce677f6798cca2438d4548eb5260c10d05629d6220f496d4d4354"),
-    field_element!("0280e654424835c2c909fe3e404b2dc94bd22a35ed9d00895facb6854de06a1a"),
-    field_element!("04ad3a7fb6f4720eff82b8436801ab4bca10d54a2587ee07e17debeef2f7bc36"),
-    field_element!("0428908439871d63ffc25c24e13d7603b7cac6a9e16d7e3dedd1e0d476755f1a"),
-    field_element!("069141318e494cd29b74b10fe0e823ab8ab0a4db2fd16e331b7962c26fc9e8df"),
-    field_element!("060d940e7b792e051df3eb0e4ced3f4f2d8b8efc87c442ba653c48935c04bb42"),
-    field_element!("069ae5172fdcdb9cc6aae8afb12cc305b7e002ef90b530afb243e4456666671d"),
-    field_element!("02cbf3731a6c5e522c0a1050e21d750ba8e62e173b34488082504b0f43e280e0"),
-    field_element!("033268bdd67a02cb4ec9ca7ac8b140fe1cea631825ac96f36d03d4a7d4cee47f"),
-    field_element!("03d2a476707bf963cdba09c0b0fd926f4f70f57f95edbc61bf114045f24ee198"),
-    field_element!("055a057dd155ab9dbd86e2d17f65226e30aa268d47ecaf157681b5576d7d602b"),
-    field_element!("03652790a89303b158757d87327798e5a0c23e58803139be700227594b81736a"),
-    field_element!("0205091f0c0979a9bdb63ce49b66f9340639049126d5426a0dba0943473aa033"),
-    field_element!("05c2608f93426840b396159ae3a84c63a887de0e67329f5d394b5a436e31c420"),
-    field_element!("0617d9ad9dcf73786fc3f12757dc876c61fc9221cc0166ba74f44dc257e78339"),
-    field_element!("079fb6bdd56991f816a678fc912771d71772079cb4f899f2fdaa2dcea8c7b0a8"),
-    field_element!("03568230dfdc7b3db1a5ffd1cbf2c1daf614b618f76f282e4522860f6fd754e7"),
-    field_element!("07ff710983e63d924963ae8acbc9a1255aff7adb70ca77e4da6de1149eb2437a"),
-    field_element!("010b7fcc30d12ab0c14af5a5f1afc8ac3ac28585b3263aadb8fe3e786d06fee9"),
-    field_element!("02b911f579fd2474fc142d3d05d83bc509713d689afe084fa96b995cf0fa2b17"),
-    field_element!("06107e398775c6af76cc09201ec3cb92baceac829dfccb3243177bac1d555323"),
-    field_element!("0125468de72ee98327f24a9e36a73cd9db5d369d6874e62afcf24c6abe2629a6"),
-    field_element!("04dee3d44be8ea733da0998c08ef2c3412161e27e340e2ee1175f4b5ef868114"),
-    field_element!("01aabe5eb0e3ec7a03aa46cdba7be5bf1c2537614c5a5bf5231aa5ce3f0c5dcb"),
-    field_element!("0165066c53ffdc2164ce189424c5b9ef2b8815e250b557c006c0db7fcf5b01ff"),
-    field_element!("0051c53461fb1a22d5aa7c6f12148b9ec8c29bb508348c7a5d5ffea4cfaf4d75"),
-    field_element!("01caec2355b32264c2fa9de09ea45e9c4bafc3c51b1d1f732214390541ab3360"),
-    field_element!("00dba56995f685e4d9bca637602f23d34ccf22ab4d8245f12c283851fbcd013a"),
-    field_element!("04ea75ac2ad04d519d3ecde4ac24fb5cc80071cb4e9bba1c6b964065386372f2"),
-    field_element!("0578a76468223ed854ed8a0ad9b189eb25efd0a505e1028bc2fbffb4d8753dd7"),
-    field_element!("02e6e2d3b6619ca6ab0ffcece6ec191175be1f37a1ac0d2e4b5d5a0324433db4"),
-    field_element!("00f501b53ce118289163e4f7bae3ad7f08e08cf62ee5b68439194c4f0fe4f639"),
-    field_element!("029e65740149eaa147ffe397b9068714472885684230adbedd0655581eba06e0"),
-    field_element!("00495249b68087f6b541e7f1bcc68016b62ca7f07ea217b2337789d235229af7"),
-    field_element!("02092d4fc36aec72c5c31da25ad3f728469213bf318ec1724e49cd22711f1107"),
-    field_element!("06c628cb46a73ae8867e63a50be8da04540157c6522765bbb247ea0adbc8d5fd"),
-    field_element!("05678412c0684d62af5a06a49d1e039a958dad0388b874787cecf55888b86c3f"),
-    field_element!("074a53cff9b0c6978ce3789e85a0635803ba689982e895daf2ebf2c55ca7345e"),
-    field_element!("058bebc1d4b16efbf993306aefe56ce22b6b1e026bb9ef05c01da1aa744b3c48"),
-    field_element!("037fd2902ec710d10da824ae15b39829b9459cf1a258a10ec178de275e9b443f"),
-    field_element!("024b8d60bc381144cc31c40603f94d471e5e6b25de7bdadc29bd0fa5bb7556a2"),
-    field_element!("01e7b51070ba8dca118e1ec4ab0975de1beef7ada2548c6bc47980164fe08625"),
-    field_element!("0503fd0f2c2d86bd2e4abb7923d137389d1aa8f546ee575bf2cbb76a7c0d2c17"),
-    field_element!("05f9892314b3aff4715eab9fc2be7f9aa3e0a127f5949dbe731ec79725668dd1"),
-    field_element!("03558c291e26dafe4cdc159096a623703d835e14b811a8c275a9387bbddb65d0"),
-    field_element!("01b79ef228429a298ce8fadc3f49480819e63932546c798bcc75742fa1ba818d"),
-    field_element!("0074098c7bdb05c7985875a80cd0a2c783db46ca0bec3c6c255b87951aa15109"),
-    field_element!("034938c30f8510ab537ed749293faad41bf9faaffa241b80a5df7f7bd04684a7"),
-    field_element!("02a0c2c069a87c17778d811798173112409d1e4e06e2cf2df83eeb03db9cd3b6"),
-    field_element!("06bd0dc83ca99a9c3be30c03b61f04e6a4f092a0ae97d846c9db52dfc12780ae"),
-    field_element!("011a82fcceef57a1ffe452b112b7acbf552d2c06179c545c6c0ae8c02ea492a4"),
-    field_element!("04b23fff000f2c5c0a4d71b05d8b65a4118dd7457ee9318555272e302cda1e90"),
-    field_element!("03830f91525cf8888ddedea85dbf2e81722bd76ca9817d8d3fd1807e696b0c37"),
-    field_element!("055c0550d3f97f5d41c5f91f8b4d22f6ee6a434a1cbbdf9acb474233938311da"),
-    field_element!("033c41f67ff5dac19cf4155c87208c671776717f7ada9ce574bc2069584c7750"),
-    field_element!("05c3a01415f5b2bf7702b8669c269e2acba853feb4bd2ccc813d765be03fa644"),
-    field_element!("039b27908b663900e28262cc735bd98b163e0936d15bb52799aab34e4ec75ec5"),
-    field_element!("0407507d73fcc7f0e3241fdaa10c118ed7e8f021190b857d0e5923c5e3d3d595"),
-    field_element!("008f7ae3e44cad2ec99651f9fa5585640739671479058f173ff49d78d20da9c4"),
-    field_element!("00add35c1ad5e70706d4a8b02401a50b440fe1db63cfeec174616fc5c41577af"),
-    field_element!("072269df46a9b19cf092a1b355cc366ee3088e359d3d1adfbf3c44ea85a28cd2"),
-    field_element!("0742f751607f56366c2677746c33a4342a59201e21e506d5c124780abbdddde9"),
-    field_element!("04193272575f17e6237eedfe214125e4be8ba3dc2df2b6cd0ee1c1c10e8b5593"),
-    field_element!("0701206b91eed1e033852fdee0c24acad206fae9ff06a61a7148f41a92cdf130"),
-    field_element!("05b3f6c725cf619c6d434f078a09f8a9548fc63f8957444630496d93df481463"),
-    field_element!("02e3a9b6f28629487d0f65a3bbaf62913ba841992a0d9223b526f159128be91e"),
-    field_element!("01540059a34b9d0524f11ef8ebeee200c5d6db526f0c76f56b9e6ad5723e4536"),
-    field_element!("01d19ee0d37806ea369669f19c077b7d738f9e2c94dc8906acd94dbf5494a716"),
-    field_element!("07cf7449f2645627f5cf710f8134750c384c13a57edd5dd2e7193a1d1bea2e09"),
-    field_element!("0794867ae767d110d7762ddae61183cc8616ad192733710e5ae2901fb87f18f0"),
-    field_element!("06350ccd43d344b5382cf26c0a5c966706b9095476eac1bd18549b5a1396266f"),
-    field_element!("001ec3ea85388b5214fd6451cfe7c70c60895e42de6399259cda4bb56cc7e9f4"),
-    field_element!("007d8aba0e42402813b9ef0d2c915fbe253a79a4d6f7e87e49ae39a08e6b39c3"),
-    field_element!("00be6f125912d6c4b08705bae853ad603f6a9d47367397f1465ba5a5a41399c0"),
-    field_element!("06fa80db8bb141787a91b1f911317ff847c506326d49655c791d87c93c99aece"),
-    field_element!("03f373a5d80019150042c48235d8db6d5a898ccbc9e814797535a617d7c9a53b"),
-    field_element!("03f9c124c49ba62eb1d982256fb09775815fa4f9c60671bd7743da7328a54534"),
-    field_element!("0185590b69fcaa4b9dea9cc29fafcc803f19e090ee3af28ff02797e404dfbebc"),
-    field_element!("0699c98e22f304ada659ed8f7fadcad614e397ffb54c54e746cea99ed975db12"),
-    field_element!("06f39a2e7a1edfd8a2800c25765f91f10017e544e97b619ff1e99da0e36cadc2"),
-    field_element!("07ddbcdf1f445a96e0ee1711cbb49758700f448af343d3f5cc061e896523edf5"),
-    field_element!("0264bf549b4348d74fb1a0f44e3893605b583af5a2d346f4977eab4e809c7fa5"),
-    field_element!("01fc441e8a221dc1b25fa7a84e8580b57b3a2a64ffa6e22febc08389314f39a6"),
-    field_element!("00b15a7e779b467a99c59cf9f8a1c263f076058c1586b2c5179aa6e1c8ecbac9"),
-];
-pub(crate) const RIGHT_Y_COEFFICIENTS: [FieldElement; 256] = [
-    field_element!("02ab33c443dc0f34064ab867477563092df9cbd56b14b519c3a6b8fc98de01c8"),
-    field_element!("030d20a6e56a93adaea7c76e57fe7ddd5aa61db719df20f450247aea39209a30"),
-    field_element!("0445ebfa0936afa075ee00b70c2427f723dfb0c59d5d65cecb3ba5a7e4ba66aa"),
-    field_element!("05ede384f6553ddc23a7f2b6e7d3ace5758642e98c150e55d70ece4b873981ea"),
-    field_element!("01d6e115dae812eb3b42d3178559f113da3e638b9f5a30fc9d6a4710a1ff0678"),
-    field_element!("05a9fce513dac4fb36e099103ad4bcb33e89754cbb489759bdc530ba9ae94863"),
-    field_element!("0333f1a5866efd223fae1e7b79ffd0ac2caf7727abe7c90ec6e8c09e7099f6fe"),
-    field_element!("0267d9d120270750b2b367ea6356c71aa921de102f56f868f0e43725086ea6d4"),
-    field_element!("065c92a4275c5328eebd0ec9dbd64a526b5336b8fc12aea9a1988f6970a7294f"),
-    field_element!("025ef360ba34f7f0b7d6525d5a048026079515f365b087bdc9e22a782f81bf0a"),
-    field_element!("031a702805ba836c9ca57e6134c00673a384837467a4cbbdcbf64c8afe8db994"),
-    field_element!("0092cb742dbf6a2a23ba59b6c3d7c0d0832c7ea42f1190152c3110850dfe58fc"),
-    field_element!("06fbc04846bc257d0bba4d32977d517b03f0507ea5ae13fa1fc345fd2a3b093b"),
-    field_element!("0365752053d8e6b6c9256dded4b8e4c0eb86f0846cbbac39dd9b977dc5741ddd"),
-    field_element!("070e8918cdcbffbfc641664c1a1214317125083b693f0db9e9de5eab7ef1d743"),
-    field_element!("00a9f57f65698e5bec6668f0e2937d2f434f9c7519ba82a0e67ad5590b2a586f"),
-    field_element!("03b004e93805a20260bb7b856b86963f410e5a634e6072f7c57c8b922c971163"),
-    field_element!("06f79fffbf4a0d4ebbbb510e0e3d4a974733baae2b9ca013a62f973a527baa79"),
-    field_element!("0406d106fb165a75ee6b8ba520544dfe8882e5fdc6e0933011ebcce901b19dc0"),
-    field_element!("008b125585b7723d6793d32d229a0e8865f2bf2bb9e649ed9d26fc5b5fb13a0e"),
-    field_element!("029fba23e161e71953d2d54bed2205e71c02d115062a277ec090d71e9b3655a3"),
-    field_element!("0224d6125b2798851f5e78025908c8011e4cb331fc1d0a0c18703dfb66a51f4c"),
-    field_element!("0220fd73afac0135ec974c49cc4410aa8b426670c1f048c06ff49a8323dc84eb"),
-    field_element!("03887752e743625f317f3db4a1e232e6975c19e20a8166099123f82a30ade2f6"),
-    field_element!("0542b822a083837eb3c4007baa6fb497b95999dac7ab90e289c9e8825732dd1c"),
-    field_element!("0726ac7e5fbb465bfc7e76556f015259eb64707c7dc21bdffd77c05fd2e461bb"),
-    field_element!("075ffb1eeffe585f8e2fc77818a71caf1dedfd2330f5283d311393a15b1a758e"),
-    field_element!("0183d2937faaf8a80e5060d6f9c4309f45d69e05748418654a5992427d8b2e3b"),
-    field_element!("00586c22568f50f660529762781fc5360d7a91b941615dc1f7e0dc0267091021"),
-    field_element!("01e81453d4f639840494ee730b077e75879c176c0a1eb371d9e322921c30f185"),
-    field_element!("016ca0dd03a23b0c51f407645783c50d6a9cd652792e92bd176606e447d2b9ce"),
-    field_element!("031424804d5ee1bd22f592c3b54bb4c38b8803e7b21bb7c43c471115db7762c5"),
-    field_element!("02ce8c3e060fe22e36b5b9590f69e7c62625be6edb62b0f77f7c0ca7fcc95ba4"),
-    field_element!("07bcd67a33591f3efa8e7062227c1891f8a1c597ab06710e163ef858556dc01d"),
-    field_element!("01f1826a0b15b50c71c8790f87d9c67a87be73ef62e98a2d93cc65fdb626ab96"),
-    field_element!("03387aa7cbf4577515b6b051d9cc9b8dcd41846f334a45e4f4f9f58e12798f79"),
-    field_element!("03d094f30c077b6b10671bcb116afaea76a9c7b0a3d7efac18d3bd63c595b7bd"),
-    field_element!("05bd7c0e72b2f8f75f01a7cb23f2001e3d17a067e8dcf2fd76acd0648cb69a51"),
-    field_element!("0337d7b64278ea303c30350e8f1a87f13c74cf3356cb7384de1836aa171af4e9"),
-    field_element!("05efca2a7c521f6c9cd22dca558bad1cb7bfadf723c29f4bb8cdf2d859c249f1"),
-    field_element!("06c46f5686cf2b41aa11bbc20afcf8e6e4048ef0fb22597a98d7734a03b647f2"),
-    field_element!("03c01ecaa44b8af1c53190416b7b69ca720e6e2237462b84f2f2c2a635582d66"),
-    field_element!("074e5c381519870bbd6200af123d8aeba4848d6989838f5cc327d831501228e5"),
-    field_element!("069e8d5cdd8fb3cdd1d078b821a444781f32f7b7d347a7c8594b440a923accc3"),
-    field_element!("0751a90487aa40765925cbe90a05a9f8d923489fdbe4c0e3e9e16902d4341b8d"),
-    field_element!("052c62d2d346e020f884054f363bd694ad79c699f67e5316b4053cefd137aa97"),
-    field_element!("05ff20d14e67ff6d64742122fb62692def4b1df7c210c06044a885fe5e233b10"),
-    field_element!("06f6cd40f8d0d627bcad7566a4a1b46177a9dfdc1011634cdcac9d0ae3c61230"),
-    field_element!("0477968c9bc746b70b55eecb8e2549a0e68972757f768b696e8f31ed0ba25c4b"),
-    field_element!("00c18531d1b45478ff92e56bcb32cada0e33845a4cea7ab3749c74ba1b56c51d"),
-    field_element!("060683ca902dd6cad95bd709c1511f6913f7d0e98b520a4cb05c2fbceaa5b2f4"),
-    field_element!("038fa88c3250d5d2bf8a0b97e783c5ff9ecc2393347591f68e8b93b64d014093"),
-    field_element!("00774dfedc552f6dee0e388a4c77ef407633b4e5c547b8a3e4a1c4d4e304878e"),
-    field_element!("04b2aaaae70b1672ad2f9b5edc72f51ffec61910a01e5e17ebca7f1442044891"),
-    field_element!("01bdec33615b706853b413f3c3f9e68fde80be0cd850bdbc9b3ab4bca17452ed"),
-    field_element!("0541f533016ae744f2acf538e652fbd8318cab686f8459c4fcc8b0cd2e6cea4c"),
-    field_element!("003836b2da69dede51386f4364a5cc52424eb18fe6c49c813fad79e591be74ff"),
-    field_element!("00f160f0c78f34a527a2f5e2f4dbf8d64da615bf0b4b00bb1586f02a82300c31"),
-    field_element!("0323af9dbb9f26104d0c5534a1891158a6d78dcde65c4f534397963ab73df821"),
-    field_element!("03b43ae9d8d21a4288b71434fa72494bbf501664ef2b70be916e1dc1e40cbdfa"),
-    field_element!("00211ae45ea5d119fff8e7f073f5fa619323c454ea1eed02911307cdd6fb6870"),
-    field_element!("0047dd487dc4a935a3be5354ca2ca3795f39bbf57632b32ed38f7f60dae3cf9a"),
-    field_element!("03d224cc088be22d3b637ff845cf04d2381cc1326946d0e8c06bbac519d2352c"),
-    field_element!("057e7f0299565250db494e0e8faa21a1dd8a6151a4454dfcc7231d45ea137e41"),
-    field_element!("033d16e25741a06014f72b2b4f84a0963f40570e1cfd97570cbe6af357bef86c"),
-    field_element!("07dffce9469df8bc761a67a27ca95014843074f75d01a8d7a1a025c43acf405d"),
-    field_element!("07d86259504fe2bb26f36b90ebe06526e4fa71dde97eaeb84371c3e0c803a802"),
-    field_element!("07e309c2db8ec9cd77095479541c10f6027bd88f9834c95e6d2fe9eb74ee6fbe"),
-    field_element!("064fb9485d55e37bd55d1af3d0918c04c0b15105d525a55183944540228898b3"),
-    field_element!("06d7884c771968adc259a5a1c4f196f0f27088fcce481c2911ce211d7f3aadf7"),
-    field_element!("042f2947fe13f9fc051ea2c716e52e6b3fda35ccf895c55945f755d0f2f4f273"),
-    field_element!("04f17edfc75e5a2d193152defb86da87370e8160239bfebe0ca3a66a933385e0"),
-    field_element!("07215f243024a1b8f3cca596b579930d09947d730e87a3341cfd7f44f1638938"),
-    field_element!("028d07df4284adc706eeb50a50c463578f6ce7ccd52237ff34114fadc470d6ac"),
-    field_element!("07709f166880dd85872e4165d3c466cf8cfda2c31978ada3bde52134ee04c2ee"),
-    field_element!("05577237824f934fb0161953a9b810ad92b6cd4a7d2805c71742b542f9673487"),
-    field_element!("02c18bf2a45a8d9ce36f0eee0ccdad99cc7b41785c79f9fa92b953674052e21d"),
-    field_element!("02d476d9fef4cf0c5e964dab830798a94814e00dcb2fb5c0b467add5289d8564"),
-    field_element!("06c4bd3396a971dfff1cbd539b107a8805ff6374be29b6f70a4a158e1ad9ce2e"),
-    field_element!("057b0cbc6ea9689e6ed3464397e234185786154346d67ea76772ecb09264175a"),
-    field_element!("0334c0bd333586e709fbac18b1363a8d74a7b6d86831c6c6128f8bc794ee7ea8"),
-    field_element!("02a060c025d1c9800009dab5fb0ef618dc2b3da8725e1748a70f4d0e1c7c1d17"),
-    field_element!("07d4da5c84f0252f0ae95625acdf7c36bf27ae754e07de3c696dd4f398d5bfc2"),
-    field_element!("0010fe7a07836d706043c9c97957f4c295d5ee939aa80d35d11d597cf9e8b33c"),
-    field_element!("019c3ab437c6e856e1bb97a9e6b0b6b4f7f43d518e525cc6872c798653796ebd"),
-    field_element!("02d1c52d991e4ffdb2736d289f23788dceb6ab774f401831109b053820591edf"),
-    field_element!("04738b7438cbd608cdd447885a18025d26d3a4ec69425f26a62a9e3ddff0729a"),
-    field_element!("0286aae607c644e10d332d3b7c923b6b0b107df3fb352918356ef5087a68be4a"),
-    field_element!("03e0878de1f5f90f806af1da23bfea45391e8f2526beada55b9bd652e9fe193d"),
-    field_element!("02db7008668d9c04e4017c1b7a562ba4509436328ccc8761195d4ca987805f03"),
-    field_element!("03d20c45575c8246f6c4b0256c5a3c8c00ae9f9a0a607f9446de857954e993d3"),
-    field_element!("029e07c28b89a9def18f22e4a1e455b0a0e9b0e279a6440d16a8d9ae40e2ae84"),
-    field_element!("0519a3fd410b0657ffcfb7cd4e2830e16523093a745204111cff0ecd475dce11"),
-    field_element!("0525cee7a177070d0d524078c2486b288168004c554c9850ee655da4537eea40"),
-    field_element!("039c0c8c402ca2e73a7cf57de0bbc6c998f751bf23632aa7a0f6f7292f8fbd8b"),
-    field_element!("051d623a94b3f45098a1fb6ffb310c17511cede90d62b87902aacdd6eb3bd004"),
-    field_element!("05ed297fe39b9b73ab59bad2b40f600d95267e930abe8123c92ae197546ec2ac"),
-    field_element!("04e21c8b1f3dd7b05b271ce6d13364ee783ca8003b49e2406212056bd7f631e3"),
-    field_element!("040b0afddcb90a49850b9db0289b00a83dde1a69860b671576b9edac132aadcb"),
-    field_element!("06be94f47acf55c3193fcf31c4c7bf328830c262b261c20dcf8a6606d3d9ac3c"),
-    field_element!("05a8aa6dd9a532b0ae4237cd1b671f4392c8e1e83ad24ce991089872061082cd"),
-    field_element!("0061827e34598df373f4ee6291bfab3ab3633fb8a7b22235edbd26c0ad658426"),
-    field_element!("05f51d25014c95d69f36f5d5fd8046fb00884e6defa6c46bf547f91c3317a7aa"),
-    field_element!("02b6c269ef2f973dc2ba4bd7699bbd0282f3ef79c215fa62e3006a3e0e35717a"),
-    field_element!("00fc123c98f484ca92ac94735bc17734d8ffb300fe787a87bbbbafb1f565b4d3"),
-    field_element!("01a4e0d95d950c0d54045feb270b8605459f0e94a639cce2b68359ea69199884"),
-    field_element!("06a4d6886782d135bfae8b980ed94f648ffbd834a1175b0504e232271b5d4777"),
-    field_element!("04f91dbe24a11f16fbd75794ae92d500293ebd512d359be91ad46627910da9ee"),
-    field_element!("03e8b37653ba6a3cc0db94844de9139b03c80fc7b78537fadf86f17423bd8e9d"),
-    field_element!("0356366acf9781893bf67da28efabd9da93d24ae1262bc9dc76c723e5ea9b9ad"),
-    field_element!("00d71db6d15a4ce79bfd89da80440741b0b50befb4f581a37d680aba3713030b"),
-    field_element!("05294c66e383b786c7e9b7ee8dd9c1bb333c33546ff1765299a88fb814a266e0"),
-    field_element!("03b727a85fdec6b2705ae512063378cec0e75c811803d397aa3463d861f5b299"),
-    field_element!("03e4904db9c6ba0fdc5ebe5a95c8f6f7b661bef2c3016ae47822f2230908e60e"),
-    field_element!("039149f31767ebd0207e7f97635684422a02c9fed78984287cdce2c47c40c558"),
-    field_element!("004a357e564f188834bc66b3cbb1eff0e1e89faf1643b90bcdd5937fb9991e94"),
-    field_element!("0286bdfcb2f2a000586dd5d94551feac5bcd7eeafe9feb32f851639a843ffcb9"),
-    field_element!("049b020911619fdc209ff32cbf8c083f7c23eea330d9e63278562c0758e5c1a5"),
-    field_element!("06669236022e0456841a09105e585297f66bd075d1ac24aa968b598b37824d0a"),
-    field_element!("01bcfb2474e15700058c7f0820b321c6fc1af2b01d335a315a60d51c9bf56a15"),
-    field_element!("06676504d6d863d0c6975aa8b44c2db8d50f8a2ac63046071ca7cf426e256786"),
-    field_element!("031b67f2e291db478ae4acd03395c40a83b51911594d321d917bfbfa87a56252"),
-    field_element!("000b0aca45836907f33fe8256db34d87752f67e781f2d38a40f519fd696d74e6"),
-    field_element!("04872a5586d20199024704ae5deb9ed4d0325ad38e9f410a43df303512486937"),
-    field_element!("02b62ddf30dd169e363b72cd81353f94633eff4a9d9f1abfa1af3bb3d6c68c66"),
-    field_element!("0231ac60595b25417108499f826524b540a4fef4792a9036a12e8ba91539a335"),
-    field_element!("02eaf4ca42b7258c922725a71e46117c8f48f68da267b122e3869ba4781a9fc6"),
-    field_element!("06e6949e339fcdf9fb29d411bce07ec1384ebef7a12fa14183e6a3af7e087a39"),
-    field_element!("0463ce9deed943898f3c3e418af699e068e7391b12cc43d02220cff794ab08d1"),
-    field_element!("00cd254ff8875b85214489b57d9071ceed54c940c8994b75c697db8283a9b24f"),
-    field_element!("04f854bae01d0b8ad256d75fada1608bb2721554700e1b826705ede89544b8f4"),
-    field_element!("0539123d4d531bab2f1dfaa580dc3ccd8b206fc85dbf21e3110f1f82adc7d5d2"),
-    field_element!("00e1fccdf86e5598fd71ba4fc849b4ec3cf01760e40d084e129a33cc39282d19"),
-    field_element!("078112cb1cd4b75182577694ca9f211978af87d41a19d7b6960ded8aa866d195"),
-    field_element!("073d8cefb8234984a58790887b1640d709d066300d9fa5383fdb56507285d88e"),
-    field_element!("0045d5fb39ed0390c3b5cb166b06366ce7d397d8f5b8fa872fda934c51c61dc2"),
-    field_element!("04d283ab6c0b7cd868ab1f7b3f3f4101d6d70301991c0b850669e37cff0c3647"),
-    field_element!("042e760f92edb30258f48d6963c3b259af8b42dee3f9ae0534050e7aa78d2ce6"),
-    field_element!("07cb63a9944623308b2b79902b54f6e1d3a6bf37e4f234c2bc145e08587c904a"),
-    field_element!("05baa97d1f7834e82f7e0f571633b820a11af5c403ba46ad0da0dd6e8c8ef3ef"),
-    field_element!("04ad19dbe82d85ad8d0371691774c1cc5df1e88a8e58930fa518fb9a5fb320cf"),
-    field_element!("03706865a87f58d64be2f1bec0bc0edca5edc3641266c67039adaac773b10c02"),
-    field_element!("07d1647af6990c16beaae8136bc6e2b9b4fd77bc1cf24bd8a4580209769618a1"),
-    field_element!("047db35d9148dd12295d442e1229b1555f6ba1ea94954d7557ebdb34b75b4a27"),
-    field_element!("05683d4eb70caa8929fd5294d0d223cd106493542c389ce844f0539bf8cc316a"),
-    field_element!("07bce98906951240bb211ae32eab43b7d8bf19384bee98d43864ddfaece33ad2"),
-    field_element!("058cdbefff3d949adf4b3d5ca608939f6d284de77d64312272dfd73657db83fb"),
-    field_element!("0229d84d2d13b28bd6394d202a54cfe65f5d7e11cdf39eeaca3e7b5ef5744b4b"),
-    field_element!("006355520ea3b7618f9df332238411545d617ba92b242e8dd31dffe38a33f086"),
-    field_element!("03d2b9c9c892de79123f101932be19c1091fa9fc8d08e5afdd2218229d341d57"),
-    field_element!("00a3f0ed3dd270308f5d03cb9c556cfc1e0315b5c17351ae7a12cc80a7bf92c6"),
-    field_element!("07706578cbb2d6789c69c66d15df2f131f148fd777720f1a11b381c3e6bf9e7b"),
-    field_element!("064a4557dbf5064986246c9c6d2f4bf784b72cda5856a0d2b0bd2a311618a19b"),
-    field_element!("05962b9e83de192ba935d053bf40c2e9edeb9d07474715809f4e5b4d9a467345"),
-    field_element!("03fd5258d8cd384134e6b56d7e7912fa5219ee78b5cd9a09083cab9b4ae961da"),
-    field_element!("074690f22693d3bff89d26f60628fb4c2225b966d42ed68763054ab647164b2e"),
-    field_element!("00cf0b8aecb2d04e76afd059694e0ced2e1f51174799954d82c5e360c1bb8b7a"),
-    field_element!("05d61f56df75fcd1e2d525d869e20651a9ef5e31ee76d85dea0ecc9701c9d874"),
-    field_element!("027a153217c3d2832b8709e6a1637a5fa0767d59080687d5bef0ed803048f6b1"),
-    field_element!("05e95b51b0ce8a2b6065b28fe42dc75dde44b241e47461e75236e0c550857393"),
-    field_element!("015ade725e4406014c5c599ea4d954383c9b861a7c191f5a5f1ae0c054d94965"),
-    field_element!("00b14b322f4965f8c1a6498c2292f7371776d466e841cb7e180ed63ea2c4ba2d"),
-    field_element!("002cb07b7131600155a4b22b7951b5d868fefef315aaea7754988c27442ef2c9"),
-    field_element!("0561396457069a06fdf5eba550f32c7dea412781964e44a246893e83ff961856"),
-    field_element!("019e32a24ac608ab776e2e7da371711071b4b4ad3f0b2c1bc3325ac0b8140562"),
-    field_element!("03e18710fd7292cbe21775f4687244a744faf7d5a4a64122f5532395d1c3f3bd"),
-    field_element!("07d0ebe2b5000e730a144f9d3d52bcc4779b8b2f9fda219c70fa90249ecde514"),
-    field_element!("02b28cb9c6e5f28e8d4f1f4790cbb86bebc6efc72c7fca4b02ac1d77cb7eee1e"),
-    field_element!("02d314cf577fdcd4b04f72620f59b79c3b951e89a49637db70856def2e4c73ac"),
-    field_element!("00595c7972c29489571f3f9319811015f4f79425b84f2ab820a7faabe0f28208"),
-    field_element!("004b731d5bd9c37f3e22b485ae885783082f76862d4b0648315e2dc99be56ca1"),
-    field_element!("02293fd81670bd397fcd7775a1daf85dfd3aa091d7999e1d361ce99220d07c37"),
-    field_element!("01fbfdda64943d144fe2e23aa54304c4e4c2bc1890334f852c0917aea22adf86"),
-    field_element!("07aa6c2bf37d059c74c043875f4bf1db4b008d2c1819f2701a546b3d6ec66e0c"),
-    field_element!("052c0c5ff1f1a646e7cf15743b926a8e74d3ae5f12d6faaa678293ac3f13e750"),
-    field_element!("01edc8a89e79201e2285f8e99b05f94724bc75ab194466f8f2201e025a6c0830"),
-    field_element!("00933dd482c31a232a16ea6ab9aaaff811b467f49c3a9c0a77522f2b5a76b07c"),
-    field_element!("01d8417b3abac6d6a95f882842392ff331d3eaa2eafe9b6b70818cd6c4abe42f"),
-    field_element!("06da66d145625bcc7d9b09bbcd94354794c77f9cd36c3a7391f39aa9a8881240"),
-    field_element!("05fe52dee97ae6f0bed690227ad94d3238f5b4a39b553e9a748dc7dd12e3cb9d"),
-    field_element!("064299e99fae8c76c6c6b33a4cf496a04537ba943ca072ab2082f17e447f6eb1"),
-    field_element!("071fd023b0c8efa3aed9b4745323694b75df93bbe9e7654f72199ed87f12d746"),
-    field_element!("0602e01351c0ce8199f2d2790fbc658ed805490ca7d9a1b8c513babe7705633c"),
-    field_element!("06108173fc40fdab6c4a61ccb6827d1b00f22e17138141a6ddde00b51a9f9881"),
-    field_element!("04fc76735760d7308b711eb67b1100b5aa4bc589f15d23384788150eca32a47c"),
-    field_element!("05dc4f9040d3cf93f3f62f90f1b28b0560fb835e1b648fa137d6c33b4d7c428b"),
-    field_element!("0732c8677310f7430f62135100ff191c92a324cd61e9cbd65c8ca4ed49832421"),
-    field_element!("0344b0ad7c3300d4b74a299f8df23301e806849afa80446dfb616867ca59732f"),
-    field_element!("03bb03e8f7f17385c32f0797fafd94dde5d423625e37911139f56ab733fe72f3"),
-    field_element!("032a1f5f523706eeafd3691c81a1837b51bd097f58650cd350c85b8d640c5e2c"),
-    field_element!("05bb0cdf6dec0f18cb3b16d84914420d3e8fcfa9e2fd30a4993fd3246a176f86"),
-    field_element!("02bd1411a784e61a7dace3112bb733bc0815473fc02197ad4f3ecf16c45df52d"),
-    field_element!("00153eeefc40582c02cae1417cd78648d2e7a75391699c7d838487d4b7be312b"),
-    field_element!("005097c20b0aa45081a4f701e2427b6ef32dc040473fb0840e08dbd9bdfe4a6f"),
-    field_element!("01083da804e1aeb398e737a236beb6b4b5afc2946756bb076a0ed5d0cb5bb371"),
-    field_element!("01dc69265df2705819f6a76543cd6ca78ee968bb9e20084f20826842e9984c29"),
-    field_element!("003bfa4bb9457da75182c745f5551791464a8aa4b77895da20f287debcf53844"),
-    field_element!("02d3f9d291f6d271838b942f40decfc0503ae3741d7c7e1bc0686e56a85062df"),
-    field_element!("071d1c6b00fba3876984801fff587b54a449b000ca6711533922042a1c9aade3"),
-    field_element!("072f3132d4c8184719dccdeeb70b9cca7b4a70eff6d071929aabd1c52ff42191"),
-    field_element!("014d6961cb116a2c4f7e555a5bc06896e905b3a5fd7ca6ce2c3e36cb15e0d982"),
-    field_element!("03452251c0680fa28251c847cc938d2be81db49d2b31f8b663c06c8c603d3e8d"),
-    field_element!("0087013abb3831a030c2992fee7a38d914ae03d082dede396907222b3c242d21"),
-    field_element!("00719f51cd314911a52473a46837e621e144db537b94bb00e15587b212ddc4af"),
-    field_element!("027ae4ecadabd7df5dfe978d54cb90ba9b3141be0bae112dccd5f8ee66461506"),
-    field_element!("011f179e64823e9203cdf970b896d7a9e88734d6d16c36e74f1724bc0783318b"),
-    field_element!("070974b3a58b72e15b10be6c224c430f5d867f90a25d53000517b3e6b2247b66"),
-    field_element!("04a27941af2abd72cccc2753ce2bd18dd9dd0147ca21aadc8b46be70d7c888c8"),
-    field_element!("0643aec46707e1d085501fed6aa8867c7f5f053c7af25d6a90221ab5a903e0c9"),
-    field_element!("004370bfec8eb6fb96483eb4610a4decce6fe59ac87bc7f981aba72ea2179213"),
-    field_element!("02af9e16a3bb28c14ccbd9e4b2706ada9b6658dc22751e33d32ead94e6af58b2"),
-    field_element!("041b5961757f76d3ee754fc6b1041f51fd93efb183ada3fabde2d9c03b4f6793"),
-    field_element!("003995929b8ea89d8535cbd717215e923fd76979409b2e802517a00d2846de88"),
-    field_element!("040f3ac6f9af75e7a727c955ed3cc89bc059f264a520c6c0b52eea80f58c09fb"),
-    field_element!("02ccf9e338a3cde11451e814a76ff1d9a549e3dc6abf9874454231a6b10a53c7"),
-    field_element!("073b9636f958a55c8f0e9c848801efbd4880a6364f886c5da1ddb8430cdd6765"),
-    field_element!("011a14aadb11d39427676695d7f42481c0a347dd24952ca6ed90722964ef0509"),
-    field_element!("03f80b189c59966284d895abda39fb4ec170c7511dc49df900479690bded438d"),
-    field_element!("034df269d913a236ffc0dcf5a9003d8370bd9612ae8938f80fe8c39129a463cf"),
-    field_element!("0282300791e6f256a083d726eac63456824e7d2d04bc488d3a7cee2eab987740"),
-    field_element!("00d43693d3c7734cc12116548b8cdaf90ace4c0626e988f65d372e12930d63ab"),
-    field_element!("025165295824b1fcd6d6354a9d74ae6bc13058491d20adfedb8d8817568f5140"),
-    field_element!("063a20df3641cab6b35266a262e3a637d85a079d704091ba9756a98a4acedf29"),
-    field_element!("0020c76ac50df6ed9f34ebd2d4d8415a58c3d63c8c0d37e5cfa83ce99580ffe0"),
-    field_element!("051ce9ce54a023eec6acef999db80f784ec20c8c49107419b1a05c6e456192fd"),
-    field_element!("013018fe7f30730fac919eac3f5f0602501ec61032281c2daec3668605b7f3be"),
-    field_element!("0037832d54c5bbeacb76a9657b566e689a54b8cfa232e47c5cefed66425565a7"),
-    field_element!("0233678fbce6ab0d3ef6634c37adcd050322006a1fb379c8bc3d4ce43d155687"),
-    field_element!("03d5c0c8e7a981d04f80a58711fac5fbc5d880d63f721c9d1c69703d936832d2"),
-    field_element!("02fb25891e769103d7d7f88e72851d0f7b388ea0c7f76d186970c2bd6f5e6fe8"),
-    field_element!("04d137dc4167c75e84bb9ef55f9ea630cdd1f913e5ba2be8be00fef38d0a4f62"),
-    field_element!("03945877c605f237bbd68e42096456ce3c6a7603d65114e55280095564587850"),
-    field_element!("05febdef850a152af221efcce585b3b1a09cb7bab4c8f428a2bf386fd1d9f0d8"),
-    field_element!("0536ea2165dfbc7a472bdf1f9939fb3946969fabff59fb276cd4f26d1ba67d05"),
-    field_element!("05afbc2a1f510c73d346dc22dbea0df431790d62e3a5c91ec9e19b8d15a6b553"),
-    field_element!("034ad1207a5d536788818a5c108d14bf49afcc8c5171131ba153271e7cb9b20b"),
-    field_element!("00b8073eaaac7169fa20bd760d03e88f730caab9acd56acabe98a9840122c540"),
-    field_element!("069c9895445191f49f7447ea05c3eb7ceee91b1bd74f942c50ddc7ddce6939fd"),
-    field_element!("050a6b884a6d4d4a25789427e1b411680049d6846d81f54edbf97ad2d63be700"),
-    field_element!("0353959faed49fc065e764198e6aafc91e5bf0db84faca5239885ddc3384ad86"),
-    field_element!("00e0b0a506fd002f6b4f86486d10691027dbcbf795a449f8a27c6b625621901c"),
-    field_element!("004e3a835102e9b8f2acb23cd5ebc43169d343ff4fbdcf7397153f31de4e836c"),
-    field_element!("0682502f55dfdf8362068c83773ae5d5c8a84f1a6aef62c458ff57993f8cfb08"),
-    field_element!("036c1db8954b5cbd93d9ed145649474506c784c6154d4cecc398fe62116d3c7a"),
-    field_element!("07a35489e3f2f883792aebdf9636df7760edc64a89aefd5824c9dfad90ae1c9a"),
-    field_element!("0380dbd0e28d4029d19c0a4af3163da8a5bc5e62db36ba4febf6a03eb488f87d"),
-    field_element!("02ae7235b95ab1a947fd7a0bebd2800adb9ba0b8d5808d75417cd25f705175d3"),
-    field_element!("057e0cc7a4ec3d8dc21237779b32245c9031a3bfbe522c88b6156ecbf49e98e0"),
-    field_element!("0273eafd33af8e826fe5b92c5c35ad64a6343981dd15877a1b9bb9dd5099af02"),
-    field_element!("0099de0431c534dc567c9b1659bea96c3b0e89155dac4abed70be9522a87a1ea"),
-    field_element!("0694265a9d6e3a500015ad8f4002ed8e37142e6d01387172b4ad184e2098e30d"),
-    field_element!("03dd35eee029ab656a451bf659094a2b966d18ddd0bc63281c59f1212b787f56"),
-    field_element!("00a98ca53712e3f5c3335e18bbb8d66a01f784e1795d641784d81949242404bc"),
-    field_element!("036cfb57a9a9cf653e1d8380b82407268b9fa4567ce35870de55531b19e80793"),
-    field_element!("01ace0db9650cd9abbb6b828e7089fd5bf0219ca878d877a0a6aaed81579560f"),
-    field_element!("0267c5b486837df01c83ead66953155d8ab83b7f5af5a4ef5da3cad66d323e1e"),
-];
-
-#[cfg(test)]
-mod tests {
-    use super::{super::pedersen_points::PEDERSEN_POINTS, *};
-    use zkp_elliptic_curve::Affine;
-    use zkp_primefield::geometric_series::root_series;
-    use zkp_stark::DensePolynomial;
-
-    #[test]
-    fn left_coefficients_match() {
-        let evaluation_points = root_series(LEFT_X_COEFFICIENTS.len()).take(252);
-        let left_points = evaluation_points.map(|f: FieldElement| {
-            Affine::Point {
-                x: DensePolynomial::new(&LEFT_X_COEFFICIENTS).evaluate(&f),
-                y: DensePolynomial::new(&LEFT_Y_COEFFICIENTS).evaluate(&f),
-            }
-        });
-
-        for (i, point) in left_points.enumerate() {
-            assert_eq!(point, PEDERSEN_POINTS[i + 1]);
-        }
-    }
-
-    #[test]
-    fn right_coefficients_match() {
-        let evaluation_points = root_series(RIGHT_X_COEFFICIENTS.len()).take(252);
-        let right_points = evaluation_points.map(|f: FieldElement| {
-            Affine::Point {
-                x: DensePolynomial::new(&RIGHT_X_COEFFICIENTS).evaluate(&f),
-                y: DensePolynomial::new(&RIGHT_Y_COEFFICIENTS).evaluate(&f),
-            }
-        });
-
-        for (i, point) in right_points.enumerate() {
-            assert_eq!(point, PEDERSEN_POINTS[i + 253]);
-        }
-    }
-}
@@ -5,7 +5,7 @@ use crate::{
 use log::info;
 use zkp_macros_decl::{field_element, hex};
 use zkp_primefield::FieldElement;
-use zkp_stark::{prove, Constraints};
+use zkp_stark::{prove, Constraints, TranscriptVersion};
 use zkp_u256::U256;
 
 pub fn starkware_example() {
@@ -39,6 +39,7 @@ pub fn starkware_example() {
     constraints.pow_bits = 28;
     constraints.num_queries = 13;
     constraints.fri_layout = vec![3, 3, 3, 3, 2];
+    constraints.transcript_version = TranscriptVersion::Legacy;
     let proof = prove(&constraints, &component.trace).unwrap();
 
     info!("Spot checking proof...");
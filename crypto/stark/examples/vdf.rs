@@ -4,7 +4,9 @@ use log::info;
 use std::time::Instant;
 use zkp_macros_decl::field_element;
 use zkp_primefield::FieldElement;
-use zkp_stark::{Constraints, Provable, RationalExpression, TraceTable, Verifiable};
+use zkp_stark::{
+    BoundaryConstraints, Constraints, Provable, RationalExpression, TraceTable, Verifiable,
+};
 use zkp_u256::U256;
 
 const R: FieldElement = field_element!("03");
@@ -21,20 +23,24 @@ impl Verifiable for Claim {
     fn constraints(&self) -> Constraints {
         use RationalExpression::*;
 
-        // Seed
-        let mut seed = self.c0_start.as_montgomery().to_bytes_be().to_vec();
-        seed.extend_from_slice(&self.c1_start.as_montgomery().to_bytes_be());
-        seed.extend_from_slice(&self.c0_end.as_montgomery().to_bytes_be());
-        seed.extend_from_slice(&self.c1_end.as_montgomery().to_bytes_be());
-
         // Constraint repetitions
         let trace_length = 1_048_576;
         let trace_generator = FieldElement::root(trace_length).unwrap();
         let g = Constant(trace_generator);
-        let on_row = |index| (X - g.pow(index)).inv();
         let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
 
-        Constraints::from_expressions((trace_length, 4), seed, vec![
+        // Public inputs
+        let mut boundary = BoundaryConstraints::new((trace_length, 4)).unwrap();
+        boundary.add(0, 0, self.c0_start.clone()).unwrap();
+        boundary.add(1, 0, self.c1_start.clone()).unwrap();
+        boundary
+            .add(0, trace_length - 1, self.c0_end.clone())
+            .unwrap();
+        boundary
+            .add(1, trace_length - 1, self.c1_end.clone())
+            .unwrap();
+
+        Constraints::from_boundary_constraints(&boundary, vec![
             // Square (Trace(0,0), Trace(1, 0)) and check that it equals (Trace(2,0), Trace(3,0))
             ((Trace(0, 0) * Trace(0, 0) + Constant(R) * Trace(1, 0) * Trace(1, 0) - Trace(2, 0))
                 * every_row()),
@@ -43,11 +49,6 @@ impl Verifiable for Claim {
             ((Trace(0, 0) * Trace(2, 0) + Constant(R) * Trace(1, 0) * Trace(3, 0) - Trace(0, 1))
                 * every_row()),
             (Trace(0, 0) * Trace(2, 0) + Trace(1, 0) * Trace(3, 0) - Trace(1, 1)) * every_row(),
-            // Boundary Constraints
-            (Trace(0, 0) - (&self.c0_start).into()) * on_row(0),
-            (Trace(1, 0) - (&self.c1_start).into()) * on_row(0),
-            (Trace(0, 0) - (&self.c0_end).into()) * on_row(trace_length - 1),
-            (Trace(1, 0) - (&self.c1_end).into()) * on_row(trace_length - 1),
         ])
         .unwrap()
     }
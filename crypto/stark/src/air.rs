@@ -0,0 +1,1280 @@
+//! Text format for constraint systems.
+//!
+//! A constraint system is written one statement per line. Line breaks inside
+//! brackets continue the statement and `#` starts a comment that runs to the
+//! end of the line. For example
+//!
+//! ```text
+//! # Fibonacci-like sequence with a public value at row 1000
+//! trace 1024 x 2
+//!
+//! column a = 0
+//! column b = 1
+//! let g = root(1024)
+//! let every_row = (X - pow(g, 1023)) / (pow(X, 1024) - 1)
+//!
+//! boundary a 0 = 1
+//! boundary a 1000 = 0xcafebabe
+//!
+//! constraint (a[1] - pow(b, 1)) * every_row
+//! constraint (b[1] - a - b) * every_row
+//! ```
+//!
+//! The statements are
+//!
+//! * `trace <rows> x <columns>`, the shape of the trace table. Required.
+//! * `seed 0x<bytes>`, the channel seed of a system without `boundary` or
+//!   `public` statements.
+//! * `boundary <column> <row> = <value>`, a public value of the trace, where
+//!   the column is an index or a column name. The boundary statements form
+//!   the [`BoundaryConstraints`] the system is built from with
+//!   [`Constraints::from_boundary_constraints`], which appends their
+//!   constraints and derives the seed from them.
+//! * `public 0x<bytes>`, a public parameter bound into the seed with
+//!   [`BoundaryConstraints::add_public`].
+//! * `blowup`, `pow_bits`, `queries`, `fri_layout [..]`, `coset_offset`,
+//!   `zero_knowledge`, `oods_points`, `hash` and `transcript`, the proof
+//!   parameters. Omitted ones take the defaults of
+//!   [`Constraints::from_expressions`].
+//! * `column <name> = <index>`, a named trace column. A column `a` is referred
+//!   to as `a` on the current row and `a[k]` on row offset `k`. The first name
+//!   declared for a column becomes its [`Constraints::column_name`].
+//! * `let <name> = <expression>`, a named subexpression.
+//! * `polynomial <name> = [<coefficients>]`, a polynomial in ascending
+//!   coefficient order, applied as `name(<expression>)`.
+//! * `periodic <name> = [<values>]`, a periodic column repeating the values
+//!   over the trace. The number of values must be a power of two dividing the
+//!   trace length. With `over <period>` it is a
+//!   [`RationalExpression::Periodic`] with a shorter period, which must
+//!   divide the trace length.
+//! * `constraint <expression>` or `constraint <name>: <expression>`, a
+//!   constraint with an optional [`Constraints::constraint_name`]. Constraint
+//!   names consist of letters, digits and `_/.|-`.
+//!
+//! Expressions are built from decimal or `0x` hexadecimal constants, `X`,
+//! `Trace(column, offset)`, names, `pow(expression, exponent)`,
+//! `inv(expression)`, `root(order)` for a root of unity, `+`, `-`, `*`, `/`
+//! and parentheses.
+//!
+//! [`print`] writes the canonical form, which [`parse`]s back into identical
+//! constraints. Column names that are not valid identifiers and constraint
+//! names with other characters are left out.
+use crate::{
+    boundary::BoundaryConstraints,
+    constraints::{Constraints, Error as ConstraintError, TranscriptVersion},
+    polynomial::DensePolynomial,
+    rational_expression::RationalExpression,
+};
+use std::{collections::BTreeMap, fmt, fmt::Write, prelude::v1::*};
+use zkp_hash::HashFunction;
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The statement on `line` is malformed.
+    Syntax { line: usize, message: String },
+    /// The name is not declared before `line`.
+    UnknownName { line: usize, name: String },
+    /// The name or parameter on `line` is declared twice.
+    Duplicate { line: usize, name: String },
+    /// There is no `trace` statement.
+    MissingTrace,
+    /// The number of trace rows is not a power of two.
+    InvalidTraceLength,
+    /// A constraint or declaration refers to a column outside the trace.
+    InvalidColumn { column: usize },
+    /// The parsed statements do not form valid [`Constraints`].
+    Constraints(ConstraintError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Error::*;
+        match self {
+            Syntax { line, message } => write!(f, "Line {}: {}", line, message),
+            UnknownName { line, name } => write!(f, "Line {}: unknown name `{}`", line, name),
+            Duplicate { line, name } => write!(f, "Line {}: `{}` is declared twice", line, name),
+            MissingTrace => write!(f, "Missing `trace` statement"),
+            InvalidTraceLength => write!(f, "Invalid trace length (must be power of two)"),
+            InvalidColumn { column } => write!(f, "Column {} is outside the trace", column),
+            Constraints(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Number(U256),
+    Symbol(char),
+}
+
+/// What a declared name refers to.
+enum Binding {
+    Column(usize),
+    Value(RationalExpression),
+    Polynomial(DensePolynomial),
+}
+
+/// Split the text into statements with the line they start on.
+fn statements(text: &str) -> Vec<(usize, String)> {
+    let mut result: Vec<(usize, String)> = Vec::new();
+    let mut depth = 0_usize;
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        match result.last_mut() {
+            Some((_, statement)) if depth > 0 => {
+                statement.push(' ');
+                statement.push_str(line);
+            }
+            _ => result.push((number + 1, line.to_string())),
+        }
+        for c in line.chars() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+    result
+}
+
+struct Parser<'a> {
+    line:     usize,
+    tokens:   Vec<Token>,
+    position: usize,
+    names:    &'a BTreeMap<String, Binding>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(line: usize, text: &str, names: &'a BTreeMap<String, Binding>) -> Result<Self> {
+        let mut tokens = Vec::new();
+        let mut chars = text.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                let _ = chars.next();
+            } else if c.is_ascii_alphabetic() || c == '_' {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    ident.push(c);
+                    let _ = chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            } else if c.is_ascii_digit() {
+                let mut digits = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    digits.push(c);
+                    let _ = chars.next();
+                }
+                tokens.push(Token::Number(parse_number(line, &digits)?));
+            } else if "+-*/()[],=".contains(c) {
+                tokens.push(Token::Symbol(c));
+                let _ = chars.next();
+            } else {
+                return Err(syntax(line, format!("unexpected character `{}`", c)));
+            }
+        }
+        Ok(Self {
+            line,
+            tokens,
+            position: 0,
+            names,
+        })
+    }
+
+    fn error<T>(&self, message: &str) -> Result<T> {
+        let found = match self.tokens.get(self.position) {
+            Some(Token::Ident(ident)) => format!("`{}`", ident),
+            Some(Token::Number(number)) => format!("`{}`", number.to_decimal_str()),
+            Some(Token::Symbol(c)) => format!("`{}`", c),
+            None => "end of line".to_string(),
+        };
+        Err(syntax(
+            self.line,
+            format!("expected {}, found {}", message, found),
+        ))
+    }
+
+    fn peek_symbol(&self, symbol: char) -> bool {
+        self.tokens.get(self.position) == Some(&Token::Symbol(symbol))
+    }
+
+    fn symbol(&mut self, symbol: char) -> Result<()> {
+        if self.peek_symbol(symbol) {
+            self.position += 1;
+            Ok(())
+        } else {
+            self.error(&format!("`{}`", symbol))
+        }
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        match self.tokens.get(self.position) {
+            Some(Token::Ident(ident)) => {
+                let ident = ident.clone();
+                self.position += 1;
+                Ok(ident)
+            }
+            _ => self.error("a name"),
+        }
+    }
+
+    fn end(&self) -> Result<()> {
+        if self.position == self.tokens.len() {
+            Ok(())
+        } else {
+            self.error("end of line")
+        }
+    }
+
+    fn number(&mut self) -> Result<U256> {
+        match self.tokens.get(self.position) {
+            Some(Token::Number(number)) => {
+                let number = number.clone();
+                self.position += 1;
+                Ok(number)
+            }
+            _ => self.error("a number"),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn usize(&mut self) -> Result<usize> {
+        let number = self.number()?;
+        if number.bits() > 63 {
+            return Err(syntax(self.line, "number too large".to_string()));
+        }
+        Ok(number.c0 as usize)
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn isize(&mut self) -> Result<isize> {
+        let negative = self.peek_symbol('-');
+        if negative {
+            self.position += 1;
+        }
+        let value = self.usize()? as isize;
+        Ok(if negative { -value } else { value })
+    }
+
+    /// A column index or the name of a column.
+    fn column(&mut self) -> Result<usize> {
+        if let Some(Token::Number(_)) = self.tokens.get(self.position) {
+            return self.usize();
+        }
+        let name = self.ident()?;
+        match self.names.get(&name) {
+            Some(Binding::Column(column)) => Ok(*column),
+            _ => {
+                Err(Error::UnknownName {
+                    line: self.line,
+                    name,
+                })
+            }
+        }
+    }
+
+    fn field_element(&mut self) -> Result<FieldElement> {
+        let negative = self.peek_symbol('-');
+        if negative {
+            self.position += 1;
+        }
+        let number = self.number()?;
+        if number >= FieldElement::MODULUS {
+            return Err(syntax(
+                self.line,
+                "constant exceeds the modulus".to_string(),
+            ));
+        }
+        let value = FieldElement::from(number);
+        Ok(if negative { -&value } else { value })
+    }
+
+    /// A comma separated list of field elements in square brackets.
+    fn field_elements(&mut self) -> Result<Vec<FieldElement>> {
+        self.symbol('[')?;
+        let mut values = Vec::new();
+        while !self.peek_symbol(']') {
+            values.push(self.field_element()?);
+            if !self.peek_symbol(']') {
+                self.symbol(',')?;
+            }
+        }
+        self.symbol(']')?;
+        Ok(values)
+    }
+
+    /// The value of a `column`, `let`, `polynomial` or `periodic` declaration.
+    fn binding(&mut self, keyword: &str, rows: Option<usize>) -> Result<Binding> {
+        Ok(match keyword {
+            "column" => Binding::Column(self.usize()?),
+            "let" => Binding::Value(self.sum()?),
+            "polynomial" => {
+                let coefficients = self.field_elements()?;
+                if !coefficients.len().is_power_of_two() {
+                    return Err(syntax(
+                        self.line,
+                        "the number of coefficients must be a power of two".to_string(),
+                    ));
+                }
+                Binding::Polynomial(DensePolynomial::new(&coefficients))
+            }
+            _ => {
+                let rows = rows.ok_or(Error::MissingTrace)?;
+                let values = self.field_elements()?;
+                let period = match self.tokens.get(self.position) {
+                    Some(Token::Ident(ident)) if ident == "over" => {
+                        self.position += 1;
+                        self.usize()?
+                    }
+                    _ => rows,
+                };
+                let periodic = RationalExpression::Periodic { values, period };
+                if !periodic.has_valid_periodic_columns(rows) {
+                    return Err(syntax(
+                        self.line,
+                        "the number of values must be a power of two dividing the period, which \
+                         must divide the trace length"
+                            .to_string(),
+                    ));
+                }
+                Binding::Value(periodic)
+            }
+        })
+    }
+
+    fn sum(&mut self) -> Result<RationalExpression> {
+        let mut result = self.product()?;
+        loop {
+            if self.peek_symbol('+') {
+                self.position += 1;
+                result = result + self.product()?;
+            } else if self.peek_symbol('-') {
+                self.position += 1;
+                result = result - self.product()?;
+            } else {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn product(&mut self) -> Result<RationalExpression> {
+        let mut result = self.unary()?;
+        loop {
+            if self.peek_symbol('*') {
+                self.position += 1;
+                result = result * self.unary()?;
+            } else if self.peek_symbol('/') {
+                self.position += 1;
+                result = result / self.unary()?;
+            } else {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<RationalExpression> {
+        if self.peek_symbol('-') {
+            self.position += 1;
+            Ok(self.unary()?.neg())
+        } else {
+            self.atom()
+        }
+    }
+
+    fn atom(&mut self) -> Result<RationalExpression> {
+        use RationalExpression::*;
+        if self.peek_symbol('(') {
+            self.position += 1;
+            let result = self.sum()?;
+            self.symbol(')')?;
+            return Ok(result);
+        }
+        if let Some(Token::Number(_)) = self.tokens.get(self.position) {
+            return Ok(Constant(self.field_element()?));
+        }
+        let name = match self.tokens.get(self.position) {
+            Some(Token::Ident(_)) => self.ident()?,
+            _ => return self.error("an expression"),
+        };
+        match name.as_str() {
+            "X" => Ok(X),
+            "Trace" => {
+                self.symbol('(')?;
+                let column = self.usize()?;
+                self.symbol(',')?;
+                let offset = self.isize()?;
+                self.symbol(')')?;
+                Ok(Trace(column, offset))
+            }
+            "pow" => {
+                self.symbol('(')?;
+                let base = self.sum()?;
+                self.symbol(',')?;
+                let exponent = self.usize()?;
+                self.symbol(')')?;
+                Ok(base.pow(exponent))
+            }
+            "inv" => {
+                self.symbol('(')?;
+                let result = self.sum()?.inv();
+                self.symbol(')')?;
+                Ok(result)
+            }
+            "root" => {
+                self.symbol('(')?;
+                let order = self.usize()?;
+                self.symbol(')')?;
+                FieldElement::root(order).map(Constant).ok_or_else(|| {
+                    syntax(
+                        self.line,
+                        format!("there is no root of unity of order {}", order),
+                    )
+                })
+            }
+            _ => {
+                match self.names.get(&name) {
+                    Some(Binding::Column(column)) => {
+                        let offset = if self.peek_symbol('[') {
+                            self.position += 1;
+                            let offset = self.isize()?;
+                            self.symbol(']')?;
+                            offset
+                        } else {
+                            0
+                        };
+                        Ok(Trace(*column, offset))
+                    }
+                    Some(Binding::Value(value)) => Ok(value.clone()),
+                    Some(Binding::Polynomial(polynomial)) => {
+                        self.symbol('(')?;
+                        let argument = self.sum()?;
+                        self.symbol(')')?;
+                        Ok(Polynomial(polynomial.clone(), Box::new(argument)))
+                    }
+                    None => {
+                        Err(Error::UnknownName {
+                            line: self.line,
+                            name,
+                        })
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn syntax(line: usize, message: String) -> Error {
+    Error::Syntax { line, message }
+}
+
+fn parse_number(line: usize, digits: &str) -> Result<U256> {
+    let invalid = || syntax(line, format!("invalid number `{}`", digits));
+    if let Some(hex) = digits.strip_prefix("0x") {
+        if hex.is_empty() || hex.len() > 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        Ok(U256::from_hex_str(hex))
+    } else {
+        U256::from_decimal_str(digits).map_err(|_| invalid())
+    }
+}
+
+fn parse_bytes(line: usize, text: &str) -> Result<Vec<u8>> {
+    let invalid = || syntax(line, format!("invalid bytes `{}`", text));
+    let hex = text.strip_prefix("0x").ok_or_else(invalid)?;
+    if hex.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+/// Proof parameters, set when given in the text.
+#[derive(Default)]
+struct Parameters {
+    seed:               Option<Vec<u8>>,
+    blowup:             Option<usize>,
+    pow_bits:           Option<usize>,
+    num_queries:        Option<usize>,
+    fri_layout:         Option<Vec<usize>>,
+    coset_offset:       Option<FieldElement>,
+    zero_knowledge:     Option<bool>,
+    num_oods_points:    Option<usize>,
+    hash_function:      Option<HashFunction>,
+    transcript_version: Option<TranscriptVersion>,
+}
+
+impl Parameters {
+    /// Parse a parameter statement.
+    fn parse(&mut self, line: usize, keyword: &str, rest: &str) -> Result<()> {
+        let names = BTreeMap::new();
+        let parser = || Parser::new(line, rest, &names);
+        match keyword {
+            "seed" => set(line, keyword, &mut self.seed, parse_bytes(line, rest)?)?,
+            "hash" => {
+                let hash_function = [
+                    HashFunction::Keccak256,
+                    HashFunction::Sha256,
+                    HashFunction::Blake2s256,
+                    HashFunction::Poseidon,
+                    HashFunction::Pedersen,
+                ]
+                .iter()
+                .find(|hash_function| hash_function.to_string() == rest)
+                .ok_or_else(|| syntax(line, format!("unknown hash function `{}`", rest)))?;
+                set(line, keyword, &mut self.hash_function, *hash_function)?;
+            }
+            "transcript" => {
+                let version = match rest {
+                    "legacy" => TranscriptVersion::Legacy,
+                    "v1" => TranscriptVersion::V1,
+                    _ => return Err(syntax(line, format!("unknown transcript `{}`", rest))),
+                };
+                set(line, keyword, &mut self.transcript_version, version)?;
+            }
+            "zero_knowledge" => {
+                let value = match rest {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(syntax(line, "expected `true` or `false`".to_string())),
+                };
+                set(line, keyword, &mut self.zero_knowledge, value)?;
+            }
+            "blowup" | "pow_bits" | "queries" | "oods_points" => {
+                let mut parser = parser()?;
+                let value = parser.usize()?;
+                parser.end()?;
+                let field = match keyword {
+                    "blowup" => &mut self.blowup,
+                    "pow_bits" => &mut self.pow_bits,
+                    "queries" => &mut self.num_queries,
+                    _ => &mut self.num_oods_points,
+                };
+                set(line, keyword, field, value)?;
+            }
+            "fri_layout" => {
+                let mut parser = parser()?;
+                let mut layout = Vec::new();
+                parser.symbol('[')?;
+                while !parser.peek_symbol(']') {
+                    layout.push(parser.usize()?);
+                    if !parser.peek_symbol(']') {
+                        parser.symbol(',')?;
+                    }
+                }
+                parser.symbol(']')?;
+                parser.end()?;
+                set(line, keyword, &mut self.fri_layout, layout)?;
+            }
+            "coset_offset" => {
+                let mut parser = parser()?;
+                let value = parser.field_element()?;
+                parser.end()?;
+                set(line, keyword, &mut self.coset_offset, value)?;
+            }
+            _ => return Err(syntax(line, format!("unknown statement `{}`", keyword))),
+        }
+        Ok(())
+    }
+
+    fn apply(self, constraints: &mut Constraints) {
+        if let Some(blowup) = self.blowup {
+            constraints.blowup = blowup;
+        }
+        if let Some(pow_bits) = self.pow_bits {
+            constraints.pow_bits = pow_bits;
+        }
+        if let Some(num_queries) = self.num_queries {
+            constraints.num_queries = num_queries;
+        }
+        if let Some(fri_layout) = self.fri_layout {
+            constraints.fri_layout = fri_layout;
+        }
+        if let Some(coset_offset) = self.coset_offset {
+            constraints.coset_offset = coset_offset;
+        }
+        if let Some(zero_knowledge) = self.zero_knowledge {
+            constraints.zero_knowledge = zero_knowledge;
+        }
+        if let Some(num_oods_points) = self.num_oods_points {
+            constraints.num_oods_points = num_oods_points;
+        }
+        if let Some(hash_function) = self.hash_function {
+            constraints.hash_function = hash_function;
+        }
+        if let Some(transcript_version) = self.transcript_version {
+            constraints.transcript_version = transcript_version;
+        }
+    }
+}
+
+fn set<T>(line: usize, name: &str, field: &mut Option<T>, value: T) -> Result<()> {
+    if field.is_some() {
+        return Err(Error::Duplicate {
+            line,
+            name: name.to_string(),
+        });
+    }
+    *field = Some(value);
+    Ok(())
+}
+
+/// Parse a constraint system from its text form.
+///
+/// See the [module documentation](self) for the format.
+// TODO: Split up
+#[allow(clippy::too_many_lines)]
+pub fn parse(text: &str) -> Result<Constraints> {
+    let mut names = BTreeMap::new();
+    let mut parameters = Parameters::default();
+    let mut trace: Option<(usize, usize)> = None;
+    let mut expressions = Vec::new();
+    let mut column_names = BTreeMap::new();
+    let mut constraint_names = BTreeMap::new();
+    let mut boundary = Vec::new();
+    let mut public = Vec::new();
+    let mut derived_seed = false;
+
+    for (line, statement) in statements(text) {
+        let (keyword, rest) = match statement.find(char::is_whitespace) {
+            Some(index) => (&statement[..index], statement[index..].trim()),
+            None => (statement.as_str(), ""),
+        };
+        let parser = Parser::new(line, rest, &names);
+        match keyword {
+            "trace" => {
+                let mut parser = parser?;
+                let rows = parser.usize()?;
+                match parser.ident()?.as_str() {
+                    "x" => {}
+                    _ => return Err(syntax(line, "expected `<rows> x <columns>`".to_string())),
+                }
+                let columns = parser.usize()?;
+                parser.end()?;
+                if !rows.is_power_of_two() || FieldElement::root(rows).is_none() {
+                    return Err(Error::InvalidTraceLength);
+                }
+                set(line, keyword, &mut trace, (rows, columns))?;
+            }
+            "constraint" => {
+                let (name, rest) = constraint_name(line, rest)?;
+                let mut parser = Parser::new(line, rest, &names)?;
+                if let Some(name) = name {
+                    let _ = constraint_names.insert(expressions.len(), name);
+                }
+                expressions.push(parser.sum()?);
+                parser.end()?;
+            }
+            "boundary" => {
+                let mut parser = parser?;
+                let column = parser.column()?;
+                let row = parser.usize()?;
+                parser.symbol('=')?;
+                let value = parser.field_element()?;
+                parser.end()?;
+                boundary.push((column, row, value));
+            }
+            "public" => public.push(parse_bytes(line, rest)?),
+            "column" | "let" | "polynomial" | "periodic" => {
+                let mut parser = parser?;
+                let name = parser.ident()?;
+                parser.symbol('=')?;
+                let binding = parser.binding(keyword, trace.map(|(rows, _)| rows))?;
+                parser.end()?;
+                if names.contains_key(&name) {
+                    return Err(Error::Duplicate { line, name });
+                }
+                if let Binding::Column(column) = binding {
+                    let _ = column_names.entry(column).or_insert_with(|| name.clone());
+                }
+                let _ = names.insert(name, binding);
+            }
+            _ => parameters.parse(line, keyword, rest)?,
+        }
+        derived_seed |= keyword == "boundary" || keyword == "public";
+        if derived_seed && parameters.seed.is_some() {
+            return Err(syntax(
+                line,
+                "`seed` can not be combined with `boundary` or `public`".to_string(),
+            ));
+        }
+    }
+
+    let (rows, columns) = trace.ok_or(Error::MissingTrace)?;
+    if let Some(&column) = column_names.keys().find(|column| **column >= columns) {
+        return Err(Error::InvalidColumn { column });
+    }
+    for expression in &expressions {
+        if let Some(&(column, _)) = expression
+            .trace_arguments()
+            .iter()
+            .find(|(column, _)| *column >= columns)
+        {
+            return Err(Error::InvalidColumn { column });
+        }
+    }
+    let mut constraints = if derived_seed {
+        let mut public_inputs =
+            BoundaryConstraints::new((rows, columns)).map_err(Error::Constraints)?;
+        for (column, row, value) in boundary {
+            public_inputs
+                .add(column, row, value)
+                .map_err(Error::Constraints)?;
+        }
+        for bytes in &public {
+            public_inputs.add_public(bytes);
+        }
+        Constraints::from_boundary_constraints(&public_inputs, expressions)
+    } else {
+        Constraints::from_expressions(
+            (rows, columns),
+            parameters.seed.take().unwrap_or_default(),
+            expressions,
+        )
+    }
+    .map_err(Error::Constraints)?;
+    parameters.apply(&mut constraints);
+    for (column, name) in &column_names {
+        constraints.set_column_name(*column, name);
+    }
+    for (index, name) in &constraint_names {
+        constraints.set_constraint_name(*index, name);
+    }
+    Ok(constraints)
+}
+
+/// Split the optional `<name>:` prefix off a constraint statement.
+fn constraint_name(line: usize, rest: &str) -> Result<(Option<String>, &str)> {
+    match rest.find(':') {
+        None => Ok((None, rest)),
+        Some(index) => {
+            let name = rest[..index].trim();
+            if !is_constraint_name(name) {
+                return Err(syntax(line, format!("invalid constraint name `{}`", name)));
+            }
+            Ok((Some(name.to_string()), &rest[index + 1..]))
+        }
+    }
+}
+
+fn is_constraint_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_/.|-".contains(c))
+}
+
+/// Whether `name` can be printed as a column declaration.
+///
+/// Names of the form `p<n>` are reserved for printed polynomials.
+fn is_column_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_alphabetic = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_');
+    let polynomial =
+        name.starts_with('p') && name.len() > 1 && name[1..].chars().all(|c| c.is_ascii_digit());
+    starts_alphabetic
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !polynomial
+        && !["X", "Trace", "pow", "inv", "root"].contains(&name)
+}
+
+/// A polynomial or periodic column, declared as `p<n>` when printing.
+#[derive(PartialEq)]
+enum Declaration {
+    Polynomial(DensePolynomial),
+    Periodic(Vec<FieldElement>, usize),
+}
+
+struct Printer {
+    declarations: Vec<Declaration>,
+    columns:      BTreeMap<usize, String>,
+}
+
+impl Printer {
+    fn declare(&mut self, declaration: Declaration) {
+        if !self.declarations.contains(&declaration) {
+            self.declarations.push(declaration);
+        }
+    }
+
+    fn collect(&mut self, expression: &RationalExpression) {
+        use RationalExpression::*;
+        match expression {
+            X | Constant(_) | Trace(..) => {}
+            Periodic { values, period } => {
+                self.declare(Declaration::Periodic(values.clone(), *period));
+            }
+            Polynomial(polynomial, argument) => {
+                self.declare(Declaration::Polynomial(polynomial.clone()));
+                self.collect(argument);
+            }
+            Neg(a) | Inv(a) | Exp(a, _) => self.collect(a),
+            Add(a, b) | Mul(a, b) => {
+                self.collect(a);
+                self.collect(b);
+            }
+        }
+    }
+
+    fn name(&self, declaration: &Declaration) -> usize {
+        self.declarations
+            .iter()
+            .position(|d| d == declaration)
+            .expect("declarations are collected before printing")
+    }
+
+    /// Write `expression`, parenthesized unless it binds at least as tight as
+    /// `level`: 0 for sums, 1 for products, 2 for negations and 3 for atoms.
+    fn write(&self, f: &mut String, expression: &RationalExpression, level: usize) -> fmt::Result {
+        use RationalExpression::*;
+        let own = match expression {
+            Add(..) => 0,
+            Mul(..) => 1,
+            Neg(..) => 2,
+            _ => 3,
+        };
+        if own < level {
+            f.push('(');
+        }
+        match expression {
+            X => f.push('X'),
+            Constant(value) => write_constant(f, value)?,
+            Trace(column, offset) => {
+                match (self.columns.get(column), offset) {
+                    (Some(name), 0) => f.push_str(name),
+                    (Some(name), _) => write!(f, "{}[{}]", name, offset)?,
+                    (None, _) => write!(f, "Trace({}, {})", column, offset)?,
+                }
+            }
+            Periodic { values, period } => {
+                let declaration = Declaration::Periodic(values.clone(), *period);
+                write!(f, "p{}", self.name(&declaration))?;
+            }
+            Polynomial(polynomial, argument) => {
+                let declaration = Declaration::Polynomial(polynomial.clone());
+                write!(f, "p{}(", self.name(&declaration))?;
+                self.write(f, argument, 0)?;
+                f.push(')');
+            }
+            Add(a, b) => {
+                self.write(f, a, 0)?;
+                if let Neg(b) = b.as_ref() {
+                    f.push_str(" - ");
+                    self.write(f, b, 1)?;
+                } else {
+                    f.push_str(" + ");
+                    self.write(f, b, 1)?;
+                }
+            }
+            Mul(a, b) => {
+                self.write(f, a, 1)?;
+                if let Inv(b) = b.as_ref() {
+                    f.push_str(" / ");
+                    self.write(f, b, 2)?;
+                } else {
+                    f.push_str(" * ");
+                    self.write(f, b, 2)?;
+                }
+            }
+            Neg(a) => {
+                f.push('-');
+                self.write(f, a, 2)?;
+            }
+            Inv(a) => {
+                f.push_str("inv(");
+                self.write(f, a, 0)?;
+                f.push(')');
+            }
+            Exp(a, exponent) => {
+                f.push_str("pow(");
+                self.write(f, a, 0)?;
+                write!(f, ", {})", exponent)?;
+            }
+        }
+        if own < level {
+            f.push(')');
+        }
+        Ok(())
+    }
+}
+
+fn write_constant(f: &mut String, value: &FieldElement) -> fmt::Result {
+    let value = U256::from(value);
+    if value.bits() <= 64 {
+        f.push_str(&value.to_decimal_str());
+        Ok(())
+    } else {
+        write!(f, "0x{}", value.to_string().trim_start_matches('0'))
+    }
+}
+
+fn write_bytes(f: &mut String, bytes: &[u8]) -> fmt::Result {
+    f.push_str("0x");
+    for byte in bytes {
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}
+
+fn write_values(f: &mut String, values: &[FieldElement]) -> fmt::Result {
+    f.push('[');
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.push_str(", ");
+        }
+        write_constant(f, value)?;
+    }
+    f.push(']');
+    Ok(())
+}
+
+/// Write constraints in their canonical text form.
+///
+/// Polynomials and periodic columns are declared as `p0`, `p1`, ... and all
+/// other subexpressions are written out in full.
+pub fn print(constraints: &Constraints) -> String {
+    let mut f = String::new();
+    print_impl(&mut f, constraints).expect("writing to a String does not fail");
+    f
+}
+
+fn print_impl(f: &mut String, constraints: &Constraints) -> fmt::Result {
+    writeln!(
+        f,
+        "trace {} x {}",
+        constraints.trace_nrows(),
+        constraints.trace_ncolumns()
+    )?;
+    let boundary = constraints.boundary_constraints();
+    match boundary {
+        Some(boundary) => {
+            for bytes in boundary.public() {
+                f.push_str("public ");
+                write_bytes(f, bytes)?;
+                writeln!(f)?;
+            }
+        }
+        None => {
+            f.push_str("seed ");
+            write_bytes(f, constraints.channel_seed())?;
+            writeln!(f)?;
+        }
+    }
+    writeln!(f, "blowup {}", constraints.blowup)?;
+    writeln!(f, "pow_bits {}", constraints.pow_bits)?;
+    writeln!(f, "queries {}", constraints.num_queries)?;
+    writeln!(
+        f,
+        "fri_layout [{}]",
+        constraints
+            .fri_layout
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )?;
+    f.push_str("coset_offset ");
+    write_constant(f, &constraints.coset_offset)?;
+    writeln!(f)?;
+    writeln!(f, "zero_knowledge {}", constraints.zero_knowledge)?;
+    writeln!(f, "oods_points {}", constraints.num_oods_points)?;
+    writeln!(f, "hash {}", constraints.hash_function)?;
+    writeln!(f, "transcript {}", match constraints.transcript_version {
+        TranscriptVersion::Legacy => "legacy",
+        TranscriptVersion::V1 => "v1",
+    })?;
+
+    let mut printer = Printer {
+        declarations: Vec::new(),
+        columns:      BTreeMap::new(),
+    };
+    for column in 0..constraints.trace_ncolumns() {
+        if let Some(name) = constraints.column_name(column) {
+            if is_column_name(name) && !printer.columns.values().any(|other| other == name) {
+                let _ = printer.columns.insert(column, name.to_string());
+            }
+        }
+    }
+    // The constraints of the boundary statements come last.
+    let expressions = &constraints.expressions()
+        [..constraints.len() - boundary.map_or(0, BoundaryConstraints::len)];
+    for expression in expressions {
+        printer.collect(expression);
+    }
+    if !printer.columns.is_empty() || !printer.declarations.is_empty() {
+        writeln!(f)?;
+    }
+    for (column, name) in &printer.columns {
+        writeln!(f, "column {} = {}", name, column)?;
+    }
+    for (i, declaration) in printer.declarations.iter().enumerate() {
+        match declaration {
+            Declaration::Periodic(values, period) => {
+                write!(f, "periodic p{} = ", i)?;
+                write_values(f, values)?;
+                if *period != constraints.trace_nrows() {
+                    write!(f, " over {}", period)?;
+                }
+            }
+            Declaration::Polynomial(polynomial) => {
+                write!(f, "polynomial p{} = ", i)?;
+                write_values(f, polynomial.coefficients())?;
+            }
+        }
+        writeln!(f)?;
+    }
+    if let Some(boundary) = boundary.filter(|boundary| !boundary.is_empty()) {
+        writeln!(f)?;
+        for (column, row, value) in boundary.iter() {
+            f.push_str("boundary ");
+            match printer.columns.get(&column) {
+                Some(name) => f.push_str(name),
+                None => write!(f, "{}", column)?,
+            }
+            write!(f, " {} = ", row)?;
+            write_constant(f, value)?;
+            writeln!(f)?;
+        }
+    }
+
+    writeln!(f)?;
+    for (index, expression) in expressions.iter().enumerate() {
+        f.push_str("constraint ");
+        if let Some(name) = constraints.constraint_name(index) {
+            if is_constraint_name(name) {
+                write!(f, "{}: ", name)?;
+            }
+        }
+        printer.write(f, expression, 0)?;
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::tests::Recurrance, Verifiable};
+    use zkp_macros_decl::field_element;
+
+    const RECURRANCE: &str = "
+        # Fibonacci-like sequence with a public value at row 1000
+        trace 1024 x 2
+        seed 0x00000000000003e8{seed}
+
+        column a = 0
+        column b = 1
+        let value = 0x{value}
+        let g = root(1024)
+        let every_row = (X - pow(g, 1023)) / (pow(X, 1024) - 1)
+
+        constraint (a[1] - pow(b, 1)) * every_row
+        constraint (b[1] - a - b) * every_row
+        constraint start: (a - 1) / (X - pow(g, 1024))
+        constraint result: (a - value) / (
+            X - pow(g, 1000)
+        )
+    ";
+
+    fn recurrance() -> Recurrance {
+        Recurrance {
+            index:         1000,
+            initial_value: field_element!("cafebabe"),
+            exponent:      1,
+        }
+    }
+
+    #[test]
+    fn parse_recurrance() {
+        let claim = recurrance().claim();
+        let text = RECURRANCE
+            .replace("{seed}", &claim.value.as_montgomery().to_string())
+            .replace("{value}", &U256::from(&claim.value).to_string());
+        let parsed = parse(&text).unwrap();
+        let mut expected = claim.legacy_constraints();
+        assert_eq!(parsed.transcript_seed(), expected.transcript_seed());
+        expected.set_column_name(0, "a");
+        expected.set_column_name(1, "b");
+        expected.set_constraint_name(2, "start");
+        expected.set_constraint_name(3, "result");
+        assert_eq!(print(&parsed), print(&expected));
+        assert_eq!(parsed.constraint_name(0), None);
+        assert_eq!(parsed.constraint_name(3), Some("result"));
+    }
+
+    #[test]
+    fn parse_boundary() {
+        let claim = recurrance().claim();
+        let text = format!(
+            "
+            trace 1024 x 2
+            public 0x0000000000000001
+            column a = 0
+            column b = 1
+            let every_row = (X - pow(root(1024), 1023)) / (pow(X, 1024) - 1)
+            boundary a 0 = 1
+            boundary 0 1000 = 0x{}
+            constraint (a[1] - pow(b, 1)) * every_row
+            constraint (b[1] - a - b) * every_row
+            ",
+            U256::from(&claim.value)
+        );
+        let parsed = parse(&text).unwrap();
+        let expected = claim.constraints();
+        assert_eq!(parsed.boundary_constraints(), expected.boundary_constraints());
+        assert_eq!(parsed.channel_seed(), expected.channel_seed());
+        assert_eq!(parsed.transcript_seed(), expected.transcript_seed());
+        assert_eq!(parsed.constraint_name(2), Some("boundary/0/0"));
+    }
+
+    #[test]
+    fn print_names() {
+        let mut constraints = recurrance().claim().constraints();
+        constraints.set_column_name(0, "left|right");
+        constraints.set_column_name(1, "fib");
+        constraints.set_constraint_name(0, "fib/next-1.a");
+        constraints.set_constraint_name(1, "not printed");
+        let text = print(&constraints);
+        assert!(text.contains("column fib = 1\n"));
+        assert!(text.contains("constraint fib/next-1.a: (Trace(0, 1) - pow(fib, 1)) * "));
+        assert!(text.contains("constraint (fib[1] - Trace(0, 0) - fib) * "));
+        let parsed = parse(&text).unwrap();
+        assert_eq!(print(&parsed), text);
+        assert_eq!(parsed.column_name(0), None);
+        assert_eq!(parsed.column_name(1), Some("fib"));
+        assert_eq!(parsed.constraint_name(0), Some("fib/next-1.a"));
+        assert_eq!(parsed.constraint_name(1), None);
+    }
+
+    #[test]
+    fn print_round_trip() {
+        use RationalExpression::*;
+        let mut constraints = recurrance().claim().constraints();
+        let values = (0..8).map(FieldElement::from).collect::<Vec<_>>();
+        let periodic = |values: &[FieldElement], period| {
+            Periodic {
+                values: values.to_vec(),
+                period,
+            }
+        };
+        let boundary = constraints.boundary_constraints().unwrap().clone();
+        let mut expressions =
+            constraints.expressions()[..constraints.len() - boundary.len()].to_vec();
+        expressions.push(periodic(&values[..2], 512) * Trace(0, 0));
+        expressions.push(
+            (Trace(1, -1).neg() * periodic(&values, 1024) - Constant(-&FieldElement::ONE))
+                / (Polynomial(DensePolynomial::new(&values[..4]), Box::new(X + 3.into()))
+                    * (Trace(0, 2) + Trace(1, 0)).neg().inv()),
+        );
+        constraints = Constraints::from_boundary_constraints(&boundary, expressions).unwrap();
+        constraints.blowup = 32;
+        constraints.pow_bits = 12;
+        constraints.num_queries = 20;
+        constraints.fri_layout = vec![2, 1];
+        constraints.coset_offset = 5.into();
+        constraints.zero_knowledge = true;
+        constraints.num_oods_points = 2;
+        constraints.hash_function = HashFunction::Blake2s256;
+        constraints.transcript_version = TranscriptVersion::Legacy;
+
+        let text = print(&constraints);
+        assert!(text.contains("periodic p0 = [0, 1] over 512"));
+        assert!(text.contains("periodic p1 = [0, 1, 2, 3, 4, 5, 6, 7]\n"));
+        assert!(text.contains("polynomial p2 = [0, 1, 2, 3]"));
+        assert!(text.contains("public 0x0000000000000001\n"));
+        assert!(text.contains("boundary 0 0 = 1\nboundary 0 1000 = "));
+        assert!(!text.contains("seed"));
+        let mut parsed = parse(&text).unwrap();
+        assert_eq!(print(&parsed), text);
+        assert_eq!(parsed.channel_seed(), constraints.channel_seed());
+
+        // The V1 transcript binds all parameters and expressions
+        constraints.transcript_version = TranscriptVersion::V1;
+        parsed.transcript_version = TranscriptVersion::V1;
+        assert_eq!(parsed.transcript_seed(), constraints.transcript_seed());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |text: &str| parse(text).unwrap_err();
+        assert_eq!(error("blowup 16"), Error::MissingTrace);
+        assert_eq!(error("trace 8 x 1\ncolumn a = 1"), Error::InvalidColumn {
+            column: 1,
+        });
+        assert_eq!(error("trace 8 x 1\nconstraint a b: X"), Error::Syntax {
+            line:    2,
+            message: "invalid constraint name `a b`".to_string(),
+        });
+        assert_eq!(error("trace 1000 x 1"), Error::InvalidTraceLength);
+        assert_eq!(error("trace 0 x 1"), Error::InvalidTraceLength);
+        assert_eq!(
+            error("trace 8 x 1\nconstraint Trace(1, 0)"),
+            Error::InvalidColumn { column: 1 }
+        );
+        assert_eq!(error("trace 8 x 1\nconstraint a"), Error::UnknownName {
+            line: 2,
+            name: "a".to_string(),
+        });
+        assert_eq!(
+            error("trace 8 x 1\n\nblowup 2\nblowup 4"),
+            Error::Duplicate {
+                line: 4,
+                name: "blowup".to_string(),
+            }
+        );
+        assert_eq!(error("trace 8 x 1\nconstraint (X - 1"), Error::Syntax {
+            line:    2,
+            message: "expected `)`, found end of line".to_string(),
+        });
+        assert_eq!(
+            error("trace 8 x 1\nperiodic k = [1, 2, 3]"),
+            Error::Syntax {
+                line:    2,
+                message: "the number of values must be a power of two dividing the period, \
+                          which must divide the trace length"
+                    .to_string(),
+            }
+        );
+        assert!(matches!(
+            error("trace 8 x 1\nperiodic k = [1, 2] over 16"),
+            Error::Syntax { line: 2, .. }
+        ));
+        assert_eq!(error("trace 8 x 1\nboundary a 0 = 1"), Error::UnknownName {
+            line: 2,
+            name: "a".to_string(),
+        });
+        assert!(matches!(
+            error("trace 8 x 1\nboundary 0 8 = 1"),
+            Error::Constraints(ConstraintError::InvalidBoundary { .. })
+        ));
+        assert_eq!(
+            error("trace 8 x 1\nboundary 0 0 = 1\nseed 0x00"),
+            Error::Syntax {
+                line:    3,
+                message: "`seed` can not be combined with `boundary` or `public`".to_string(),
+            }
+        );
+    }
+}
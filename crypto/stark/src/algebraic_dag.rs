@@ -1,9 +1,9 @@
 use crate::{
     polynomial::DensePolynomial, rational_expression::RationalExpression, trace_table::TraceTable,
 };
-use std::{cmp::min, ops::Neg, prelude::v1::*};
+use std::{cmp::min, collections::BTreeMap, mem::size_of, ops::Neg, prelude::v1::*, sync::Mutex};
 use tiny_keccak::Keccak;
-use zkp_primefield::{invert_batch_src_dst, FieldElement};
+use zkp_primefield::{fft::fft, invert_batch_src_dst, FieldElement};
 use zkp_u256::U256;
 
 /// Number of values to calculate at once.
@@ -96,6 +96,26 @@ enum Operation {
     Lookup(Table),
 }
 
+/// Lookup tables shared between graphs.
+///
+/// Tables are keyed by the graph seed and the node hash. Within graphs with the
+/// same seed, equal hashes identify algebraically identical nodes, so their
+/// tables can be reused.
+#[derive(Debug, Default)]
+pub(crate) struct LookupCache(Mutex<BTreeMap<(U256, U256), Vec<FieldElement>>>);
+
+impl LookupCache {
+    /// Memory used by the tables, in bytes.
+    pub(crate) fn size(&self) -> usize {
+        let tables = self.0.lock().unwrap();
+        tables.values().map(Vec::len).sum::<usize>() * size_of::<FieldElement>()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
 /// Reference to a node in the graph.
 #[derive(Clone, Copy, PartialEq)]
 pub(crate) struct Index(usize);
@@ -330,6 +350,10 @@ impl AlgebraicGraph {
             RE::X => self.op(Op::Coset(self.cofactor.clone(), self.coset_size)),
             RE::Constant(a) => self.op(Op::Constant(a)),
             RE::Trace(i, j) => self.op(Op::Trace(i, j)),
+            RE::Periodic { values, period } => {
+                let a = self.expression(RE::X.pow(period / values.len()));
+                self.op(Op::Poly(RE::periodic_polynomial(&values), a))
+            }
             RE::Polynomial(p, a) => {
                 let a = self.expression(*a);
                 self.op(Op::Poly(p, a))
@@ -359,8 +383,24 @@ impl AlgebraicGraph {
         }
     }
 
+    /// A polynomial evaluated on a coset, such as a periodic column.
+    ///
+    /// Its lookup table can be computed with an FFT, so it gets one regardless
+    /// of its period.
+    fn coset_polynomial(&self, index: Index) -> Option<(&DensePolynomial, &FieldElement)> {
+        if let Operation::Poly(p, a) = &self[index].op {
+            if let Operation::Coset(c, _) = &self[*a].op {
+                return Some((p, c));
+            }
+        }
+        None
+    }
+
     fn make_lookup(&self, index: Index) -> Vec<FieldElement> {
         let node = &self[index];
+        if let Some((polynomial, cofactor)) = self.coset_polynomial(index) {
+            return coset_evaluation(polynomial, cofactor, node.period);
+        }
         assert!(node.period <= 1024);
         let mut result = Vec::with_capacity(node.period);
         let mut subdag = self.clone();
@@ -373,20 +413,31 @@ impl AlgebraicGraph {
         result
     }
 
-    pub(crate) fn lookup_tables(&mut self) {
+    pub(crate) fn lookup_tables(&mut self, cache: &LookupCache) {
         use Operation::*;
         // OPT: Don't create a bunch of lookup tables just to throw them away
         // later. Analyze which nodes will be needed.
         let treshold = min(LOOKUP_SIZE, self.coset_size / 2);
         for i in 0..self.nodes.len() {
             let node = &self.nodes[i];
-            if node.period > treshold {
+            if node.period > treshold && self.coset_polynomial(Index(i)).is_none() {
                 continue;
             }
             if let Coset(..) = node.op {
                 continue;
             }
-            let table = self.make_lookup(Index(i));
+            let key = (
+                self.seed.as_montgomery().clone(),
+                node.hash.as_montgomery().clone(),
+            );
+            let cached = cache.0.lock().unwrap().get(&key).cloned();
+            let table = if let Some(table) = cached {
+                table
+            } else {
+                let table = self.make_lookup(Index(i));
+                let _ = cache.0.lock().unwrap().insert(key, table.clone());
+                table
+            };
             self.nodes[i].op = Lookup(Table(table));
         }
     }
@@ -574,6 +625,23 @@ impl AlgebraicGraph {
     }
 }
 
+/// Evaluate `polynomial` on the coset `cofactor * root(size)^i` with an FFT.
+fn coset_evaluation(
+    polynomial: &DensePolynomial,
+    cofactor: &FieldElement,
+    size: usize,
+) -> Vec<FieldElement> {
+    // Since root(size)^size = 1, coefficients of degree i and i + size
+    // contribute to the same FFT input.
+    let mut coefficients = vec![FieldElement::ZERO; size];
+    let mut power = FieldElement::ONE;
+    for (i, coefficient) in polynomial.coefficients().iter().enumerate() {
+        coefficients[i % size] += coefficient * &power;
+        power *= cofactor;
+    }
+    fft(&coefficients)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -613,6 +681,38 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_periodic_lookup() {
+        let values = (0..8)
+            .map(|i| FieldElement::from(i * i + 3))
+            .collect::<Vec<_>>();
+        let trace = TraceTable::new(32, 1);
+        let cofactor = FieldElement::GENERATOR;
+        for period in &[8, 32] {
+            let expression = RE::Periodic {
+                values: values.clone(),
+                period: *period,
+            } * RE::X;
+            let mut dag = AlgebraicGraph::new(&cofactor, 128, 4);
+            let result = dag.expression(expression.clone());
+            dag.lookup_tables(&LookupCache::default());
+            assert!(dag
+                .nodes
+                .iter()
+                .any(|node| matches!(node.op, Op::Lookup(_))));
+            let _ = dag.tree_shake(result);
+            dag.init(0);
+            let root = FieldElement::root(128).unwrap();
+            for i in 0..128 {
+                let x = &cofactor * root.pow(i);
+                assert_eq!(
+                    dag.next(&trace),
+                    expression.evaluate(&x, &|_, _| unreachable!())
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_hash_x_is_seed() {
         let mut dag = AlgebraicGraph::new(&FieldElement::GENERATOR, 1024, 2);
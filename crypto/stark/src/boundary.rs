@@ -0,0 +1,282 @@
+use crate::{constraints::Error, rational_expression::RationalExpression};
+use std::{collections::BTreeMap, prelude::v1::*};
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+/// Public values of the trace table.
+///
+/// Each entry states that a column has a given value on a given row. The
+/// entries are the public inputs of the claim: they generate the boundary
+/// constraints `(Trace(column, 0) - value) / (X - g^row)` and determine the
+/// channel seed through [`seed`](BoundaryConstraints::seed).
+///
+/// Entries are kept sorted by column and row, so the constraints and the seed
+/// do not depend on the order in which they were added. Public parameters
+/// that are not trace values, such as constants in the transition
+/// constraints, are bound with [`add_public`](BoundaryConstraints::add_public).
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct BoundaryConstraints {
+    trace_nrows:    usize,
+    trace_ncolumns: usize,
+    values:         BTreeMap<(usize, usize), FieldElement>,
+    public:         Vec<Vec<u8>>,
+}
+
+impl BoundaryConstraints {
+    /// No public values for a trace table of the given shape.
+    ///
+    /// Fails with [`Error::InvalidTraceLength`] unless the number of rows is
+    /// a power of two with a root of unity in the field.
+    pub fn new((trace_nrows, trace_ncolumns): (usize, usize)) -> Result<Self, Error> {
+        if !trace_nrows.is_power_of_two() || FieldElement::root(trace_nrows).is_none() {
+            return Err(Error::InvalidTraceLength);
+        }
+        Ok(Self {
+            trace_nrows,
+            trace_ncolumns,
+            values: BTreeMap::new(),
+            public: Vec::new(),
+        })
+    }
+
+    /// The number of rows of the trace table.
+    pub fn trace_nrows(&self) -> usize {
+        self.trace_nrows
+    }
+
+    /// The number of columns of the trace table.
+    pub fn trace_ncolumns(&self) -> usize {
+        self.trace_ncolumns
+    }
+
+    /// Require `column` to equal `value` on `row`.
+    ///
+    /// The cell must lie inside the trace table. Adding the same entry twice
+    /// is allowed, adding a different value for the same cell is an error.
+    pub fn add(&mut self, column: usize, row: usize, value: FieldElement) -> Result<(), Error> {
+        if column >= self.trace_ncolumns || row >= self.trace_nrows {
+            return Err(Error::InvalidBoundary { column, row });
+        }
+        match self.values.get(&(column, row)) {
+            Some(existing) if *existing != value => Err(Error::ConflictingBoundary { column, row }),
+            Some(_) => Ok(()),
+            None => {
+                let _ = self.values.insert((column, row), value);
+                Ok(())
+            }
+        }
+    }
+
+    /// Bind public parameters of the claim that are not trace values.
+    ///
+    /// Each call adds one length-prefixed chunk to the
+    /// [`seed`](BoundaryConstraints::seed), in the order of the calls. They do
+    /// not generate constraints.
+    pub fn add_public(&mut self, bytes: &[u8]) {
+        self.public.push(bytes.to_vec());
+    }
+
+    /// The chunks passed to [`add_public`](BoundaryConstraints::add_public).
+    pub fn public(&self) -> &[Vec<u8>] {
+        &self.public
+    }
+
+    /// The value required on `row` of `column`, if any.
+    pub fn get(&self, column: usize, row: usize) -> Option<&FieldElement> {
+        self.values.get(&(column, row))
+    }
+
+    /// The number of entries.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The entries as `(column, row, value)`, sorted by column and row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &FieldElement)> {
+        self.values
+            .iter()
+            .map(|(&(column, row), value)| (column, row, value))
+    }
+
+    /// The boundary constraint expressions, in the order of [`iter`].
+    ///
+    /// [`iter`]: BoundaryConstraints::iter
+    pub fn expressions(&self) -> Vec<RationalExpression> {
+        use RationalExpression::*;
+        // Can not fail, the trace length was validated in `new`.
+        let trace_generator = FieldElement::root(self.trace_nrows).unwrap();
+        self.iter()
+            .map(|(column, row, value)| {
+                (Trace(column, 0) - Constant(value.clone()))
+                    / (X - Constant(trace_generator.pow(row)))
+            })
+            .collect()
+    }
+
+    /// Canonical encoding of the public inputs, used as channel seed.
+    ///
+    /// Contains the trace dimensions and the number of entries followed by
+    /// every entry, with integers as big-endian `u64`s and values in standard
+    /// (not Montgomery) form. It ends with each chunk passed to
+    /// [`add_public`](BoundaryConstraints::add_public), prefixed by its length.
+    pub fn seed(&self) -> Vec<u8> {
+        let mut seed = Vec::with_capacity(24 + 48 * self.values.len());
+        let write_usize = |seed: &mut Vec<u8>, value: usize| {
+            seed.extend_from_slice(&(value as u64).to_be_bytes());
+        };
+        let write_bytes = |seed: &mut Vec<u8>, bytes: &[u8]| {
+            write_usize(seed, bytes.len());
+            seed.extend_from_slice(bytes);
+        };
+        write_usize(&mut seed, self.trace_nrows);
+        write_usize(&mut seed, self.trace_ncolumns);
+        write_usize(&mut seed, self.values.len());
+        for (column, row, value) in self.iter() {
+            write_usize(&mut seed, column);
+            write_usize(&mut seed, row);
+            seed.extend_from_slice(&U256::from(value).to_bytes_be());
+        }
+        for bytes in &self.public {
+            write_bytes(&mut seed, bytes);
+        }
+        seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prove, prove_checked, verify, Constraints, TraceTable};
+
+    fn fibonacci(value: &FieldElement) -> (Constraints, TraceTable) {
+        use RationalExpression::*;
+        let mut trace = TraceTable::new(8, 2);
+        trace[(0, 0)] = 1.into();
+        trace[(0, 1)] = 1.into();
+        for i in 1..8 {
+            trace[(i, 0)] = trace[(i - 1, 1)].clone();
+            trace[(i, 1)] = &trace[(i - 1, 0)] + &trace[(i - 1, 1)];
+        }
+        let g = Constant(FieldElement::root(8).unwrap());
+        let every_row = (X - g.pow(7)) / (X.pow(8) - 1.into());
+
+        let mut boundary = BoundaryConstraints::new((8, 2)).unwrap();
+        boundary.add(0, 6, value.clone()).unwrap();
+        boundary.add(0, 0, 1.into()).unwrap();
+        boundary.add(1, 0, 1.into()).unwrap();
+        let constraints = Constraints::from_boundary_constraints(&boundary, vec![
+            (Trace(0, 1) - Trace(1, 0)) * every_row.clone(),
+            (Trace(1, 1) - Trace(0, 0) - Trace(1, 0)) * every_row,
+        ])
+        .unwrap();
+        (constraints, trace)
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        let mut first = BoundaryConstraints::new((8, 2)).unwrap();
+        first.add(1, 3, 5.into()).unwrap();
+        first.add(0, 7, 2.into()).unwrap();
+        let mut second = BoundaryConstraints::new((8, 2)).unwrap();
+        second.add(0, 7, 2.into()).unwrap();
+        second.add(1, 3, 5.into()).unwrap();
+        second.add(0, 7, 2.into()).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.seed(), second.seed());
+        assert_eq!(
+            first
+                .iter()
+                .map(|(column, row, _)| (column, row))
+                .collect::<Vec<_>>(),
+            vec![(0, 7), (1, 3)]
+        );
+
+        let mut extended = first.clone();
+        extended.add(0, 0, 0.into()).unwrap();
+        assert_ne!(first.seed(), extended.seed());
+        let mut changed = BoundaryConstraints::new((8, 2)).unwrap();
+        changed.add(0, 7, 2.into()).unwrap();
+        changed.add(1, 3, 6.into()).unwrap();
+        assert_ne!(first.seed(), changed.seed());
+        let mut larger = BoundaryConstraints::new((16, 2)).unwrap();
+        larger.add(0, 7, 2.into()).unwrap();
+        larger.add(1, 3, 5.into()).unwrap();
+        assert_ne!(first.seed(), larger.seed());
+
+        let mut public = first.clone();
+        public.add_public(&[1, 2]);
+        assert_ne!(first, public);
+        assert_eq!(
+            public.seed(),
+            [&first.seed()[..], &[0, 0, 0, 0, 0, 0, 0, 2, 1, 2]].concat()
+        );
+        assert_eq!(public.expressions(), first.expressions());
+
+        // Chunks are framed, so splitting the bytes differently changes the seed
+        let mut split = first.clone();
+        split.add_public(b"ab");
+        split.add_public(b"c");
+        let mut other = first.clone();
+        other.add_public(b"a");
+        other.add_public(b"bc");
+        assert_ne!(split.seed(), other.seed());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(BoundaryConstraints::new((9, 2)).is_err());
+        assert!(BoundaryConstraints::new((5, 2)).is_err());
+        assert!(BoundaryConstraints::new((0, 2)).is_err());
+        let mut boundary = BoundaryConstraints::new((8, 2)).unwrap();
+        assert!(matches!(
+            boundary.add(2, 0, 1.into()),
+            Err(Error::InvalidBoundary {
+                column: 2,
+                row:    0,
+            })
+        ));
+        boundary.add(0, 1, 1.into()).unwrap();
+        assert!(matches!(
+            boundary.add(0, 1, 2.into()),
+            Err(Error::ConflictingBoundary {
+                column: 0,
+                row:    1,
+            })
+        ));
+        assert_eq!(boundary.get(0, 1), Some(&1.into()));
+    }
+
+    #[test]
+    fn test_row_outside_trace() {
+        let mut boundary = BoundaryConstraints::new((8, 2)).unwrap();
+        for &row in &[8, 16] {
+            assert!(matches!(
+                boundary.add(0, row, 1.into()),
+                Err(Error::InvalidBoundary { column: 0, row: r }) if r == row
+            ));
+        }
+        assert!(boundary.is_empty());
+        boundary.add(0, 0, 1.into()).unwrap();
+        assert_eq!(boundary.get(0, 8), None);
+    }
+
+    #[test]
+    fn test_prove_verify() {
+        let (constraints, trace) = fibonacci(&13.into());
+        assert_eq!(constraints.channel_seed()[..8], 8_u64.to_be_bytes());
+        assert_eq!(constraints.constraint_name(2), Some("boundary/0/0"));
+        let proof = prove(&constraints, &trace).unwrap();
+        assert_eq!(verify(&constraints, &proof), Ok(()));
+
+        // A different public value changes the claim.
+        let (other, _) = fibonacci(&14.into());
+        assert_ne!(other.channel_seed(), constraints.channel_seed());
+        assert!(verify(&other, &proof).is_err());
+        assert!(prove_checked(&other, &trace).is_err());
+    }
+}
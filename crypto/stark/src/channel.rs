@@ -1,23 +1,40 @@
+//! Fiat-Shamir transcripts.
+//!
+//! The prover writes its messages to a [`ProverChannel`], which appends them
+//! to the proof and absorbs them into a [`PublicCoin`]. Random challenges are
+//! drawn from the coin, so they depend on everything written before. The
+//! verifier replays the proof through a [`VerifierChannel`] to obtain the same
+//! messages and challenges.
+//!
+//! Values are encoded as 32 byte big-endian numbers, except for `u64`s which
+//! take eight bytes. Field elements are written in Montgomery form. The
+//! channels can be used for other interactive protocols over the same field,
+//! using [`ProverChannel::label`] to separate the parts of a protocol.
 // TODO: Naming?
 #![allow(clippy::module_name_repetitions)]
 use crate::proof_of_work;
-use std::prelude::v1::*;
-use tiny_keccak::Keccak;
-use zkp_hash::Hash;
+use std::{fmt, prelude::v1::*};
+use zkp_hash::{Hash, HashFunction, Hasher};
 use zkp_macros_decl::u256h;
 use zkp_merkle_tree;
 use zkp_primefield::FieldElement;
 use zkp_u256::U256;
 
-pub(crate) trait RandomGenerator<T> {
+/// Draws random values of type `T` from the transcript.
+pub trait RandomGenerator<T> {
     fn get_random(&mut self) -> T;
 }
 
-pub(crate) trait Writable<T> {
+/// Writes values of type `T` to the proof.
+pub trait Writable<T> {
     fn write(&mut self, data: T);
 }
 
-pub(crate) trait Replayable<T> {
+/// Reads values of type `T` from the proof, in the order they were written.
+///
+/// Reading past the end of the proof gives zero bytes and is reported by
+/// [`VerifierChannel::truncated`].
+pub trait Replayable<T> {
     fn replay(&mut self) -> T;
 
     fn replay_many(&mut self, count: usize) -> Vec<T> {
@@ -25,41 +42,116 @@ pub(crate) trait Replayable<T> {
     }
 }
 
-// TODO: Limit to crate
+/// Prefix of the hash input when absorbing a label, see
+/// [`PublicCoin::label`].
+const LABEL_TAG: [u8; 32] = *b"zkp-stark/public-coin/label\0\0\0\0\0";
+
+/// The hash chain that random challenges are derived from.
+///
+/// Writing replaces the digest by the hash of the digest and the data. The
+/// `n`-th random value after a write is the hash of the digest and `n`.
 #[derive(PartialEq, Eq, Clone, Default)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub(crate) struct PublicCoin {
+pub struct PublicCoin {
     pub(crate) digest: [u8; 32],
     counter:           u64,
+    hash_function:     HashFunction,
 }
 
+/// The prover side of a transcript.
 #[derive(PartialEq, Eq, Clone, Default)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub(crate) struct ProverChannel {
+pub struct ProverChannel {
     pub(crate) coin:  PublicCoin,
     pub(crate) proof: Vec<u8>,
 }
 
+/// A source of proof bytes for a [`VerifierChannel`].
+///
+/// With `std` this is implemented for every [`std::io::Read`], such as
+/// `&[u8]`, files and sockets. Without `std` it is implemented for `&[u8]`.
+pub trait ProofSource {
+    /// Fill `buffer` with the next bytes of the proof and return the number of
+    /// bytes read. This is less than `buffer.len()` only at the end of the
+    /// proof.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source fails before the end of the proof.
+    fn read_proof(&mut self, buffer: &mut [u8]) -> Result<usize, ReadError>;
+}
+
+/// A failure of a [`ProofSource`] other than reaching the end of the proof.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReadError {
+    /// An I/O error of this kind, other than
+    /// [`std::io::ErrorKind::Interrupted`].
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// The source failed for another reason.
+    Other,
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            #[cfg(feature = "std")]
+            Self::Io(kind) => write!(f, "{}", std::io::Error::from(kind)),
+            Self::Other => write!(f, "The proof source failed"),
+        }
+    }
+}
+
+/// The verifier side of a transcript.
+///
+/// Reads the proof incrementally from a [`ProofSource`], so only the values
+/// being replayed are held in memory.
 #[derive(PartialEq, Eq, Clone, Default)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub(crate) struct VerifierChannel {
-    pub(crate) coin:  PublicCoin,
-    pub(crate) proof: Vec<u8>,
-    proof_index:      usize,
+pub struct VerifierChannel<S> {
+    pub(crate) coin: PublicCoin,
+    source:          S,
+    position:        usize,
+    truncated:       Option<usize>,
+    read_error:      Option<(usize, ReadError)>,
 }
 
 impl PublicCoin {
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
-            digest:  [0; 32],
-            counter: 0,
+            digest:        [0; 32],
+            counter:       0,
+            hash_function: HashFunction::default(),
         }
     }
 
-    pub(crate) fn seed(&mut self, seed: &[u8]) {
-        let mut keccak = Keccak::new_keccak256();
-        keccak.update(seed);
-        keccak.finalize(&mut self.digest);
+    pub fn seed(&mut self, seed: &[u8], hash_function: HashFunction) {
+        self.hash_function = hash_function;
+        let mut hasher = hash_function.hasher();
+        hasher.update(seed);
+        self.digest = hasher.digest();
+        self.counter = 0;
+    }
+
+    /// The current state of the hash chain.
+    pub fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Absorb a domain separation label.
+    ///
+    /// The new digest is the hash of a fixed 32 byte tag, the digest, the
+    /// length of the label and the label. Writes hash the digest followed by
+    /// the data, so a write only gives the same hash input if the digest
+    /// equals the tag, which is as hard as inverting the hash. Writes are not
+    /// tagged themselves to stay compatible with existing proofs.
+    pub fn label(&mut self, label: &[u8]) {
+        let mut hasher = self.hash_function.hasher();
+        hasher.update(&LABEL_TAG);
+        hasher.update(&self.digest);
+        hasher.update(&(label.len() as u64).to_be_bytes());
+        hasher.update(label);
+        self.digest = hasher.digest();
         self.counter = 0;
     }
 }
@@ -73,35 +165,153 @@ impl From<Vec<u8>> for ProverChannel {
     }
 }
 
-#[cfg(feature = "prover")]
 impl ProverChannel {
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             coin:  PublicCoin::new(),
             proof: Vec::new(),
         }
     }
 
-    pub(crate) fn initialize(&mut self, seed: &[u8]) {
-        self.coin.seed(seed);
+    /// Seed the transcript. This should bind all public inputs of the
+    /// protocol.
+    pub fn initialize(&mut self, seed: &[u8], hash_function: HashFunction) {
+        self.coin.seed(seed, hash_function);
+    }
+
+    /// Absorb a domain separation label, see [`PublicCoin::label`]. Labels
+    /// are not part of the proof.
+    pub fn label(&mut self, label: &[u8]) {
+        self.coin.label(label);
+    }
+
+    pub fn coin(&self) -> &PublicCoin {
+        &self.coin
+    }
+
+    /// The proof written so far.
+    pub fn proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    pub fn into_proof(self) -> Vec<u8> {
+        self.proof
     }
 }
 
-impl VerifierChannel {
-    pub(crate) fn new(proof: Vec<u8>) -> Self {
+impl<S> VerifierChannel<S> {
+    pub fn new(source: S) -> Self {
         Self {
             coin: PublicCoin::new(),
-            proof,
-            proof_index: 0,
+            source,
+            position: 0,
+            truncated: None,
+            read_error: None,
         }
     }
 
-    pub(crate) fn initialize(&mut self, seed: &[u8]) {
-        self.coin.seed(seed);
+    /// Seed the transcript, as in [`ProverChannel::initialize`].
+    pub fn initialize(&mut self, seed: &[u8], hash_function: HashFunction) {
+        self.coin.seed(seed, hash_function);
+    }
+
+    /// Absorb a domain separation label, as in [`ProverChannel::label`].
+    pub fn label(&mut self, label: &[u8]) {
+        self.coin.label(label);
     }
 
-    pub(crate) fn at_end(self) -> bool {
-        self.proof_index == self.proof.len()
+    pub fn coin(&self) -> &PublicCoin {
+        &self.coin
+    }
+
+    /// Byte offset of the next value to be replayed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The length of the proof, if a value was replayed past its end.
+    pub fn truncated(&self) -> Option<usize> {
+        self.truncated
+    }
+
+    /// The offset and cause of the first failure of the source.
+    ///
+    /// Nothing is read from the source after a failure, the remaining values
+    /// are replayed as zeros.
+    pub fn read_error(&self) -> Option<(usize, ReadError)> {
+        self.read_error
+    }
+}
+
+impl<S: ProofSource> VerifierChannel<S> {
+    /// Whether the whole proof has been replayed.
+    ///
+    /// A failure of the source while checking is reported by
+    /// [`VerifierChannel::read_error`].
+    pub fn at_end(&mut self) -> bool {
+        self.read_source(&mut [0_u8]) == 0
+    }
+
+    fn read_source(&mut self, buffer: &mut [u8]) -> usize {
+        if self.read_error.is_some() {
+            return 0;
+        }
+        match self.source.read_proof(buffer) {
+            Ok(read) => read,
+            Err(error) => {
+                self.read_error = Some((self.position, error));
+                0
+            }
+        }
+    }
+
+    /// Read the next `buffer.len()` bytes, padding with zeros at the end of
+    /// the proof, and absorb them into the coin.
+    fn read(&mut self, buffer: &mut [u8]) {
+        let read = self.read_source(buffer);
+        if read < buffer.len() {
+            for byte in &mut buffer[read..] {
+                *byte = 0;
+            }
+            if self.truncated.is_none() && self.read_error.is_none() {
+                self.truncated = Some(self.position + read);
+            }
+        }
+        self.position += read;
+        self.coin.write(&buffer[..]);
+    }
+}
+
+impl VerifierChannel<&[u8]> {
+    /// Number of proof bytes that have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.source.len()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> ProofSource for R {
+    fn read_proof(&mut self, buffer: &mut [u8]) -> Result<usize, ReadError> {
+        let mut read = 0;
+        while read < buffer.len() {
+            match self.read(&mut buffer[read..]) {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(ref error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(ReadError::Io(error.kind())),
+            }
+        }
+        Ok(read)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ProofSource for &[u8] {
+    fn read_proof(&mut self, buffer: &mut [u8]) -> Result<usize, ReadError> {
+        let read = buffer.len().min(self.len());
+        buffer[..read].copy_from_slice(&self[..read]);
+        *self = &self[read..];
+        Ok(read)
     }
 }
 
@@ -109,26 +319,19 @@ impl RandomGenerator<proof_of_work::ChallengeSeed> for PublicCoin {
     fn get_random(&mut self) -> proof_of_work::ChallengeSeed {
         self.counter += 1;
         // FIX: Use get_random::<[u8;32]>();
-        proof_of_work::ChallengeSeed::from_bytes(self.digest)
+        proof_of_work::ChallengeSeed::from_bytes(self.digest, self.hash_function)
     }
 }
 
 impl Writable<proof_of_work::Response> for ProverChannel {
     fn write(&mut self, data: proof_of_work::Response) {
-        self.write(&data.nonce().to_be_bytes()[..]);
+        self.write(data.nonce());
     }
 }
 
-impl Replayable<proof_of_work::Response> for VerifierChannel {
+impl<S: ProofSource> Replayable<proof_of_work::Response> for VerifierChannel<S> {
     fn replay(&mut self) -> proof_of_work::Response {
-        let mut holder = [0_u8; 8];
-        let from = self.proof_index;
-        let to = from + 8;
-        self.proof_index = to;
-        holder.copy_from_slice(&self.proof[from..to]);
-        self.coin.write(&holder[..]);
-        let nonce = u64::from_be_bytes(holder);
-        proof_of_work::Response::from_nonce(nonce)
+        proof_of_work::Response::from_nonce(self.replay())
     }
 }
 
@@ -154,14 +357,12 @@ impl RandomGenerator<U256> for PublicCoin {
 
 impl RandomGenerator<[u8; 32]> for PublicCoin {
     fn get_random(&mut self) -> [u8; 32] {
-        let mut result = [0; 32];
-        let mut keccak = Keccak::new_keccak256();
-        keccak.update(&self.digest);
-        keccak.update(&[0_u8; 24]);
-        keccak.update(&self.counter.to_be_bytes());
-        keccak.finalize(&mut result);
+        let mut hasher = self.hash_function.hasher();
+        hasher.update(&self.digest);
+        hasher.update(&[0_u8; 24]);
+        hasher.update(&self.counter.to_be_bytes());
         self.counter += 1;
-        result
+        hasher.digest()
     }
 }
 
@@ -174,7 +375,7 @@ where
     }
 }
 
-impl<T> RandomGenerator<T> for VerifierChannel
+impl<S, T> RandomGenerator<T> for VerifierChannel<S>
 where
     PublicCoin: RandomGenerator<T>,
 {
@@ -185,13 +386,11 @@ where
 
 impl Writable<&[u8]> for PublicCoin {
     fn write(&mut self, data: &[u8]) {
-        let mut result: [u8; 32] = [0; 32];
-        let mut keccak = Keccak::new_keccak256();
-        keccak.update(&self.digest);
-        keccak.update(data);
-        keccak.finalize(&mut result);
+        let mut hasher = self.hash_function.hasher();
+        hasher.update(&self.digest);
+        hasher.update(data);
         // FIX: Hash counter into digest.
-        self.digest = result;
+        self.digest = hasher.digest();
         self.counter = 0;
     }
 }
@@ -224,6 +423,9 @@ impl Writable<&zkp_merkle_tree::Proof> for ProverChannel {
         for hash in data.hashes() {
             self.write(hash)
         }
+        for salt in data.salts().unwrap_or_default() {
+            self.write(salt)
+        }
     }
 }
 
@@ -262,50 +464,58 @@ impl Writable<U256> for ProverChannel {
     }
 }
 
-impl Replayable<Hash> for VerifierChannel {
+impl Writable<u64> for ProverChannel {
+    fn write(&mut self, data: u64) {
+        self.write(&data.to_be_bytes()[..]);
+    }
+}
+
+impl<S: ProofSource> Replayable<Hash> for VerifierChannel<S> {
     fn replay(&mut self) -> Hash {
         let hash: [u8; 32] = self.replay();
         Hash::new(hash)
     }
 }
 
-impl Replayable<[u8; 32]> for VerifierChannel {
+impl<S: ProofSource> Replayable<[u8; 32]> for VerifierChannel<S> {
     fn replay(&mut self) -> [u8; 32] {
         let mut holder = [0_u8; 32];
-        let from = self.proof_index;
-        let to = from + 32;
-        self.proof_index = to;
-        // OPT: Use arrayref crate or similar to avoid copy
-        holder.copy_from_slice(&self.proof[from..to]);
-        self.coin.write(&holder[..]);
+        self.read(&mut holder);
         holder
     }
 }
 
-impl Replayable<U256> for VerifierChannel {
+impl<S: ProofSource> Replayable<u64> for VerifierChannel<S> {
+    fn replay(&mut self) -> u64 {
+        let mut holder = [0_u8; 8];
+        self.read(&mut holder);
+        u64::from_be_bytes(holder)
+    }
+}
+
+impl<S: ProofSource> Replayable<U256> for VerifierChannel<S> {
     fn replay(&mut self) -> U256 {
         U256::from_bytes_be(&Replayable::replay(self))
     }
 }
 
-impl Replayable<FieldElement> for VerifierChannel {
+impl<S: ProofSource> Replayable<FieldElement> for VerifierChannel<S> {
     fn replay(&mut self) -> FieldElement {
         FieldElement::from_montgomery(Replayable::replay(self))
     }
 
+    // The prover writes these in a single write
     fn replay_many(&mut self, len: usize) -> Vec<FieldElement> {
-        let start_index = self.proof_index;
-        let mut ret = Vec::with_capacity(len);
-        for _ in 0..len {
-            let mut holder = [0_u8; 32];
-            let from = self.proof_index;
-            let to = from + 32;
-            self.proof_index = to;
-            holder.copy_from_slice(&self.proof[from..to]);
-            ret.push(FieldElement::from_montgomery(U256::from_bytes_be(&holder)));
-        }
-        self.coin.write(&self.proof[start_index..self.proof_index]);
-        ret
+        let mut bytes = vec![0_u8; 32 * len];
+        self.read(&mut bytes);
+        bytes
+            .chunks(32)
+            .map(|chunk| {
+                let mut holder = [0_u8; 32];
+                holder.copy_from_slice(chunk);
+                FieldElement::from_montgomery(U256::from_bytes_be(&holder))
+            })
+            .collect()
     }
 }
 
@@ -319,7 +529,10 @@ mod tests {
     #[test]
     fn test_channel_get_random() {
         let mut source = ProverChannel::new();
-        source.initialize(hex!("0123456789abcded").to_vec().as_slice());
+        source.initialize(
+            hex!("0123456789abcded").to_vec().as_slice(),
+            HashFunction::default(),
+        );
         let rand_bytes: [u8; 32] = source.get_random();
         assert_eq!(
             rand_bytes,
@@ -344,7 +557,7 @@ mod tests {
     #[test]
     fn test_channel_write() {
         let mut source = ProverChannel::new();
-        source.initialize(&hex!("0123456789abcded"));
+        source.initialize(&hex!("0123456789abcded"), HashFunction::default());
         let rand_bytes: [u8; 32] = source.get_random();
         source.write(&rand_bytes[..]);
         assert_eq!(
@@ -383,7 +596,7 @@ mod tests {
     #[test]
     fn verifier_channel_test() {
         let mut source = ProverChannel::new();
-        source.initialize(&hex!("0123456789abcded"));
+        source.initialize(&hex!("0123456789abcded"), HashFunction::default());
         let rand_bytes: [u8; 32] = source.get_random();
         source.write(&rand_bytes[..]);
         source.write(proof_of_work::Response::from_nonce(11_028_357_238_u64));
@@ -407,8 +620,8 @@ mod tests {
         ];
         source.write(written_big_int_vec.clone());
 
-        let mut verifier = VerifierChannel::new(source.proof.clone());
-        verifier.initialize(&hex!("0123456789abcded"));
+        let mut verifier = VerifierChannel::new(source.proof.as_slice());
+        verifier.initialize(&hex!("0123456789abcded"), HashFunction::default());
         let bytes_test: [u8; 32] = verifier.replay();
         assert_eq!(bytes_test, rand_bytes);
         assert_eq!(
@@ -442,11 +655,129 @@ mod tests {
     fn test_challenge_seed_from_channel() {
         use crate::channel::*;
         let mut rand_source = ProverChannel::new();
-        rand_source.initialize(&hex!("0123456789abcded"));
+        rand_source.initialize(&hex!("0123456789abcded"), HashFunction::default());
         // Verify that reading challenges does not depend on public coin counter.
         // FIX: Make it depend on public coin counter.
         let seed1: proof_of_work::ChallengeSeed = rand_source.get_random();
         let seed2: proof_of_work::ChallengeSeed = rand_source.get_random();
         assert_eq!(seed1, seed2);
     }
+
+    fn transcript(hash_function: HashFunction) -> ProverChannel {
+        let mut channel = ProverChannel::new();
+        channel.initialize(b"transcript test", hash_function);
+        channel.label(b"commit");
+        channel.write(&Hash::new(hex!(
+            "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff"
+        )));
+        let _: FieldElement = channel.get_random();
+        channel.label(b"respond");
+        channel.write(&FieldElement::from(5));
+        channel.write(u256h!(
+            "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+        ));
+        channel.write(0x0102_0304_0506_0708_u64);
+        channel
+    }
+
+    #[test]
+    fn test_transcript_vectors() {
+        // Hash, field element in Montgomery form, `U256` and `u64`
+        let expected_proof = [
+            &hex!("00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff")[..],
+            &hex!("07fffffffffff570ffffffffffffffffffffffffffffffffffffffffffffff61"),
+            &hex!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+            &hex!("0102030405060708"),
+        ]
+        .concat();
+        for (hash_function, digest, element, number) in &[
+            (
+                HashFunction::Keccak256,
+                hex!("6e783c02091916b3cc4e4535246f183a7fcb4d975754b408a42ed6fe7d0470a1"),
+                u256h!("00920b62d612d9093d5fd76afe8d692a7ae0b12fad99995f59465177d0f70b6e"),
+                u256h!("7f4254eb48d078900fbcd598edf765786b1be31dca83a6b72e07192eaeb41c9b"),
+            ),
+            (
+                HashFunction::Sha256,
+                hex!("4f692acb5c53175c542e7f6a0ecc20ae9e861fbc648b8ef71f926e01f29e7f02"),
+                u256h!("0409026844bdb97cb44af3f5c8eb5475d7236933dfbf819516a1099d69c7c40b"),
+                u256h!("f5f8a26ec44c5c82a096e6c89aea99c616860cfe85b73ac0201737340f4b25c4"),
+            ),
+            (
+                HashFunction::Blake2s256,
+                hex!("f3971b4a83abc52e19df7ced9f2b09e6aed92a2df4a9effc98d3b57461b4f502"),
+                u256h!("062c47321365a218cc5a1a8174b97b80ceaac8d371881280dab850bd0a107621"),
+                u256h!("8501e0298e02a160cbc454196929ca3d5c55649d3d152c641c4ba021ab63231f"),
+            ),
+            (
+                HashFunction::Poseidon,
+                hex!("05d4f734b01db52d9183fbe9c0011d433b194a1c89782ea8e034c7a307cefb24"),
+                u256h!("03d07cf8daa5d34e995836c56c2158f4590db2dd743785b564e501fd35523915"),
+                u256h!("0039802ea385264325d1fe381264970b2bec5c148687ea174d5b6717027d382f"),
+            ),
+            (
+                HashFunction::Pedersen,
+                hex!("06f693a8dd13c9bd9d0ca3929da63525ae12fc214c66a3d3999a78b4f99f76a1"),
+                u256h!("05be9550e6ef7d97ee8f57b1e937eafb2fac457dbd7b0815a2bb9aaa9c49019a"),
+                u256h!("06ad012863ca04416607253ea712fca0e048cfaf4a31088284949014b5393ccb"),
+            ),
+        ] {
+            let mut channel = transcript(*hash_function);
+            assert_eq!(channel.proof(), expected_proof.as_slice());
+            assert_eq!(channel.coin().digest(), digest);
+            let random_element: FieldElement = channel.get_random();
+            assert_eq!(random_element.as_montgomery(), element);
+            let random_number: U256 = channel.get_random();
+            assert_eq!(&random_number, number);
+        }
+    }
+
+    #[test]
+    fn test_transcript_replay() {
+        let prover = transcript(HashFunction::Sha256);
+
+        let mut verifier = VerifierChannel::new(prover.proof());
+        verifier.initialize(b"transcript test", HashFunction::Sha256);
+        verifier.label(b"commit");
+        let hash: Hash = verifier.replay();
+        assert_eq!(hash.as_bytes()[..2], [0x00, 0x11]);
+        let _: FieldElement = verifier.get_random();
+        verifier.label(b"respond");
+        let element: FieldElement = verifier.replay();
+        assert_eq!(element, FieldElement::from(5));
+        let number: U256 = verifier.replay();
+        assert_eq!(
+            number,
+            u256h!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef")
+        );
+        let nonce: u64 = verifier.replay();
+        assert_eq!(nonce, 0x0102_0304_0506_0708);
+        assert_eq!(verifier.coin(), prover.coin());
+        assert!(verifier.at_end());
+    }
+
+    #[test]
+    fn test_label_separation() {
+        let mut seeded = ProverChannel::new();
+        seeded.initialize(b"seed", HashFunction::default());
+        let mut labeled = seeded.clone();
+        labeled.label(b"abc");
+        assert!(labeled.proof().is_empty());
+        let mut other = seeded.clone();
+        other.label(b"abd");
+        assert_ne!(labeled.coin(), other.coin());
+
+        // Writing the label, or the label with its length and a prefix, does
+        // not reproduce it
+        for prefix in &[&b""[..], &b"label"[..], &LABEL_TAG[..]] {
+            let mut data = prefix.to_vec();
+            if !prefix.is_empty() {
+                data.extend_from_slice(&3_u64.to_be_bytes());
+            }
+            data.extend_from_slice(b"abc");
+            let mut written = seeded.clone();
+            written.write(data.as_slice());
+            assert_ne!(labeled.coin(), written.coin());
+        }
+    }
 }
@@ -2,7 +2,11 @@ use crate::{
     constraint_check::check_constraints, primefield::FieldElement, Constraints, Provable,
     RationalExpression, TraceTable, Verifiable,
 };
-use std::{collections::HashMap, convert::TryFrom};
+use log::info;
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
+};
 
 // TODO: Introduce prover/verifier distinction
 
@@ -15,9 +19,11 @@ use std::{collections::HashMap, convert::TryFrom};
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct Component {
     // TODO: Make private
-    pub trace:       TraceTable,
-    pub constraints: Vec<RationalExpression>,
-    pub labels:      HashMap<String, (usize, RationalExpression)>,
+    pub trace:            TraceTable,
+    pub constraints:      Vec<RationalExpression>,
+    pub labels:           HashMap<String, (usize, RationalExpression)>,
+    column_names:         BTreeMap<usize, String>,
+    constraint_names:     BTreeMap<usize, String>,
 }
 
 /// Utility function to add offsets on indices
@@ -32,26 +38,90 @@ fn index_rotate(len: usize, index: usize, offset: isize) -> usize {
 }
 
 impl Component {
+    /// Constructs a component without column and constraint names.
+    pub fn new(
+        trace: TraceTable,
+        constraints: Vec<RationalExpression>,
+        labels: HashMap<String, (usize, RationalExpression)>,
+    ) -> Self {
+        Self {
+            trace,
+            constraints,
+            labels,
+            column_names:     BTreeMap::new(),
+            constraint_names: BTreeMap::new(),
+        }
+    }
+
     /// Constructs an empty component of given size.
     ///
     /// This is useful in combination with composition combinators to pad out a
     /// component to a required size.
     pub fn empty(rows: usize, columns: usize) -> Self {
         Self {
-            trace:       TraceTable::new(rows, columns),
-            constraints: Vec::new(),
-            labels:      HashMap::new(),
+            trace:            TraceTable::new(rows, columns),
+            constraints:      Vec::new(),
+            labels:           HashMap::new(),
+            column_names:     BTreeMap::new(),
+            constraint_names: BTreeMap::new(),
         }
     }
 
+    pub fn column_name(&self, column: usize) -> Option<&str> {
+        self.column_names.get(&column).map(String::as_str)
+    }
+
+    /// Name a trace column, see [`Constraints::set_column_name`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` is not a column of the trace table.
+    pub fn set_column_name(&mut self, column: usize, name: &str) {
+        assert!(column < self.trace.num_columns());
+        let _ = self.column_names.insert(column, name.to_owned());
+    }
+
+    pub fn constraint_name(&self, index: usize) -> Option<&str> {
+        self.constraint_names.get(&index).map(String::as_str)
+    }
+
+    /// Name a constraint, see [`Constraints::set_constraint_name`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the number of constraints.
+    pub fn set_constraint_name(&mut self, index: usize, name: &str) {
+        assert!(index < self.constraints.len());
+        let _ = self.constraint_names.insert(index, name.to_owned());
+    }
+
     pub fn check(&self) -> bool {
-        let constraints = Constraints::from_expressions(
-            (self.trace.num_rows(), self.trace.num_columns()),
-            Vec::new(),
-            self.constraints.clone(),
-        )
-        .unwrap();
-        check_constraints(&constraints, &self.trace).is_ok()
+        let constraints = Verifiable::constraints(self);
+        match check_constraints(&constraints, &self.trace) {
+            Ok(()) => true,
+            Err((row, constraint)) => {
+                info!(
+                    "Constraint {} failed at row {}",
+                    constraints.describe_constraint(constraint),
+                    row
+                );
+                false
+            }
+        }
+    }
+
+    /// Prefix all column and constraint names with `prefix/`.
+    ///
+    /// Use this to namespace the names of subcomponents before composing
+    /// them, for example to get `hash/ec_subset_sum/booleanity`.
+    pub fn prefix_names(&mut self, prefix: &str) {
+        for name in self
+            .column_names
+            .values_mut()
+            .chain(self.constraint_names.values_mut())
+        {
+            *name = format!("{}/{}", prefix, name);
+        }
     }
 
     pub fn generator(&self) -> FieldElement {
@@ -99,6 +169,22 @@ impl Component {
                 target.trace[trace_map(i, j)] = self.trace[(i, j)].clone();
             }
         }
+        // Copy over names. Columns that end up in the same target column,
+        // such as in `fold`, get both names.
+        for (&column, name) in &self.column_names {
+            let column = trace_map(0, column).1;
+            let name = match target.column_names.get(&column) {
+                Some(existing) if existing != name => format!("{}|{}", existing, name),
+                _ => name.clone(),
+            };
+            let _ = target.column_names.insert(column, name);
+        }
+        let offset = target.constraints.len();
+        target.constraint_names.extend(
+            self.constraint_names
+                .iter()
+                .map(|(index, name)| (offset + index, name.clone())),
+        );
         // Copy over Constraints
         target.constraints.extend(
             self.constraints
@@ -118,12 +204,19 @@ impl Component {
 
 impl Verifiable for Component {
     fn constraints(&self) -> Constraints {
-        Constraints::from_expressions(
+        let mut constraints = Constraints::from_expressions(
             (self.trace.num_rows(), self.trace.num_columns()),
             Vec::new(), // TODO: create a meaningful seed value
             self.constraints.clone(),
         )
-        .expect("Could not produce Constraint object for Component")
+        .expect("Could not produce Constraint object for Component");
+        for (&column, name) in &self.column_names {
+            constraints.set_column_name(column, name);
+        }
+        for (&index, name) in &self.constraint_names {
+            constraints.set_constraint_name(index, name);
+        }
+        constraints
     }
 }
 
@@ -191,6 +284,18 @@ pub fn shift(a: Component, amount: isize) -> Component {
         |expression| {
             match expression {
                 X => Constant(factor.clone()) * X,
+                // On a trace of `period` rows this is a rotation of the values
+                Periodic { mut values, period } if period == a.trace.num_rows() => {
+                    let amount = amount_abs % values.len();
+                    values.rotate_right(amount);
+                    Periodic { values, period }
+                }
+                Periodic { values, period } => {
+                    Polynomial(
+                        RationalExpression::periodic_polynomial(&values),
+                        Box::new((Constant(factor.clone()) * X).pow(period / values.len())),
+                    )
+                }
                 other => other,
             }
         },
@@ -277,6 +382,12 @@ pub fn compose_vertical(mut a: Component, mut b: Component) -> Component {
     let expr_map = |expression| {
         match expression {
             X => X.pow(2),
+            Periodic { values, period } => {
+                Periodic {
+                    values,
+                    period: 2 * period,
+                }
+            }
             other => other,
         }
     };
@@ -295,6 +406,7 @@ pub fn compose_vertical(mut a: Component, mut b: Component) -> Component {
     b.project_into(&mut result, |i, j| (i + a.trace.num_rows(), j), expr_map);
     // Remove b's constraints (but keep the mapped labels)
     result.constraints.truncate(a.constraints.len());
+    let _ = result.constraint_names.split_off(&a.constraints.len());
     result
 }
 
@@ -391,10 +503,13 @@ impl Component {
         let omega = Constant(FieldElement::root(rows).unwrap());
         let mut constraints = Vec::new();
         let mut labels = HashMap::new();
+        let mut constraint_names = BTreeMap::new();
+        let column_names = (0..columns).map(|i| (i, format!("x{}", i))).collect();
         // x[0] = start
         if rows * columns >= 1 {
             constraints.push((Trace(0, 0) - constraint_seed.into()) / (X - omega.pow(0)));
             let _ = labels.insert("start".to_owned(), (0, Trace(0, 0)));
+            let _ = constraint_names.insert(0, "start".to_owned());
         }
         if rows * columns >= 3 {
             let _ = labels.insert("final".to_owned(), (rows - 1, Trace(columns - 1, 0)));
@@ -425,6 +540,7 @@ impl Component {
                     (..) => 1.into(),
                 };
                 // x[i+2] = x[i] * x[i + 1] + offset
+                let _ = constraint_names.insert(constraints.len(), format!("step{}", i));
                 constraints.push(
                     (Trace(i, 0) - x0 * x1 - constraint_seed.into()) * exceptions
                         / (X.pow(rows) - 1.into()),
@@ -436,6 +552,8 @@ impl Component {
             trace,
             constraints,
             labels,
+            column_names,
+            constraint_names,
         }
     }
 }
@@ -541,6 +659,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_names() {
+        let component = Component::example(4, 2, &2.into(), &3.into());
+        let constraints = Verifiable::constraints(&component);
+        assert_eq!(constraints.column_name(1), Some("x1"));
+        assert_eq!(constraints.describe_constraint(0), "`start`");
+
+        let permuted = permute_columns(component.clone(), &[1, 0]);
+        assert_eq!(permuted.column_name(0), Some("x1"));
+        assert_eq!(permuted.constraint_names, component.constraint_names);
+
+        let folded = fold(component.clone());
+        assert_eq!(folded.column_name(0), Some("x0|x1"));
+
+        let mut left = component.clone();
+        let mut right = component.clone();
+        left.prefix_names("left");
+        right.prefix_names("right");
+        let composed = compose_horizontal(left, right);
+        assert_eq!(composed.column_name(0), Some("left/x0"));
+        assert_eq!(composed.column_name(3), Some("right/x1"));
+        assert_eq!(
+            composed.constraint_name(component.constraints.len()),
+            Some("right/start")
+        );
+
+        let bottom = Component::example(4, 2, &2.into(), &5.into());
+        let stacked = compose_vertical(component.clone(), bottom);
+        assert_eq!(stacked.column_names, component.column_names);
+        assert_eq!(stacked.constraint_names, component.constraint_names);
+    }
+
+    #[test]
+    fn test_periodic() {
+        use RationalExpression::*;
+        let values = [3, 1, 4, 1]
+            .iter()
+            .map(|&v| FieldElement::from(v))
+            .collect::<Vec<_>>();
+        let mut component = Component::empty(8, 2);
+        for row in 0..8 {
+            component.trace[(row, 0)] = values[row % 4].clone();
+            component.trace[(row, 1)] = FieldElement::from(row);
+        }
+        component.constraints.push(
+            (Trace(0, 0)
+                - Periodic {
+                    values: values.clone(),
+                    period: 8,
+                })
+                / (X.pow(8) - 1.into()),
+        );
+        assert!(component.check());
+
+        let shifted = shift(component.clone(), -3);
+        assert!(shifted.check());
+        // The values are rotated instead of interpolated
+        let rotated = std::cell::Cell::new(false);
+        let _ = shifted.constraints[0].map(&|expression| {
+            if let Periodic { values, .. } = &expression {
+                rotated.set(values[0] == 1.into());
+            }
+            expression
+        });
+        assert!(rotated.get());
+        assert!(shift(component.clone(), 13).check());
+        assert!(compose_vertical(component.clone(), component.clone()).check());
+        assert!(shift(compose_vertical(component.clone(), component.clone()), 5).check());
+        assert!(fold(component.clone()).check());
+        // The periodic column no longer has the period of the trace
+        assert!(shift(fold(component.clone()), 3).check());
+
+        component.trace[(5, 0)] += FieldElement::ONE;
+        assert!(!component.check());
+    }
+
     proptest! {
 
         #[test]
@@ -1,11 +1,24 @@
-use crate::rational_expression::RationalExpression;
+use crate::{
+    boundary::BoundaryConstraints, channel::VerifierChannel,
+    rational_expression::RationalExpression, security, verifier::get_indices,
+};
 use itertools::Itertools;
-use std::{collections::BTreeSet, fmt, prelude::v1::*};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    prelude::v1::*,
+};
+use zkp_hash::{Hash, HashFunction, Hasher};
+use zkp_merkle_tree::{Commitment, Error as MerkleError};
 use zkp_primefield::FieldElement;
+use zkp_u256::U256;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Error {
     InvalidTraceLength,
+    InvalidPeriodicColumn,
+    InvalidBoundary { column: usize, row: usize },
+    ConflictingBoundary { column: usize, row: usize },
 }
 
 impl fmt::Display for Error {
@@ -13,10 +26,103 @@ impl fmt::Display for Error {
         use Error::*;
         match *self {
             InvalidTraceLength => write!(f, "Invalid trace length (must be power of two)"),
+            InvalidPeriodicColumn => {
+                write!(
+                    f,
+                    "Invalid periodic column (number of values must be a power of two dividing \
+                     the period, which must divide the trace length)"
+                )
+            }
+            InvalidBoundary { column, row } => {
+                write!(
+                    f,
+                    "Boundary constraint on column {} row {} is outside the trace",
+                    column, row
+                )
+            }
+            ConflictingBoundary { column, row } => {
+                write!(
+                    f,
+                    "Conflicting boundary constraints on column {} row {}",
+                    column, row
+                )
+            }
         }
     }
 }
 
+/// How the proof parameters are bound into the transcript.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum TranscriptVersion {
+    /// The channel is seeded with the channel seed only, followed by any
+    /// non-default coset offset, zero-knowledge mode or number of
+    /// out-of-domain points. This is compatible with existing proofs and the
+    /// `StarkWare` verifier contracts, but a proof may verify under other
+    /// parameters, for example with fewer proof of work bits.
+    Legacy,
+    /// The channel is seeded with [`Constraints::PROTOCOL_ID`] followed by a
+    /// canonical encoding of all the parameters and a hash of the constraint
+    /// expressions.
+    V1,
+}
+
+/// Distribution of the proof size in bytes, see
+/// [`Constraints::expected_proof_size`].
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ProofSize {
+    pub min:  usize,
+    pub mean: f64,
+    pub max:  usize,
+}
+
+/// Size in bytes of each section of a proof, see [`Constraints::proof_size`].
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SectionSizes {
+    pub trace_commitment:        usize,
+    pub constraint_commitment:   usize,
+    pub oods_values:             usize,
+    pub fri_roots:               usize,
+    pub last_layer_coefficients: usize,
+    pub pow_nonce:               usize,
+    pub trace_decommitment:      usize,
+    pub constraint_decommitment: usize,
+    /// The decommitment of each FRI layer.
+    pub fri_decommitments:       Vec<usize>,
+}
+
+impl SectionSizes {
+    /// Size in bytes of the whole proof.
+    pub fn total(&self) -> usize {
+        self.trace_commitment
+            + self.constraint_commitment
+            + self.oods_values
+            + self.fri_roots
+            + self.last_layer_coefficients
+            + self.pow_nonce
+            + self.trace_decommitment
+            + self.constraint_decommitment
+            + self.fri_decommitments.iter().sum::<usize>()
+    }
+}
+
+/// Properties of the constraint expressions that the proof layout depends
+/// on.
+///
+/// Computing these walks all expressions, so code that evaluates many
+/// parameter choices for the same expressions computes them once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Shape {
+    /// See [`Constraints::degree`].
+    pub(crate) degree:        usize,
+    /// Number of [`Constraints::trace_arguments`].
+    pub(crate) num_arguments: usize,
+    /// Number of distinct row offsets in the trace arguments.
+    pub(crate) num_offsets:   usize,
+}
+
 /// Constraints for Stark proofs
 ///
 /// Contains the constraint expressions that apply to the trace table in
@@ -36,6 +142,13 @@ pub struct Constraints {
 
     expressions: Vec<RationalExpression>,
 
+    // Names are only used in messages and are not part of the transcript.
+    column_names:     BTreeMap<usize, String>,
+    constraint_names: BTreeMap<usize, String>,
+
+    // The public inputs the seed and the trailing expressions are derived from.
+    boundary: Option<BoundaryConstraints>,
+
     /// The blowup factor
     ///
     /// The size of the low-degree-extension domain compared to the trace
@@ -60,9 +173,56 @@ pub struct Constraints {
     /// After `fri_layout.sum()` reductions are done, the remaining polynomial
     /// is written explicitly in coefficient form.
     pub fri_layout: Vec<usize>,
+
+    /// Offset of the low-degree-extension coset
+    ///
+    /// The trace and constraint polynomials are evaluated on the coset
+    /// `coset_offset * <ω>` where `ω` generates the evaluation domain. The
+    /// offset must not be in the evaluation domain itself. Defaults to
+    /// `FieldElement::GENERATOR`. Any other value is bound into the channel.
+    pub coset_offset: FieldElement,
+
+    /// Zero-knowledge mode
+    ///
+    /// When set, the prover masks each trace polynomial with a random multiple
+    /// of the vanishing polynomial of the trace domain, adds a random
+    /// polynomial to the out-of-domain combination and salts the leaves of the
+    /// trace and constraint commitments. The masked trace polynomials have
+    /// at least twice the degree, and more for short traces with many
+    /// queries or out-of-domain points, so the blowup needs to be that much
+    /// larger than the constraint degree and each query provides at least one
+    /// bit less security.
+    pub zero_knowledge: bool,
+
+    /// Number of out-of-domain sampling points
+    ///
+    /// The trace and constraint polynomials are opened at this many random
+    /// points outside the evaluation domain, and the verifier checks the
+    /// constraints at each of them. Every point adds one field element per
+    /// trace argument and constraint polynomial to the proof, but multiplies
+    /// the soundness of the out-of-domain check, see
+    /// [`Constraints::oods_security_bits`]. Defaults to one.
+    pub num_oods_points: usize,
+
+    /// Hash function for the channel, the commitments and the proof of work
+    ///
+    /// Defaults to Keccak-256. Verifiers on chains with cheap SHA-256 or
+    /// Blake2s can use those instead. The channel is seeded using this hash
+    /// function, so the choice is bound into the transcript.
+    pub hash_function: HashFunction,
+
+    /// Binding of the parameters into the transcript
+    ///
+    /// Defaults to [`TranscriptVersion::V1`], which makes sure a proof for
+    /// one set of constraints and parameters does not verify under another.
+    pub transcript_version: TranscriptVersion,
 }
 
 impl Constraints {
+    /// Protocol identifier that starts the [`TranscriptVersion::V1`]
+    /// transcript.
+    pub const PROTOCOL_ID: &'static [u8] = b"zkp-stark/v1";
+
     fn default_fri_layout(trace_nrows: usize) -> Vec<usize> {
         // The binary logarithm of the final layer polynomial degree.
         const LOG2_TARGET: usize = 8;
@@ -91,8 +251,13 @@ impl Constraints {
         expressions: Vec<RationalExpression>,
     ) -> Result<Self, Error> {
         let _ = FieldElement::root(trace_nrows).ok_or(Error::InvalidTraceLength)?;
+        if !expressions
+            .iter()
+            .all(|expression| expression.has_valid_periodic_columns(trace_nrows))
+        {
+            return Err(Error::InvalidPeriodicColumn);
+        }
         // TODO: Validate expressions
-        // TODO: Hash expressions into channel seed
         // TODO - Examine if we want to up these security params further.
         // 22.5*4  + 0 queries = 90
         // TODO: Sensible default for pow_bits. For small proofs it should be small.
@@ -101,17 +266,183 @@ impl Constraints {
             trace_nrows,
             trace_ncolumns,
             expressions,
+            column_names: BTreeMap::new(),
+            constraint_names: BTreeMap::new(),
+            boundary: None,
             blowup: 16,
             pow_bits: 0,
             num_queries: 45,
             fri_layout: Self::default_fri_layout(trace_nrows),
+            coset_offset: FieldElement::GENERATOR,
+            zero_knowledge: false,
+            num_oods_points: 1,
+            hash_function: HashFunction::default(),
+            transcript_version: TranscriptVersion::V1,
         })
     }
 
+    /// Constraints for a claim whose public inputs are `boundary`.
+    ///
+    /// The boundary constraints are appended to `expressions` and are named
+    /// `boundary/<column>/<row>`. The channel seed is derived from `boundary`.
+    pub fn from_boundary_constraints(
+        boundary: &BoundaryConstraints,
+        mut expressions: Vec<RationalExpression>,
+    ) -> Result<Self, Error> {
+        let offset = expressions.len();
+        expressions.extend(boundary.expressions());
+        let mut constraints = Self::from_expressions(
+            (boundary.trace_nrows(), boundary.trace_ncolumns()),
+            boundary.seed(),
+            expressions,
+        )?;
+        for (index, (column, row, _)) in boundary.iter().enumerate() {
+            constraints
+                .set_constraint_name(offset + index, &format!("boundary/{}/{}", column, row));
+        }
+        constraints.boundary = Some(boundary.clone());
+        Ok(constraints)
+    }
+
+    /// The public inputs, if created by
+    /// [`from_boundary_constraints`](Constraints::from_boundary_constraints).
+    pub fn boundary_constraints(&self) -> Option<&BoundaryConstraints> {
+        self.boundary.as_ref()
+    }
+
     pub fn channel_seed(&self) -> &[u8] {
         &self.channel_seed
     }
 
+    /// The seed used to initialize the channel.
+    ///
+    /// See [`TranscriptVersion`] for what it contains. For the legacy
+    /// transcript with default parameters it equals the channel seed.
+    pub(crate) fn transcript_seed(&self) -> Vec<u8> {
+        match self.transcript_version {
+            TranscriptVersion::Legacy => self.legacy_transcript_seed(),
+            TranscriptVersion::V1 => self.canonical_transcript_seed(),
+        }
+    }
+
+    fn legacy_transcript_seed(&self) -> Vec<u8> {
+        let mut seed = self.channel_seed.clone();
+        if self.coset_offset != FieldElement::GENERATOR {
+            seed.extend_from_slice(&self.coset_offset.as_montgomery().to_bytes_be());
+        }
+        if self.zero_knowledge {
+            seed.extend_from_slice(b"zero-knowledge");
+        }
+        if self.num_oods_points != 1 {
+            seed.extend_from_slice(b"oods-points");
+            seed.extend_from_slice(&(self.num_oods_points as u64).to_be_bytes());
+        }
+        seed
+    }
+
+    fn canonical_transcript_seed(&self) -> Vec<u8> {
+        let mut seed = Vec::new();
+        let write_bytes = |seed: &mut Vec<u8>, bytes: &[u8]| {
+            seed.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+            seed.extend_from_slice(bytes);
+        };
+        let write_usize = |seed: &mut Vec<u8>, value: usize| {
+            seed.extend_from_slice(&(value as u64).to_be_bytes());
+        };
+
+        write_bytes(&mut seed, Self::PROTOCOL_ID);
+        write_bytes(&mut seed, &self.channel_seed);
+        write_usize(&mut seed, self.trace_nrows);
+        write_usize(&mut seed, self.trace_ncolumns);
+        write_usize(&mut seed, self.blowup);
+        write_usize(&mut seed, self.pow_bits);
+        write_usize(&mut seed, self.num_queries);
+        write_usize(&mut seed, self.fri_layout.len());
+        for &reductions in &self.fri_layout {
+            write_usize(&mut seed, reductions);
+        }
+        seed.extend_from_slice(&U256::from(&self.coset_offset).to_bytes_be());
+        seed.push(u8::from(self.zero_knowledge));
+        write_usize(&mut seed, self.num_oods_points);
+        write_bytes(&mut seed, self.hash_function.to_string().as_bytes());
+
+        // The expressions can be large, so only their hash is included
+        let mut expressions = Vec::new();
+        for expression in &self.expressions {
+            expression.write_canonical(&mut expressions);
+        }
+        let mut hasher = self.hash_function.hasher();
+        hasher.update(&expressions);
+        write_usize(&mut seed, self.expressions.len());
+        seed.extend_from_slice(&hasher.digest());
+        seed
+    }
+
+    /// Number of coefficients of the committed trace polynomials.
+    ///
+    /// In zero-knowledge mode the trace polynomials are masked with a random
+    /// multiple of the vanishing polynomial of the trace domain. The mask has
+    /// at least as many random coefficients as the trace has rows, and at
+    /// least as many as there are revealed evaluations of each trace
+    /// polynomial (see [`Constraints::num_trace_openings`]).
+    pub(crate) fn trace_poly_len(&self) -> usize {
+        if self.zero_knowledge {
+            self.trace_poly_len_with(&self.shape())
+        } else {
+            self.trace_nrows
+        }
+    }
+
+    /// [`Constraints::trace_poly_len`] with a precomputed [`Shape`].
+    pub(crate) fn trace_poly_len_with(&self, shape: &Shape) -> usize {
+        if self.zero_knowledge {
+            let mask_len = std::cmp::max(self.trace_nrows, self.num_trace_openings_with(shape));
+            (self.trace_nrows + mask_len).next_power_of_two()
+        } else {
+            self.trace_nrows
+        }
+    }
+
+    /// Upper bound on the number of evaluations of a single trace polynomial
+    /// revealed by a proof.
+    ///
+    /// Each out-of-domain point opens the trace at every distinct row offset
+    /// and each query opens it once in the evaluation domain.
+    pub(crate) fn num_trace_openings(&self) -> usize {
+        self.num_trace_openings_with(&self.shape())
+    }
+
+    fn num_trace_openings_with(&self, shape: &Shape) -> usize {
+        self.num_oods_points * shape.num_offsets + self.num_queries
+    }
+
+    /// Size of the evaluation domain relative to the trace polynomial length.
+    ///
+    /// This is the effective blowup of the committed polynomials, which
+    /// determines the size of the final FRI layer and the soundness per query.
+    pub(crate) fn lde_blowup(&self) -> usize {
+        self.blowup * self.trace_nrows / self.trace_poly_len()
+    }
+
+    pub(crate) fn lde_blowup_with(&self, shape: &Shape) -> usize {
+        self.blowup * self.trace_nrows / self.trace_poly_len_with(shape)
+    }
+
+    /// Compute the [`Shape`] of the constraint expressions.
+    pub(crate) fn shape(&self) -> Shape {
+        let arguments = self.trace_arguments();
+        let num_offsets = arguments
+            .iter()
+            .map(|(_, offset)| offset)
+            .collect::<BTreeSet<_>>()
+            .len();
+        Shape {
+            degree:        self.degree(),
+            num_arguments: arguments.len(),
+            num_offsets,
+        }
+    }
+
     pub fn trace_nrows(&self) -> usize {
         self.trace_nrows
     }
@@ -132,6 +463,48 @@ impl Constraints {
         &self.expressions
     }
 
+    pub fn column_name(&self, column: usize) -> Option<&str> {
+        self.column_names.get(&column).map(String::as_str)
+    }
+
+    /// Name a trace column.
+    ///
+    /// Names are for error messages and debug output only, they do not
+    /// affect proofs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` is not less than [`Self::trace_ncolumns`].
+    pub fn set_column_name(&mut self, column: usize, name: &str) {
+        assert!(column < self.trace_ncolumns);
+        let _ = self.column_names.insert(column, name.to_owned());
+    }
+
+    pub fn constraint_name(&self, index: usize) -> Option<&str> {
+        self.constraint_names.get(&index).map(String::as_str)
+    }
+
+    /// Name a constraint, for example `hash/ec_subset_sum/booleanity`.
+    ///
+    /// Names are for error messages and debug output only, they do not
+    /// affect proofs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [`Self::len`].
+    pub fn set_constraint_name(&mut self, index: usize, name: &str) {
+        assert!(index < self.expressions.len());
+        let _ = self.constraint_names.insert(index, name.to_owned());
+    }
+
+    /// The constraint's name in backquotes if it has one, or its index.
+    pub fn describe_constraint(&self, index: usize) -> String {
+        match self.constraint_name(index) {
+            Some(name) => format!("`{}`", name),
+            None => index.to_string(),
+        }
+    }
+
     pub fn degree(&self) -> usize {
         self.expressions
             .iter()
@@ -143,55 +516,268 @@ impl Constraints {
             .expect("no constraints")
     }
 
-    // TODO: Better explanation with literature references.
+    /// Conjectured security level in bits.
+    ///
+    /// See [`conjectured_security`] for the formula and [`proven_security`]
+    /// for a bound that does not rely on conjectures.
+    ///
+    /// [`conjectured_security`]: crate::conjectured_security
+    /// [`proven_security`]: crate::proven_security
     pub fn security_bits(&self) -> usize {
-        // Our conservative formula is (1/2^blowup_log)^(queries/2)*(1/2^pow_bits)
-        // So the bit security should be blowup_log*(queries/2) + pow_bits
-        let blowup_log = (64 - (self.blowup as u64).leading_zeros()) as usize;
-        blowup_log * (self.num_queries / 2) + self.pow_bits
+        security::conjectured_security(self)
+    }
+
+    /// Bits of security of the out-of-domain check.
+    ///
+    /// A false claim passes the check at a random point with probability at
+    /// most `degree / |F|`, where `degree` is the degree of the combined
+    /// constraint polynomial. Each independent point multiplies this.
+    ///
+    /// This only bounds the out-of-domain check. The security of the proof is
+    /// also capped by the field size relative to the evaluation domain (see
+    /// [`Constraints::security_bits`]), which does not depend on the number of
+    /// points. More points therefore only help while this check is the
+    /// weakest part, and never allow fewer queries.
+    pub fn oods_security_bits(&self) -> usize {
+        self.oods_security_bits_with(&self.shape())
     }
 
-    // Returns an upper bound on proof size in terms of bytes in the proof.
-    // Note we expect that actual sizes are compressed by the removal of overlaps in
-    // decommitments
-    // TODO - Improve bound by removing the elements of overlap in
-    // worst cases.
+    pub(crate) fn oods_security_bits_with(&self, shape: &Shape) -> usize {
+        let degree = shape.degree * self.trace_poly_len_with(shape);
+        let degree_log = (64 - (degree as u64).leading_zeros()) as usize;
+        security::FIELD_BITS.saturating_sub(degree_log) * self.num_oods_points
+    }
+
+    /// Upper bound on the proof size in bytes.
+    ///
+    /// This assumes the worst case where the decommitments for different
+    /// queries share no Merkle tree nodes and only one value of each FRI coset
+    /// is known from the previous layer. Actual proofs are smaller, see
+    /// [`Constraints::expected_proof_size`].
     pub fn max_proof_size(&self) -> usize {
-        let trace_len_log = self.trace_nrows().trailing_zeros() as usize;
-        // First we decommit two proofs for each query [one which is the evaluation
-        // domain decommitment and one is the constraints]
-        let mut total_decommitment =
-            self.num_queries * (trace_len_log * self.trace_ncolumns() + trace_len_log);
-        // Now we account for the first layer which is 8 elements [assuming the worst
-        // case we need to decommit 7 other elements].
-        let mut current_size = trace_len_log - 3;
-        total_decommitment += self.num_queries * (current_size + 7);
-
-        for &i in &self.fri_layout {
-            // This worst case assumes that only one in each group is from the previous
-            // layer.
-            current_size -= i;
-            total_decommitment += self.num_queries * (current_size + (1 << i) - 1);
-        }
-        // Decommits all of the remaining elements
-        let final_list = 1 << current_size;
-        if final_list > self.num_queries {
-            total_decommitment += final_list - self.num_queries;
-        }
-        32 * total_decommitment
+        self.max_proof_size_with(&self.shape())
+    }
+
+    pub(crate) fn max_proof_size_with(&self, shape: &Shape) -> usize {
+        let eval_domain_log = (self.trace_nrows() * self.blowup).trailing_zeros() as usize;
+        let salt = usize::from(self.zero_knowledge);
+        let constraint_polynomials = shape.degree.next_power_of_two();
+
+        // Commitments, out-of-domain values and the proof of work nonce
+        let mut total = 2 + self.fri_layout.len() + 1;
+        total += self.num_oods_points * (shape.num_arguments + constraint_polynomials);
+
+        // Trace and constraint values, salts and authentication paths
+        total += self.num_queries * (self.trace_ncolumns() + salt + eval_domain_log);
+        total += self.num_queries * (constraint_polynomials + 2 * salt + eval_domain_log);
+
+        // The other values in each FRI coset and authentication paths
+        let mut layer_log = eval_domain_log;
+        for &reductions in &self.fri_layout {
+            layer_log = layer_log.saturating_sub(reductions);
+            total += self.num_queries * ((1 << reductions) - 1 + layer_log);
+        }
+
+        // Coefficients of the last layer
+        total += (1 << layer_log) / self.lde_blowup_with(shape);
+        32 * total
+    }
+
+    /// Size in bytes of each section of a proof with the given query indices.
+    ///
+    /// The size of a proof only depends on the queries, through the values
+    /// and Merkle authentication paths needed for the distinct indices. With
+    /// the indices of an actual proof this gives its exact size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a query index is outside the evaluation domain.
+    pub fn proof_size(&self, queries: &[usize]) -> Result<SectionSizes, MerkleError> {
+        let eval_domain_size = self.trace_nrows() * self.blowup;
+        let constraint_polynomials = self.degree().next_power_of_two();
+        let salt = usize::from(self.zero_knowledge);
+        let mut queries = queries.to_vec();
+        queries.sort_unstable();
+        let decommitment_size = |size: usize, indices: &[usize]| -> Result<_, MerkleError> {
+            let commitment = Commitment::from_size_hash(size, &Hash::default())?;
+            let hashes = commitment.proof_size(indices)?;
+            Ok((hashes, commitment.sort_indices(indices)?.len()))
+        };
+
+        // Trace and constraint values, authentication paths and salts
+        let (hashes, distinct) = decommitment_size(eval_domain_size, &queries)?;
+        let salts = salt * distinct;
+        let trace_values = queries.len() * self.trace_ncolumns();
+        let constraint_values = queries.len() * (constraint_polynomials + salt);
+
+        // FRI coset values not known from the previous layer and paths
+        let mut fri_decommitments = Vec::with_capacity(self.fri_layout.len());
+        let mut layer_size = eval_domain_size;
+        let mut previous = queries;
+        for &reductions in &self.fri_layout {
+            let coset_size = 1 << reductions;
+            layer_size >>= reductions;
+            let mut indices: Vec<usize> = previous.iter().map(|i| i / coset_size).collect();
+            indices.dedup();
+            previous.dedup();
+            let (hashes, _) = decommitment_size(layer_size, &indices)?;
+            fri_decommitments.push(32 * (indices.len() * coset_size - previous.len() + hashes));
+            previous = indices;
+        }
+
+        let reductions: usize = self.fri_layout.iter().sum();
+        let oods_values =
+            self.num_oods_points * (self.trace_arguments().len() + constraint_polynomials);
+        Ok(SectionSizes {
+            trace_commitment: 32,
+            constraint_commitment: 32,
+            oods_values: 32 * oods_values,
+            fri_roots: 32 * self.fri_layout.len(),
+            last_layer_coefficients: 32 * ((eval_domain_size >> reductions) / self.lde_blowup()),
+            // The proof of work nonce is eight bytes
+            pow_nonce: 8,
+            trace_decommitment: 32 * (trace_values + hashes + salts),
+            constraint_decommitment: 32 * (constraint_values + hashes + salts),
+            fri_decommitments,
+        })
+    }
+
+    /// Distribution of the proof size in bytes.
+    ///
+    /// Simulates the query sampling of the prover for `samples` different
+    /// transcripts and computes the exact [`Constraints::proof_size`] for
+    /// each. This does not require a trace, so it can be used to budget a
+    /// proof before running the prover. At least one sample is taken. The
+    /// simulated transcripts only depend on the constraints, so the result
+    /// is deterministic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the evaluation domain is too large for a Merkle
+    /// tree.
+    pub fn expected_proof_size(&self, samples: usize) -> Result<ProofSize, MerkleError> {
+        let eval_domain_size = self.trace_nrows() * self.blowup;
+        let sizes = (0..samples.max(1))
+            .map(|sample| {
+                let mut seed = self.transcript_seed();
+                seed.extend_from_slice(b"proof-size");
+                seed.extend_from_slice(&(sample as u64).to_be_bytes());
+                let empty: &[u8] = &[];
+                let mut channel = VerifierChannel::new(empty);
+                channel.initialize(&seed, self.hash_function);
+                let queries = get_indices(
+                    self.num_queries,
+                    eval_domain_size.trailing_zeros(),
+                    &mut channel,
+                );
+                self.proof_size(&queries).map(|sizes| sizes.total())
+            })
+            .collect::<Result<Vec<usize>, MerkleError>>()?;
+        #[allow(clippy::cast_precision_loss)]
+        let mean = sizes.iter().sum::<usize>() as f64 / sizes.len() as f64;
+        Ok(ProofSize {
+            min: *sizes.iter().min().unwrap(),
+            mean,
+            max: *sizes.iter().max().unwrap(),
+        })
+    }
+
+    /// The FRI layout with the smallest expected proof size.
+    ///
+    /// Minimizes the expected size of the FRI roots, the coset values not
+    /// known from the previous layer, the authentication paths (which share
+    /// nodes between queries) and the last layer coefficients, for the current
+    /// number of queries, trace size and blowup. The final layer size follows
+    /// from the number of reductions. Apply it with
+    ///
+    /// ```ignore
+    /// constraints.fri_layout = constraints.optimize_fri_layout();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn optimize_fri_layout(&self) -> Vec<usize> {
+        let eval_domain_log = (self.trace_nrows() * self.blowup).trailing_zeros() as usize;
+        let max_reductions = self.trace_poly_len().trailing_zeros() as usize;
+
+        // Cheapest layout for each total number of reductions. The layer costs
+        // only depend on the layer size and the number of reductions, so this
+        // is a shortest path problem.
+        let mut best: Vec<Option<(f64, Vec<usize>)>> = vec![None; max_reductions + 1];
+        best[0] = Some((0.0, Vec::new()));
+        for done in 0..max_reductions {
+            let (cost, layout) = match &best[done] {
+                Some(entry) => entry.clone(),
+                None => continue,
+            };
+            for reductions in 1..=4.min(max_reductions - done) {
+                let cost = cost + self.expected_fri_layer_size(eval_domain_log - done, reductions);
+                let next = &mut best[done + reductions];
+                let better = match next {
+                    Some((best_cost, _)) => cost < *best_cost,
+                    None => true,
+                };
+                if better {
+                    let mut layout = layout.clone();
+                    layout.push(reductions);
+                    *next = Some((cost, layout));
+                }
+            }
+        }
+        best.into_iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(done, entry)| {
+                entry.map(|(cost, layout)| {
+                    (cost + self.last_layer_size(eval_domain_log - done), layout)
+                })
+            })
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).expect("cost is not a number"))
+            .map(|(_, layout)| layout)
+            .expect("no FRI layout")
+    }
+
+    /// Expected size in bytes of the FRI part of a proof with `fri_layout`.
+    #[cfg(test)]
+    pub(crate) fn expected_fri_size(&self, fri_layout: &[usize]) -> f64 {
+        let mut layer_log = (self.trace_nrows() * self.blowup).trailing_zeros() as usize;
+        let mut total = 0.0;
+        for &reductions in fri_layout {
+            total += self.expected_fri_layer_size(layer_log, reductions);
+            layer_log -= reductions;
+        }
+        total + self.last_layer_size(layer_log)
+    }
+
+    /// Expected size in bytes of the root and decommitment of a FRI layer
+    /// with `2^layer_log` values, folded `reductions` times.
+    #[cfg(feature = "std")]
+    #[allow(clippy::cast_precision_loss)]
+    fn expected_fri_layer_size(&self, layer_log: usize, reductions: usize) -> f64 {
+        let queries = self.num_queries as f64;
+        let known = expected_distinct((1_u64 << layer_log) as f64, queries);
+        let cosets = expected_distinct((1_u64 << (layer_log - reductions)) as f64, queries);
+        let values = cosets * (1_u64 << reductions) as f64 - known;
+        let hashes = expected_decommitment_size(layer_log - reductions, queries);
+        32.0 * (1.0 + values + hashes)
+    }
+
+    /// Size in bytes of the coefficients of the last FRI layer.
+    #[cfg(feature = "std")]
+    #[allow(clippy::cast_precision_loss)]
+    fn last_layer_size(&self, layer_log: usize) -> f64 {
+        32.0 * ((1_usize << layer_log) / self.lde_blowup()) as f64
     }
 
     pub fn combine(&self, constraint_coefficients: &[FieldElement]) -> RationalExpression {
         use RationalExpression::*;
         assert_eq!(2 * self.len(), constraint_coefficients.len());
-        let target_degree = self.degree() * self.trace_nrows() - 1;
+        let target_degree = self.degree() * self.trace_poly_len() - 1;
 
         self.expressions
             .iter()
             .zip(constraint_coefficients.iter().tuples())
             .map(
                 |(constraint, (coefficient_low, coefficient_high))| -> RationalExpression {
-                    let (num, den) = constraint.degree(self.trace_nrows() - 1);
+                    let (num, den) = constraint.degree(self.trace_poly_len() - 1);
                     let adjustment_degree = target_degree + den - num;
                     let adjustment = Constant(coefficient_low.clone())
                         + Constant(coefficient_high.clone()) * X.pow(adjustment_degree);
@@ -211,9 +797,36 @@ impl Constraints {
     }
 }
 
+/// Expected number of distinct values in `samples` uniform draws from
+/// `size` values.
+#[cfg(feature = "std")]
+fn expected_distinct(size: f64, samples: f64) -> f64 {
+    size * (1.0 - (1.0 - 1.0 / size).powf(samples))
+}
+
+/// Expected number of hashes in a Merkle decommitment of `queries` uniformly
+/// random leaves of a tree of depth `depth`.
+///
+/// A node's sibling is in the decommitment when the queries touch exactly one
+/// of the two subtrees. This is the expectation of
+/// `Commitment::proof_size` in the Merkle tree crate.
+#[cfg(feature = "std")]
+fn expected_decommitment_size(depth: usize, queries: f64) -> f64 {
+    (1..=depth)
+        .map(|level| {
+            #[allow(clippy::cast_precision_loss)]
+            let nodes = (1_u64 << level) as f64;
+            nodes * ((1.0 - 1.0 / nodes).powf(queries) - (1.0 - 2.0 / nodes).powf(queries))
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{prove, traits::tests::Recurrance, Provable, Verifiable};
+    use super::*;
+    use crate::{
+        prove, traits::tests::Recurrance, verify, Decommitment, ProofSections, Provable, Verifiable,
+    };
     use zkp_macros_decl::field_element;
     use zkp_primefield::FieldElement;
     use zkp_u256::U256;
@@ -237,4 +850,263 @@ mod tests {
         let actual = prove(&constraints, &public.trace(&private)).unwrap();
         assert!(actual.as_bytes().len() <= constraints.max_proof_size());
     }
+
+    #[test]
+    fn security_bits_oods_points() {
+        let recurrance = Recurrance {
+            index:         4000,
+            initial_value: field_element!("0f00dbabe0cafebabe"),
+            exponent:      1,
+        };
+        let mut constraints = recurrance.claim().constraints();
+        constraints.num_queries = 200;
+
+        // Many queries leave the commit and out-of-domain phases as the limit
+        let oods_bits = constraints.oods_security_bits();
+        let field_bits = crate::security::FIELD_BITS - 16;
+        assert_eq!(constraints.security_bits(), oods_bits.min(field_bits));
+
+        constraints.num_oods_points = 3;
+        assert_eq!(constraints.oods_security_bits(), 3 * oods_bits);
+        assert_eq!(constraints.security_bits(), field_bits);
+    }
+
+    fn fri_compositions(reductions: usize) -> Vec<Vec<usize>> {
+        if reductions == 0 {
+            return vec![vec![]];
+        }
+        (1..=4.min(reductions))
+            .flat_map(|first| {
+                fri_compositions(reductions - first)
+                    .into_iter()
+                    .map(move |mut rest| {
+                        rest.insert(0, first);
+                        rest
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn optimize_fri_layout_is_minimal() {
+        let recurrance = Recurrance {
+            index:         200,
+            initial_value: field_element!("0f00dbabe0cafebabe"),
+            exponent:      1,
+        };
+        let mut constraints = recurrance.claim().constraints();
+        for &num_queries in &[1, 20, 45] {
+            constraints.num_queries = num_queries;
+            let optimal = constraints.optimize_fri_layout();
+            let optimal_size = constraints.expected_fri_size(&optimal);
+            for reductions in 1..=8 {
+                for layout in fri_compositions(reductions) {
+                    assert!(optimal_size <= constraints.expected_fri_size(&layout) + 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn optimize_fri_layout_proofs() {
+        let recurrance = Recurrance {
+            index:         4000,
+            initial_value: field_element!("0f00dbabe0cafebabe"),
+            exponent:      1,
+        };
+        let private = recurrance.witness();
+        let public = recurrance.claim();
+        let trace = public.trace(&private);
+        let mut constraints = public.constraints();
+        constraints.num_queries = 30;
+
+        let fri_size = |constraints: &Constraints| {
+            let proof = prove(constraints, &trace).unwrap();
+            verify(constraints, &proof).unwrap();
+            let sections = ProofSections::from_proof(constraints, &proof).unwrap();
+            let values = sections
+                .fri_decommitments
+                .iter()
+                .map(|decommitment| {
+                    decommitment.values.iter().map(Vec::len).sum::<usize>()
+                        + decommitment.hashes.len()
+                })
+                .sum::<usize>();
+            let size = 32 * (sections.fri_roots.len() + sections.last_layer_coefficients.len());
+            (proof.as_bytes().len(), size + 32 * values)
+        };
+
+        let default_layout = constraints.fri_layout.clone();
+        let optimal = constraints.optimize_fri_layout();
+        assert_ne!(optimal, default_layout);
+        for layout in &[default_layout, vec![2, 2, 2], optimal.clone()] {
+            constraints.fri_layout = layout.clone();
+            let (_, actual) = fri_size(&constraints);
+            #[allow(clippy::cast_precision_loss)]
+            let error = (actual as f64 / constraints.expected_fri_size(layout) - 1.0).abs();
+            assert!(error < 0.05);
+        }
+
+        // Only the layout differs between the two proofs
+        constraints.fri_layout = public.constraints().fri_layout;
+        let (default_size, _) = fri_size(&constraints);
+        constraints.fri_layout = optimal;
+        let (optimal_size, _) = fri_size(&constraints);
+        assert!(optimal_size < default_size);
+    }
+
+    #[test]
+    fn proof_size_exact() {
+        let recurrance = Recurrance {
+            index:         1000,
+            initial_value: field_element!("0f00dbabe0cafebabe"),
+            exponent:      1,
+        };
+        let private = recurrance.witness();
+        let public = recurrance.claim();
+        let trace = public.trace(&private);
+
+        let mut configurations = vec![public.constraints(); 4];
+        configurations[1].fri_layout = vec![2, 1, 4, 2];
+        configurations[1].num_queries = 80;
+        configurations[2].zero_knowledge = true;
+        configurations[2].blowup = 32;
+        configurations[3].num_oods_points = 2;
+        for constraints in &configurations {
+            let proof = prove(constraints, &trace).unwrap();
+            let sections = ProofSections::from_proof(constraints, &proof).unwrap();
+            let actual = proof.as_bytes().len();
+            let sizes = constraints
+                .proof_size(&sections.trace_decommitment.indices)
+                .unwrap();
+            assert_eq!(sizes, section_sizes(&sections));
+            assert_eq!(sizes.total(), actual);
+
+            // The simulated transcripts are fixed
+            let expected = constraints.expected_proof_size(100).unwrap();
+            assert_eq!(constraints.expected_proof_size(100).unwrap(), expected);
+            assert!(expected.max <= constraints.max_proof_size());
+            #[allow(clippy::cast_precision_loss)]
+            let error = (actual as f64 / expected.mean - 1.0).abs();
+            assert!(error < 0.05);
+        }
+
+        let constraints = &configurations[0];
+        let eval_domain_size = constraints.trace_nrows() * constraints.blowup;
+        assert_eq!(
+            constraints.proof_size(&[0, eval_domain_size]),
+            Err(MerkleError::IndexOutOfRange)
+        );
+    }
+
+    fn section_sizes(sections: &ProofSections) -> SectionSizes {
+        let decommitment = |decommitment: &Decommitment| {
+            let values: usize = decommitment.values.iter().map(Vec::len).sum();
+            32 * (values + decommitment.hashes.len() + decommitment.salts.len())
+        };
+        SectionSizes {
+            trace_commitment:        32,
+            constraint_commitment:   32,
+            oods_values:             32 * sections.oods_values.len(),
+            fri_roots:               32 * sections.fri_roots.len(),
+            last_layer_coefficients: 32 * sections.last_layer_coefficients.len(),
+            pow_nonce:               8,
+            trace_decommitment:      decommitment(&sections.trace_decommitment),
+            constraint_decommitment: decommitment(&sections.constraint_decommitment),
+            fri_decommitments:       sections
+                .fri_decommitments
+                .iter()
+                .map(decommitment)
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn transcript_seed_binds_parameters() {
+        let recurrance = Recurrance {
+            index:         1000,
+            initial_value: field_element!("0f00dbabe0cafebabe"),
+            exponent:      1,
+        };
+        let base = recurrance.claim().constraints();
+        let mut legacy = base.clone();
+        legacy.transcript_version = TranscriptVersion::Legacy;
+        assert_eq!(legacy.transcript_seed(), base.channel_seed());
+
+        let mutations: Vec<&dyn Fn(&mut Constraints)> = vec![
+            &|_| (),
+            &|c| c.blowup = 32,
+            &|c| c.pow_bits = 1,
+            &|c| c.num_queries += 1,
+            &|c| c.fri_layout = vec![3, 2],
+            &|c| c.coset_offset = FieldElement::from(5),
+            &|c| c.zero_knowledge = true,
+            &|c| c.num_oods_points = 2,
+            &|c| c.hash_function = HashFunction::Sha256,
+            &|c| {
+                let mut expressions = c.expressions().to_vec();
+                expressions.push(expressions[0].clone());
+                *c = Constraints::from_expressions(
+                    (c.trace_nrows(), c.trace_ncolumns()),
+                    c.channel_seed().to_vec(),
+                    expressions,
+                )
+                .unwrap();
+            },
+        ];
+        let seeds: Vec<Vec<u8>> = mutations
+            .iter()
+            .map(|mutate| {
+                let mut constraints = base.clone();
+                mutate(&mut constraints);
+                constraints.transcript_seed()
+            })
+            .collect();
+        for (i, a) in seeds.iter().enumerate() {
+            assert!(a.starts_with(&[&[0, 0, 0, 0, 0, 0, 0, 12], Constraints::PROTOCOL_ID].concat()));
+            for b in &seeds[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn transcript_version_prevents_replay() {
+        let recurrance = Recurrance {
+            index:         1000,
+            initial_value: field_element!("0f00dbabe0cafebabe"),
+            exponent:      1,
+        };
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        for &version in &[TranscriptVersion::Legacy, TranscriptVersion::V1] {
+            let mut constraints = claim.constraints();
+            constraints.transcript_version = version;
+            constraints.pow_bits = 8;
+            let proof = prove(&constraints, &trace).unwrap();
+            verify(&constraints, &proof).unwrap();
+
+            // The legacy transcript does not bind the proof of work difficulty
+            constraints.pow_bits = 0;
+            let result = verify(&constraints, &proof);
+            assert_eq!(result.is_ok(), version == TranscriptVersion::Legacy);
+        }
+    }
+
+    #[test]
+    fn periodic_column_period_divides_trace() {
+        use RationalExpression::*;
+        let periodic = |period| {
+            Constraints::from_expressions((8, 1), Vec::new(), vec![
+                Periodic {
+                    values: vec![1.into(), 2.into()],
+                    period,
+                } * Trace(0, 0),
+            ])
+        };
+        assert!(periodic(8).is_ok());
+        assert!(periodic(4).is_ok());
+        assert_eq!(periodic(16).unwrap_err(), Error::InvalidPeriodicColumn);
+        assert_eq!(periodic(6).unwrap_err(), Error::InvalidPeriodicColumn);
+    }
 }
@@ -0,0 +1,348 @@
+use crate::{
+    constraints::Constraints,
+    proof_sections::{Decommitment, Error as ProofFormatError, ProofSections, Section},
+    verifier::{verify, Error as VerifierError},
+    Proof,
+};
+use std::{fmt, ops::Range, prelude::v1::*};
+use zkp_hash::HashFunction;
+
+/// The three phases of the proof transcript.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    /// Commitments, out of domain values and the FRI last layer.
+    Commitment,
+    ProofOfWork,
+    /// Opened values and Merkle paths for the queries.
+    Decommitment,
+}
+
+/// Section-by-section breakdown of a proof.
+///
+/// Created by [`inspect`]. The [`fmt::Display`] implementation prints a human
+/// readable report including the byte range of every section, the queried
+/// indices, the FRI layer sizes and the result of verification.
+#[derive(Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ProofReport {
+    pub proof_size:      usize,
+    pub trace_nrows:     usize,
+    pub trace_ncolumns:  usize,
+    pub blowup:          usize,
+    pub fri_layout:      Vec<usize>,
+    pub hash_function:   HashFunction,
+    pub sections:        ProofSections,
+    /// Byte range of each section in the proof, in transcript order.
+    pub layout:          Vec<(Section, Range<usize>)>,
+    /// Size of the evaluation domain of each committed FRI layer.
+    pub fri_layer_sizes: Vec<usize>,
+    pub verification:    Result<(), VerifierError>,
+}
+
+/// Split `proof` into sections and run the verifier on it.
+///
+/// Fails only if the proof can not be split into sections, i.e. when it is
+/// truncated or too long for `constraints`. A proof that has the right shape
+/// but does not verify results in a report with the verifier error.
+pub fn inspect(constraints: &Constraints, proof: &Proof) -> Result<ProofReport, ProofFormatError> {
+    let sections = ProofSections::from_proof(constraints, proof)?;
+
+    let mut layout = Vec::new();
+    let mut offset = 0;
+    let mut push = |section, size| {
+        layout.push((section, offset..offset + size));
+        offset += size;
+    };
+    push(Section::TraceCommitment, 32);
+    push(Section::ConstraintCommitment, 32);
+    push(Section::OodsValues, 32 * sections.oods_values.len());
+    push(Section::FriRoots, 32 * sections.fri_roots.len());
+    push(
+        Section::LastLayerCoefficients,
+        32 * sections.last_layer_coefficients.len(),
+    );
+    push(Section::PowNonce, 8);
+    push(
+        Section::TraceDecommitment,
+        decommitment_size(&sections.trace_decommitment),
+    );
+    push(
+        Section::ConstraintDecommitment,
+        decommitment_size(&sections.constraint_decommitment),
+    );
+    for (layer, decommitment) in sections.fri_decommitments.iter().enumerate() {
+        push(
+            Section::FriDecommitment(layer),
+            decommitment_size(decommitment),
+        );
+    }
+
+    let mut fri_size = constraints.trace_nrows() * constraints.blowup;
+    let fri_layer_sizes = constraints
+        .fri_layout
+        .iter()
+        .map(|reduction| {
+            fri_size >>= reduction;
+            fri_size
+        })
+        .collect();
+
+    Ok(ProofReport {
+        proof_size: proof.as_bytes().len(),
+        trace_nrows: constraints.trace_nrows(),
+        trace_ncolumns: constraints.trace_ncolumns(),
+        blowup: constraints.blowup,
+        fri_layout: constraints.fri_layout.clone(),
+        hash_function: constraints.hash_function,
+        sections,
+        layout,
+        fri_layer_sizes,
+        verification: verify(constraints, proof),
+    })
+}
+
+fn decommitment_size(decommitment: &Decommitment) -> usize {
+    32 * (decommitment.values.iter().map(Vec::len).sum::<usize>()
+        + decommitment.hashes.len()
+        + decommitment.salts.len())
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Phase::*;
+        // Use `pad` so that width and alignment flags are respected.
+        match *self {
+            Commitment => f.pad("commitment"),
+            ProofOfWork => f.pad("proof of work"),
+            Decommitment => f.pad("decommitment"),
+        }
+    }
+}
+
+impl Section {
+    fn phase(self) -> Phase {
+        use Section::*;
+        match self {
+            Header
+            | TraceCommitment
+            | ConstraintCommitment
+            | OodsValues
+            | FriRoots
+            | LastLayerCoefficients => Phase::Commitment,
+            PowNonce => Phase::ProofOfWork,
+            TraceDecommitment | ConstraintDecommitment | FriDecommitment(_) => Phase::Decommitment,
+        }
+    }
+}
+
+impl ProofReport {
+    /// The sorted query indices into the evaluation domain.
+    pub fn queries(&self) -> &[usize] {
+        &self.sections.trace_decommitment.indices
+    }
+
+    pub fn section_range(&self, section: Section) -> Option<Range<usize>> {
+        self.layout
+            .iter()
+            .find(|(s, _)| *s == section)
+            .map(|(_, range)| range.clone())
+    }
+
+    /// Number of proof bytes used by a phase.
+    pub fn phase_size(&self, phase: Phase) -> usize {
+        self.layout
+            .iter()
+            .filter(|(section, _)| section.phase() == phase)
+            .map(|(_, range)| range.len())
+            .sum()
+    }
+
+    /// The section containing the data that failed verification, if any.
+    pub fn failed_section(&self) -> Option<Section> {
+        use VerifierError::*;
+        match &self.verification {
+            Ok(()) => None,
+            Err(InvalidPoW) => Some(Section::PowNonce),
+            Err(InvalidLDECommitment { .. }) => Some(Section::TraceDecommitment),
+            Err(InvalidConstraintCommitment { .. }) => Some(Section::ConstraintDecommitment),
+            Err(OodsMismatch) => Some(Section::OodsValues),
+            Err(OodsCalculationFailure { .. }) | Err(FriCalculationFailure) => {
+                Some(Section::LastLayerCoefficients)
+            }
+            Err(InvalidFriCommitment { layer, .. }) | Err(HashMapFailure { layer, .. }) => {
+                Some(Section::FriDecommitment(*layer))
+            }
+            Err(RootUnavailable(_))
+            | Err(InvalidCosetOffset)
+            | Err(NoOodsPoints)
+            | Err(ProofTooLong)
+            | Err(ProofTruncated { .. })
+            | Err(Io { .. })
+            | Err(Merkle(_)) => None,
+        }
+    }
+}
+
+impl fmt::Display for ProofReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sections = &self.sections;
+        writeln!(
+            f,
+            "Proof of {} bytes for a {} x {} trace, blowup {}, FRI layout {:?}, {}",
+            self.proof_size,
+            self.trace_nrows,
+            self.trace_ncolumns,
+            self.blowup,
+            self.fri_layout,
+            self.hash_function
+        )?;
+        for (section, range) in &self.layout {
+            write!(
+                f,
+                "  {:>8}..{:<8} {:>8} bytes  {}",
+                range.start,
+                range.end,
+                range.len(),
+                section
+            )?;
+            match *section {
+                Section::TraceCommitment => write!(f, ": {}", sections.trace_commitment)?,
+                Section::ConstraintCommitment => write!(f, ": {}", sections.constraint_commitment)?,
+                Section::OodsValues => write!(f, " ({} values)", sections.oods_values.len())?,
+                Section::FriRoots => write!(f, " ({} layers)", sections.fri_roots.len())?,
+                Section::LastLayerCoefficients => {
+                    write!(
+                        f,
+                        " ({} coefficients)",
+                        sections.last_layer_coefficients.len()
+                    )?
+                }
+                Section::PowNonce => write!(f, ": {}", sections.pow_nonce)?,
+                Section::TraceDecommitment => write_decommitment(f, &sections.trace_decommitment)?,
+                Section::ConstraintDecommitment => {
+                    write_decommitment(f, &sections.constraint_decommitment)?
+                }
+                Section::FriDecommitment(layer) => {
+                    write_decommitment(f, &sections.fri_decommitments[layer])?
+                }
+                Section::Header => {}
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "FRI layers:")?;
+        for (layer, (size, root)) in self
+            .fri_layer_sizes
+            .iter()
+            .zip(&sections.fri_roots)
+            .enumerate()
+        {
+            writeln!(f, "  {:>2}: domain size {:>8}, root {}", layer, size, root)?;
+        }
+        writeln!(f, "Queries: {:?}", self.queries())?;
+        writeln!(f, "Bytes per phase:")?;
+        for phase in &[Phase::Commitment, Phase::ProofOfWork, Phase::Decommitment] {
+            writeln!(f, "  {:<14} {:>8}", phase, self.phase_size(*phase))?;
+        }
+        match (&self.verification, self.failed_section()) {
+            (Ok(()), _) => writeln!(f, "Verification: ok"),
+            (Err(error), Some(section)) => {
+                writeln!(f, "Verification: failed in {}: {}", section, error)
+            }
+            (Err(error), None) => writeln!(f, "Verification: failed: {}", error),
+        }
+    }
+}
+
+fn write_decommitment(f: &mut fmt::Formatter<'_>, decommitment: &Decommitment) -> fmt::Result {
+    write!(
+        f,
+        " ({} rows, {} values, {} hashes)",
+        decommitment.indices.len(),
+        decommitment.values.iter().map(Vec::len).sum::<usize>(),
+        decommitment.hashes.len()
+    )?;
+    if !decommitment.salts.is_empty() {
+        write!(f, " ({} salts)", decommitment.salts.len())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{channel::ReadError, traits::tests::example_proof, verifier::verify_stream};
+
+    #[test]
+    fn inspect_valid_proof() {
+        let (constraints, proof) = example_proof();
+        let report = inspect(&constraints, &proof).unwrap();
+        assert_eq!(report.verification, Ok(()));
+        assert_eq!(report.failed_section(), None);
+        assert_eq!(report.layout.first().unwrap().1.start, 0);
+        assert_eq!(report.layout.last().unwrap().1.end, proof.as_bytes().len());
+        assert!(report.layout.windows(2).all(|w| w[0].1.end == w[1].1.start));
+        assert_eq!(
+            report.phase_size(Phase::Commitment)
+                + report.phase_size(Phase::ProofOfWork)
+                + report.phase_size(Phase::Decommitment),
+            proof.as_bytes().len()
+        );
+        assert_eq!(report.queries().len(), constraints.num_queries);
+        assert_eq!(
+            *report.fri_layer_sizes.last().unwrap(),
+            report.sections.last_layer_coefficients.len() * constraints.lde_blowup()
+        );
+    }
+
+    #[test]
+    fn inspect_tampered_proof() {
+        let (constraints, proof) = example_proof();
+        let report = inspect(&constraints, &proof).unwrap();
+        let range = report.section_range(Section::TraceDecommitment).unwrap();
+        let mut bytes = proof.as_bytes().to_vec();
+        bytes[range.end - 1] ^= 1;
+        let report = inspect(&constraints, &Proof::from_bytes(bytes)).unwrap();
+        match report.verification {
+            Err(VerifierError::InvalidLDECommitment { ref expected, .. }) => {
+                assert_eq!(*expected, report.sections.trace_commitment)
+            }
+            _ => panic!("Expected an LDE commitment failure"),
+        }
+        assert_eq!(report.failed_section(), Some(Section::TraceDecommitment));
+        assert!(report
+            .to_string()
+            .contains("Verification: failed in trace decommitment"));
+    }
+
+    /// Fails after the first `len` bytes of the proof.
+    struct Failing<'a>(&'a [u8]);
+
+    impl crate::ProofSource for Failing<'_> {
+        fn read_proof(&mut self, buffer: &mut [u8]) -> Result<usize, ReadError> {
+            if self.0.is_empty() {
+                return Err(ReadError::Other);
+            }
+            let n = buffer.len().min(self.0.len());
+            buffer[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn inspect_failing_source() {
+        let (constraints, proof) = example_proof();
+        let mut report = inspect(&constraints, &proof).unwrap();
+        let bytes = &proof.as_bytes()[..100];
+        report.verification = verify_stream(&constraints, Failing(bytes));
+        assert!(matches!(
+            report.verification,
+            Err(VerifierError::Io {
+                error: ReadError::Other,
+                ..
+            })
+        ));
+        assert_eq!(report.failed_section(), None);
+        assert!(report.to_string().contains("Verification: failed: "));
+    }
+}
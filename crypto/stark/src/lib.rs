@@ -39,12 +39,19 @@
 // rand_xoshiro v0.4.0 is required for a zkp-stark example and v0.3.1 for criterion
 #![allow(clippy::multiple_crate_versions)]
 
+#[cfg(feature = "std")]
+mod air;
+mod boundary;
 mod channel;
 mod constraints;
+mod inspector;
 mod polynomial;
 mod proof;
 mod proof_of_work;
+mod proof_sections;
 mod rational_expression;
+mod security;
+mod simplify;
 mod traits;
 mod verifier;
 
@@ -57,6 +64,10 @@ mod component;
 #[cfg(feature = "prover")]
 mod constraint_check;
 #[cfg(feature = "prover")]
+mod poseidon;
+#[cfg(feature = "prover")]
+mod progress;
+#[cfg(feature = "prover")]
 mod prover;
 #[cfg(feature = "prover")]
 mod trace_table;
@@ -69,15 +80,35 @@ mod trace_table;
 extern crate no_std_compat as std;
 
 // Re-exports dependencies that are part of the public interface
+pub use zkp_hash::HashFunction;
 pub use zkp_primefield as primefield;
 
 // Exports for verifier
-pub use constraints::{Constraints, Error as ConstraintError};
+#[cfg(feature = "std")]
+pub use air::{parse as parse_air, print as print_air, Error as AirError};
+pub use boundary::BoundaryConstraints;
+pub use channel::{
+    ProofSource, ProverChannel, PublicCoin, RandomGenerator, ReadError, Replayable,
+    VerifierChannel, Writable,
+};
+pub use constraints::{
+    Constraints, Error as ConstraintError, ProofSize, SectionSizes, TranscriptVersion,
+};
+pub use inspector::{inspect, Phase as ProofPhase, ProofReport};
 pub use polynomial::DensePolynomial;
 pub use proof::Proof;
+pub use proof_sections::{
+    Decommitment, Error as ProofFormatError, ProofSections, Section as ProofSection,
+};
 pub use rational_expression::RationalExpression;
+pub use security::conjectured_security;
+#[cfg(feature = "std")]
+pub use security::{
+    proven_security, search_parameters, Bound as SecurityBound, Objective as SearchObjective,
+    Parameters as ProofParameters,
+};
 pub use traits::Verifiable;
-pub use verifier::{verify, Error as VerifierError};
+pub use verifier::{verify, verify_stream, Error as VerifierError};
 
 // Exports for prover
 #[cfg(feature = "prover")]
@@ -88,9 +119,13 @@ pub use component::{
 #[cfg(feature = "prover")]
 pub use constraint_check::check_constraints;
 #[cfg(feature = "prover")]
-pub use prover::{prove, Error as ProverError};
+pub use poseidon::{poseidon_compress, poseidon_permutation};
+#[cfg(feature = "prover")]
+pub use progress::{CancellationToken, Observer as ProverObserver, Stage as ProverStage};
+#[cfg(feature = "prover")]
+pub use prover::{prove, prove_batch, prove_checked, prove_with_observer, Error as ProverError};
 #[cfg(feature = "prover")]
-pub use trace_table::TraceTable;
+pub use trace_table::{TraceBuilder, TraceTable};
 #[cfg(feature = "prover")]
 pub use traits::Provable;
 
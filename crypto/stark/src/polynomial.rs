@@ -74,10 +74,14 @@ impl DensePolynomial {
         result
     }
 
+    /// Evaluate on the coset `coset_offset * <ω>` where `ω` is a root of
+    /// unity of order `self.len() * blowup`. The result is in permuted order.
     #[cfg(feature = "std")]
-    pub fn low_degree_extension(&self, blowup: usize) -> MmapVec<FieldElement> {
-        // TODO: shift polynomial by FieldElement::GENERATOR outside of this function.
-        const SHIFT_FACTOR: FieldElement = FieldElement::GENERATOR;
+    pub fn low_degree_extension(
+        &self,
+        blowup: usize,
+        coset_offset: &FieldElement,
+    ) -> MmapVec<FieldElement> {
         let length = self.len() * blowup;
         let generator =
             FieldElement::root(length).expect("No generator for extended_domain_length.");
@@ -92,12 +96,21 @@ impl DensePolynomial {
             .par_chunks_mut(self.len())
             .enumerate()
             .for_each(|(i, slice)| {
-                let cofactor = &SHIFT_FACTOR * generator.pow(permute_index(blowup, i));
+                let cofactor = coset_offset * generator.pow(permute_index(blowup, i));
                 fft_cofactor_permuted_out(&cofactor, &self.coefficients(), slice);
             });
         result
     }
 
+    /// Add the scaled polynomial to target.
+    ///
+    /// target += c * P(X)
+    pub fn add_scaled_into(&self, c: &FieldElement, target: &mut Self) {
+        for (coefficient, target) in self.0.iter().zip(target.0.iter_mut()) {
+            *target += c * coefficient;
+        }
+    }
+
     /// Divide out a point and add the scaled result to target.
     ///
     /// target += c * (P(X) - P(z)) / (X - z)
@@ -0,0 +1,225 @@
+use crate::{Component, RationalExpression, TraceTable};
+use std::{
+    collections::{BTreeMap, HashMap},
+    prelude::v1::*,
+};
+use zkp_hash::Poseidon;
+use zkp_primefield::FieldElement;
+
+/// Component proving one application of the [`Poseidon`] permutation.
+///
+/// Row `i` holds the state before round `i` in the first three columns and the
+/// first two elements after the S-box in the last two. The result of the last
+/// round is in row `Poseidon::ROUNDS`, the remaining rows are unconstrained.
+/// The round constants are periodic columns.
+///
+/// Labels `input0` to `input2` and `output0` to `output2` give the state before
+/// and after the permutation.
+pub fn poseidon_permutation(input: &[FieldElement; 3]) -> Component {
+    use RationalExpression::*;
+
+    let rounds = Poseidon::ROUNDS;
+    let size = (rounds + 1).next_power_of_two();
+    let constants = Poseidon::round_constants();
+
+    // Compute trace table
+    let mut trace = TraceTable::new(size, 5);
+    let mut state = input.clone();
+    for (round, constants) in constants.iter().enumerate() {
+        for (j, constant) in constants.iter().enumerate() {
+            trace[(round, j)] = state[j].clone();
+            state[j] += constant;
+        }
+        for (j, element) in state.iter_mut().enumerate() {
+            if j == 2 || Poseidon::is_full_round(round) {
+                *element = element.square() * &*element;
+            }
+        }
+        trace[(round, 3)] = state[0].clone();
+        trace[(round, 4)] = state[1].clone();
+        state = Poseidon::mix(&state);
+    }
+    for (j, value) in state.iter().enumerate() {
+        trace[(rounds, j)] = value.clone();
+    }
+
+    // Periodic columns
+    let mut constants_table = TraceTable::new(size, 3);
+    for (round, constants) in constants.iter().enumerate() {
+        for (j, constant) in constants.iter().enumerate() {
+            constants_table[(round, j)] = constant.clone();
+        }
+    }
+    let periodic = constants_table
+        .interpolate()
+        .into_iter()
+        .map(|p| Polynomial(p, Box::new(X)))
+        .collect::<Vec<_>>();
+
+    // Repeating patterns
+    let omega = FieldElement::root(size).unwrap();
+    let on_rows = |a: RationalExpression, rows: &mut dyn Iterator<Item = usize>| {
+        a / rows
+            .map(|i| X - Constant(omega.pow(i)))
+            .fold(Constant(FieldElement::ONE), |product, factor| {
+                product * factor
+            })
+    };
+    let full_rounds = || (0..rounds).filter(|&round| Poseidon::is_full_round(round));
+    let partial_rounds = || (0..rounds).filter(|&round| !Poseidon::is_full_round(round));
+
+    // Common sub-expressions
+    let added = |j: usize| Trace(j, 0) + periodic[j].clone();
+    let sbox = |j: usize| added(j).pow(3);
+    let unit = |k: usize| {
+        let mut unit = [FieldElement::ZERO, FieldElement::ZERO, FieldElement::ZERO];
+        unit[k] = FieldElement::ONE;
+        Poseidon::mix(&unit)
+    };
+    let matrix = [unit(0), unit(1), unit(2)];
+    let mixed = |j: usize| {
+        Constant(matrix[0][j].clone()) * Trace(3, 0)
+            + Constant(matrix[1][j].clone()) * Trace(4, 0)
+            + Constant(matrix[2][j].clone()) * sbox(2)
+    };
+
+    let mut constraints = Vec::new();
+    let mut constraint_names = BTreeMap::new();
+    for j in 0..2 {
+        let _ = constraint_names.insert(constraints.len(), format!("sbox{}/full", j));
+        constraints.push(on_rows(Trace(j + 3, 0) - sbox(j), &mut full_rounds()));
+        let _ = constraint_names.insert(constraints.len(), format!("sbox{}/partial", j));
+        constraints.push(on_rows(Trace(j + 3, 0) - added(j), &mut partial_rounds()));
+    }
+    for j in 0..3 {
+        let _ = constraint_names.insert(constraints.len(), format!("mix{}", j));
+        constraints.push(on_rows(Trace(j, 1) - mixed(j), &mut (0..rounds)));
+    }
+
+    // Labels
+    let mut labels = HashMap::default();
+    for j in 0..3 {
+        let _ = labels.insert(format!("input{}", j), (0, Trace(j, 0)));
+        let _ = labels.insert(format!("output{}", j), (rounds, Trace(j, 0)));
+    }
+
+    let mut component = Component::new(trace, constraints, labels);
+    for j in 0..5 {
+        let name = format!("{}{}", if j < 3 { "state" } else { "sbox" }, j % 3);
+        component.set_column_name(j, &name);
+    }
+    for (index, name) in &constraint_names {
+        component.set_constraint_name(*index, name);
+    }
+    component
+}
+
+/// Component proving `hash = Poseidon::compress(left, right)`.
+///
+/// This is [`poseidon_permutation`] with the capacity element fixed to two.
+/// It has labels `left`, `right` and `hash`, like the `tree_layer` component
+/// in the `pedersen_merkle` example, but uses 128 rows instead of 256 and a
+/// degree three constraint system instead of elliptic curve additions.
+pub fn poseidon_compress(left: &FieldElement, right: &FieldElement) -> Component {
+    use RationalExpression::*;
+
+    let capacity = FieldElement::from(2);
+    let mut component = poseidon_permutation(&[left.clone(), right.clone(), capacity.clone()]);
+    component
+        .constraints
+        .push((Trace(2, 0) - Constant(capacity)) / (X - Constant(FieldElement::ONE)));
+    let mut labels = HashMap::default();
+    let _ = labels.insert("left".to_owned(), (0, Trace(0, 0)));
+    let _ = labels.insert("right".to_owned(), (0, Trace(1, 0)));
+    let _ = labels.insert("hash".to_owned(), (Poseidon::ROUNDS, Trace(0, 0)));
+    component.labels = labels;
+    component
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compose_vertical, Provable, Verifiable};
+    use zkp_hash::{Hash, HashFunction};
+    use zkp_merkle_tree::Tree;
+    use zkp_u256::U256;
+
+    fn elements(values: &[u64; 3]) -> [FieldElement; 3] {
+        [
+            FieldElement::from(values[0]),
+            FieldElement::from(values[1]),
+            FieldElement::from(values[2]),
+        ]
+    }
+
+    #[test]
+    fn test_permutation() {
+        let input = elements(&[0, 1, 2]);
+        let mut expected = input.clone();
+        Poseidon::permute(&mut expected);
+
+        let component = poseidon_permutation(&input);
+        assert!(component.check());
+        for j in 0..3 {
+            assert_eq!(component.eval_label(&format!("input{}", j)), input[j]);
+            assert_eq!(component.eval_label(&format!("output{}", j)), expected[j]);
+        }
+    }
+
+    #[test]
+    fn test_permutation_invalid() {
+        let mut component = poseidon_permutation(&elements(&[3, 4, 5]));
+        component.trace[(Poseidon::FULL_ROUNDS, 1)] += FieldElement::ONE;
+        assert!(!component.check());
+    }
+
+    #[test]
+    fn test_compress() {
+        let left = FieldElement::from(1);
+        let right = FieldElement::from(2);
+        let component = poseidon_compress(&left, &right);
+        assert!(component.check());
+        assert_eq!(component.eval_label("left"), left);
+        assert_eq!(component.eval_label("right"), right);
+        assert_eq!(
+            component.eval_label("hash"),
+            Poseidon::compress(&left, &right)
+        );
+    }
+
+    #[test]
+    fn test_compress_prove() {
+        let component = compose_vertical(
+            poseidon_compress(&FieldElement::from(1), &FieldElement::from(2)),
+            poseidon_compress(&FieldElement::from(3), &FieldElement::from(4)),
+        );
+        assert!(component.check());
+        let proof = component.prove(()).unwrap();
+        component.verify(&proof).unwrap();
+    }
+
+    #[test]
+    fn test_compress_merkle_path() {
+        let leaves = (0..4_u64).map(|i| U256::from(i + 10)).collect::<Vec<_>>();
+        let tree = Tree::from_leaves_with(leaves.clone(), None, HashFunction::Poseidon).unwrap();
+        let proof = tree.open(&[2]).unwrap();
+        proof.verify(&[(2, leaves[2].clone())]).unwrap();
+
+        // Recompute the root along the path of leaf two using the gadget
+        let node = |left: &FieldElement, right: &FieldElement| {
+            let component = poseidon_compress(left, right);
+            assert!(component.check());
+            component.eval_label("hash")
+        };
+        let leaf = |i: usize| FieldElement::from(&leaves[i]);
+        let sibling = node(&leaf(0), &leaf(1));
+        let root = node(&sibling, &node(&leaf(2), &leaf(3)));
+        assert!(proof
+            .hashes()
+            .contains(&Hash::new(U256::from(&sibling).to_bytes_be())));
+        assert_eq!(
+            tree.commitment().hash(),
+            &Hash::new(U256::from(&root).to_bytes_be())
+        );
+    }
+}
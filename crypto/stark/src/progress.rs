@@ -0,0 +1,82 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering::Relaxed},
+        Arc,
+    },
+};
+
+/// Stages of the prover, in the order they are run.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Stage {
+    /// Interpolate the trace table and evaluate it on the LDE domain.
+    TraceLde,
+    TraceCommit,
+    /// Evaluate the combined constraints on a coset and interpolate them.
+    ConstraintEvaluation,
+    /// Evaluate the constraint polynomials on the LDE domain and commit.
+    ConstraintCommit,
+    /// Out of domain sampling and the combination of the polynomials.
+    Oods,
+    FriLayers,
+    ProofOfWork,
+    /// Open the queried values in all committed trees.
+    Decommit,
+}
+
+/// Receives progress updates from the prover.
+///
+/// Callbacks can come from any thread, so implementations need to be `Sync`.
+/// All methods have empty default implementations. The unit type `()` is an
+/// observer that ignores all updates.
+pub trait Observer: Sync {
+    fn stage_start(&self, _stage: Stage) {}
+
+    fn stage_end(&self, _stage: Stage) {}
+
+    /// Called during long running stages with the amount of work done out of
+    /// `total`. Updates may arrive out of order when work runs in parallel.
+    fn progress(&self, _stage: Stage, _done: usize, _total: usize) {}
+}
+
+impl Observer for () {}
+
+/// Flag to request a running proof to stop.
+///
+/// Clones share the same flag, so a clone can be handed to the prover while
+/// another is kept to cancel it. The prover checks the flag between stages and
+/// while solving the proof of work, and then returns
+/// [`ProverError::Cancelled`](crate::ProverError::Cancelled).
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Relaxed)
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Stage::*;
+        // Use `pad` so that width and alignment flags are respected.
+        match *self {
+            TraceLde => f.pad("trace LDE"),
+            TraceCommit => f.pad("trace commitment"),
+            ConstraintEvaluation => f.pad("constraint evaluation"),
+            ConstraintCommit => f.pad("constraint commitment"),
+            Oods => f.pad("out of domain sampling"),
+            FriLayers => f.pad("FRI layers"),
+            ProofOfWork => f.pad("proof of work"),
+            Decommit => f.pad("decommitment"),
+        }
+    }
+}
@@ -1,23 +1,29 @@
 #[cfg(all(feature = "std", feature = "prover"))]
+use crate::progress::CancellationToken;
+#[cfg(all(feature = "std", feature = "prover"))]
 use log::info;
 #[cfg(all(feature = "std", feature = "prover"))]
 use rayon::prelude::*;
 use std::convert::TryFrom;
 #[cfg(all(feature = "std", feature = "prover"))]
 use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
-use tiny_keccak::Keccak;
+use zkp_hash::{HashFunction, Hasher};
 use zkp_macros_decl::hex;
 use zkp_u256::U256;
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub(crate) struct ChallengeSeed([u8; 32]);
+pub(crate) struct ChallengeSeed {
+    seed:          [u8; 32],
+    hash_function: HashFunction,
+}
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub(crate) struct Challenge {
-    seed:       [u8; 32],
-    difficulty: usize,
+    seed:          [u8; 32],
+    difficulty:    usize,
+    hash_function: HashFunction,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -27,18 +33,23 @@ pub(crate) struct Response {
 }
 
 impl ChallengeSeed {
-    pub(crate) fn from_bytes(seed: [u8; 32]) -> Self {
-        Self(seed)
+    pub(crate) fn from_bytes(seed: [u8; 32], hash_function: HashFunction) -> Self {
+        Self {
+            seed,
+            hash_function,
+        }
     }
 
     pub(crate) fn with_difficulty(self, difficulty: usize) -> Challenge {
-        let mut seed = [0_u8; 32];
-        let mut keccak = Keccak::new_keccak256();
-        keccak.update(&hex!("0123456789abcded"));
-        keccak.update(&self.0);
-        keccak.update(&[u8::try_from(difficulty).unwrap()]);
-        keccak.finalize(&mut seed);
-        Challenge { difficulty, seed }
+        let mut hasher = self.hash_function.hasher();
+        hasher.update(&hex!("0123456789abcded"));
+        hasher.update(&self.seed);
+        hasher.update(&[u8::try_from(difficulty).unwrap()]);
+        Challenge {
+            seed: hasher.digest(),
+            difficulty,
+            hash_function: self.hash_function,
+        }
     }
 }
 
@@ -46,11 +57,10 @@ impl Challenge {
     pub(crate) fn verify(&self, response: Response) -> bool {
         // TODO: return Result<()>
         // OPT: Inline Keccak256 and work directly on buffer using 'keccakf'
-        let mut keccak = Keccak::new_keccak256();
-        let mut digest = [0_u8; 32];
-        keccak.update(&self.seed);
-        keccak.update(&(response.nonce.to_be_bytes()));
-        keccak.finalize(&mut digest);
+        let mut hasher = self.hash_function.hasher();
+        hasher.update(&self.seed);
+        hasher.update(&(response.nonce.to_be_bytes()));
+        let digest = hasher.digest();
         // OPT: Check performance impact of conversion
         let work = U256::from_bytes_be(&digest).leading_zeros();
         work >= self.difficulty
@@ -75,6 +85,15 @@ impl Challenge {
 
     #[cfg(feature = "std")]
     pub(crate) fn solve(&self) -> Response {
+        self.solve_cancellable(&CancellationToken::new())
+            .expect("Proof of work was cancelled")
+    }
+
+    /// Solve the challenge, giving up when `cancel` is set.
+    #[cfg(feature = "std")]
+    pub(crate) fn solve_cancellable(&self, cancel: &CancellationToken) -> Option<Response> {
+        // Number of nonces each thread tries between checks of `cancel`.
+        const CHECK_INTERVAL: usize = 1 << 12;
         let num_threads = rayon::current_num_threads();
         info!(
             "Solving {} bit proof of work with {} threads.",
@@ -82,17 +101,23 @@ impl Challenge {
         );
         let first_nonce = AtomicU64::new(u64::max_value());
         (0..num_threads as u64).into_par_iter().for_each(|offset| {
-            for nonce in (offset..).step_by(num_threads) {
+            for (i, nonce) in (offset..).step_by(num_threads).enumerate() {
                 if self.verify(Response { nonce }) {
                     let _ = fetch_min(&first_nonce, nonce);
                 }
                 if nonce >= first_nonce.load(Relaxed) {
                     break;
                 }
+                if i % CHECK_INTERVAL == 0 && cancel.is_cancelled() {
+                    break;
+                }
             }
         });
-        Response {
-            nonce: first_nonce.into_inner(),
+        let nonce = first_nonce.into_inner();
+        if nonce == u64::max_value() {
+            None
+        } else {
+            Some(Response { nonce })
         }
     }
 }
@@ -128,12 +153,39 @@ mod tests {
 
     #[test]
     fn proof_of_work_test() {
-        let challenge = ChallengeSeed::from_bytes(hex!(
-            "0123456789abcded0123456789abcded0123456789abcded0123456789abcded"
-        ))
+        let challenge = ChallengeSeed::from_bytes(
+            hex!("0123456789abcded0123456789abcded0123456789abcded0123456789abcded"),
+            HashFunction::default(),
+        )
         .with_difficulty(8);
         let response = challenge.solve();
         assert_eq!(response.nonce, 138);
         assert!(challenge.verify(response));
     }
+
+    #[test]
+    fn proof_of_work_cancelled() {
+        let challenge = ChallengeSeed::from_bytes(
+            hex!("0123456789abcded0123456789abcded0123456789abcded0123456789abcded"),
+            HashFunction::default(),
+        )
+        .with_difficulty(64);
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        assert_eq!(challenge.solve_cancellable(&cancel), None);
+    }
+
+    #[test]
+    fn proof_of_work_hash_functions() {
+        for &hash_function in &[HashFunction::Sha256, HashFunction::Blake2s256] {
+            let challenge = ChallengeSeed::from_bytes(
+                hex!("0123456789abcded0123456789abcded0123456789abcded0123456789abcded"),
+                hash_function,
+            )
+            .with_difficulty(8);
+            let response = challenge.solve();
+            assert!(challenge.verify(response));
+            assert_ne!(response.nonce, 138);
+        }
+    }
 }
@@ -0,0 +1,967 @@
+use crate::{channel::*, constraints::Constraints, proof_of_work, verifier::get_indices, Proof};
+#[cfg(feature = "std")]
+use std::error;
+use std::{convert::TryFrom, fmt, prelude::v1::*};
+use zkp_hash::Hash;
+use zkp_merkle_tree::{Commitment, Error as MerkleError};
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+type Result<T> = std::result::Result<T, Error>;
+
+const MAGIC: [u8; 4] = *b"ZKPS";
+
+/// The sections of a proof, in the order in which they appear in both the
+/// channel transcript and the binary encoding.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Section {
+    Header,
+    TraceCommitment,
+    ConstraintCommitment,
+    OodsValues,
+    FriRoots,
+    LastLayerCoefficients,
+    PowNonce,
+    TraceDecommitment,
+    ConstraintDecommitment,
+    FriDecommitment(usize),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    UnexpectedEnd {
+        section: Section,
+        offset:  usize,
+    },
+    UnexpectedSection {
+        expected: Section,
+        found:    u8,
+        offset:   usize,
+    },
+    InvalidSectionLength {
+        section: Section,
+        offset:  usize,
+    },
+    InvalidFieldElement {
+        section: Section,
+        offset:  usize,
+    },
+    InvalidIndex {
+        section: Section,
+        offset:  usize,
+    },
+    TrailingBytes {
+        offset: usize,
+    },
+    LengthOverflow {
+        section: Section,
+    },
+    Merkle(MerkleError),
+}
+
+/// Opened values and Merkle authentication path for one committed layer.
+///
+/// For the trace and constraint decommitments there is one row of values per
+/// query, in query order (duplicates included). For FRI layers the indices are
+/// the (deduplicated) cosets that are opened and each row holds only those
+/// coset values that can not be derived from the previous layer.
+///
+/// In zero-knowledge mode the trace and constraint commitments are hiding and
+/// the decommitment also holds the salts of the opened leaves, one for each
+/// distinct index in increasing order.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Decommitment {
+    pub indices: Vec<usize>,
+    pub values:  Vec<Vec<FieldElement>>,
+    pub hashes:  Vec<Hash>,
+    pub salts:   Vec<Hash>,
+}
+
+/// A [`Proof`] split into its named sections.
+///
+/// The raw [`Proof`] can only be interpreted by replaying the channel with the
+/// matching [`Constraints`]. A `ProofSections` can be obtained that way using
+/// [`ProofSections::from_proof`] and then stored with a versioned binary
+/// encoding ([`ProofSections::to_bytes`]) that can be read back without the
+/// constraints.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ProofSections {
+    pub trace_commitment:        Hash,
+    pub constraint_commitment:   Hash,
+    pub oods_values:             Vec<FieldElement>,
+    pub fri_roots:               Vec<Hash>,
+    pub last_layer_coefficients: Vec<FieldElement>,
+    pub pow_nonce:               u64,
+    pub trace_decommitment:      Decommitment,
+    pub constraint_decommitment: Decommitment,
+    pub fri_decommitments:       Vec<Decommitment>,
+}
+
+impl Section {
+    fn tag(self) -> u8 {
+        use Section::*;
+        match self {
+            Header => 0,
+            TraceCommitment => 1,
+            ConstraintCommitment => 2,
+            OodsValues => 3,
+            FriRoots => 4,
+            LastLayerCoefficients => 5,
+            PowNonce => 6,
+            TraceDecommitment => 7,
+            ConstraintDecommitment => 8,
+            FriDecommitment(_) => 9,
+        }
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Section::*;
+        match *self {
+            Header => write!(f, "header"),
+            TraceCommitment => write!(f, "trace commitment"),
+            ConstraintCommitment => write!(f, "constraint commitment"),
+            OodsValues => write!(f, "oods values"),
+            FriRoots => write!(f, "FRI roots"),
+            LastLayerCoefficients => write!(f, "last layer coefficients"),
+            PowNonce => write!(f, "proof of work nonce"),
+            TraceDecommitment => write!(f, "trace decommitment"),
+            ConstraintDecommitment => write!(f, "constraint decommitment"),
+            FriDecommitment(layer) => write!(f, "FRI layer {} decommitment", layer),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Error::*;
+        match *self {
+            InvalidMagic => write!(f, "The input does not start with the proof magic bytes"),
+            UnsupportedVersion(version) => {
+                write!(f, "Proof format version {} is not supported", version)
+            }
+            UnexpectedEnd { section, offset } => {
+                write!(
+                    f,
+                    "Unexpected end of input in {} at byte {}",
+                    section, offset
+                )
+            }
+            UnexpectedSection {
+                expected,
+                found,
+                offset,
+            } => {
+                write!(
+                    f,
+                    "Expected {} at byte {} but found section tag {}",
+                    expected, offset, found
+                )
+            }
+            InvalidSectionLength { section, offset } => {
+                write!(f, "Invalid length of {} at byte {}", section, offset)
+            }
+            InvalidFieldElement { section, offset } => {
+                write!(
+                    f,
+                    "Non-canonical field element in {} at byte {}",
+                    section, offset
+                )
+            }
+            InvalidIndex { section, offset } => {
+                write!(f, "Index out of range in {} at byte {}", section, offset)
+            }
+            TrailingBytes { offset } => write!(f, "Unexpected trailing bytes at byte {}", offset),
+            LengthOverflow { section } => {
+                write!(f, "Length of {} does not fit the proof encoding", section)
+            }
+            // This is a wrapper, so defer to the underlying types' implementation of `fmt`.
+            Merkle(ref e) => std::fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl From<MerkleError> for Error {
+    fn from(err: MerkleError) -> Self {
+        Self::Merkle(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Self::Merkle(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl ProofSections {
+    /// Version of the binary encoding produced by [`ProofSections::to_bytes`].
+    pub const VERSION: u16 = 2;
+
+    /// Split a proof into sections by replaying the channel transcript.
+    ///
+    /// This draws the same random values as [`crate::verify`] does, but does
+    /// not check any of the commitments, the proof of work or the FRI
+    /// evaluations. It only fails if the proof does not have the shape
+    /// required by `constraints`.
+    pub fn from_proof(constraints: &Constraints, proof: &Proof) -> Result<Self> {
+        let eval_domain_size = constraints.trace_nrows() * constraints.blowup;
+        let trace_arguments = constraints.trace_arguments();
+        let constraints_trace_degree = constraints.degree().next_power_of_two();
+        let zero_knowledge = usize::from(constraints.zero_knowledge);
+
+        let mut channel = VerifierChannel::new(proof.as_bytes());
+        channel.initialize(&constraints.transcript_seed(), constraints.hash_function);
+
+        let trace_commitment = replay_hash(&mut channel, Section::TraceCommitment)?;
+        for _ in 0..constraints.len() {
+            let _: FieldElement = channel.get_random();
+            let _: FieldElement = channel.get_random();
+        }
+        let constraint_commitment = replay_hash(&mut channel, Section::ConstraintCommitment)?;
+
+        let mut oods_values = Vec::new();
+        for _ in 0..constraints.num_oods_points {
+            let _oods_point: FieldElement = channel.get_random();
+            for _ in 0..trace_arguments.len() + constraints_trace_degree {
+                oods_values.push(replay_field_element(&mut channel, Section::OodsValues)?);
+            }
+        }
+        for _ in 0..(oods_values.len() + zero_knowledge) {
+            let _: FieldElement = channel.get_random();
+        }
+
+        let mut fri_roots = Vec::with_capacity(constraints.fri_layout.len());
+        let mut fri_size = eval_domain_size;
+        for (layer, &reduction) in constraints.fri_layout.iter().enumerate() {
+            fri_size >>= reduction;
+            if layer > 0 && reduction != 0 {
+                let _: FieldElement = channel.get_random();
+            }
+            fri_roots.push(replay_hash(&mut channel, Section::FriRoots)?);
+        }
+        let _: FieldElement = channel.get_random();
+        let last_layer_coefficients = replay_field_elements(
+            &mut channel,
+            fri_size / constraints.lde_blowup(),
+            Section::LastLayerCoefficients,
+        )?;
+
+        let _: proof_of_work::ChallengeSeed = channel.get_random();
+        require_bytes(&channel, 8, Section::PowNonce)?;
+        let pow_nonce = Replayable::<proof_of_work::Response>::replay(&mut channel).nonce();
+
+        let queries = get_indices(
+            constraints.num_queries,
+            eval_domain_size.trailing_zeros(),
+            &mut channel,
+        );
+
+        let trace_decommitment = replay_decommitment(
+            &mut channel,
+            &Commitment::from_size_hash(eval_domain_size, &trace_commitment)?,
+            &queries,
+            constraints.trace_ncolumns(),
+            constraints.zero_knowledge,
+            Section::TraceDecommitment,
+        )?;
+        let constraint_decommitment = replay_decommitment(
+            &mut channel,
+            &Commitment::from_size_hash(eval_domain_size, &constraint_commitment)?,
+            &queries,
+            constraints_trace_degree + zero_knowledge,
+            constraints.zero_knowledge,
+            Section::ConstraintDecommitment,
+        )?;
+
+        let fri_decommitments = replay_fri_decommitments(
+            &mut channel,
+            constraints,
+            &fri_roots,
+            queries,
+            eval_domain_size,
+        )?;
+
+        if channel.remaining() > 0 {
+            return Err(Error::TrailingBytes {
+                offset: channel.position(),
+            });
+        }
+
+        Ok(Self {
+            trace_commitment,
+            constraint_commitment,
+            oods_values,
+            fri_roots,
+            last_layer_coefficients,
+            pow_nonce,
+            trace_decommitment,
+            constraint_decommitment,
+            fri_decommitments,
+        })
+    }
+
+    /// Reassemble the channel transcript the sections were taken from.
+    pub fn to_proof(&self) -> Proof {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.trace_commitment.as_bytes());
+        bytes.extend_from_slice(self.constraint_commitment.as_bytes());
+        write_field_elements(&mut bytes, &self.oods_values);
+        for root in &self.fri_roots {
+            bytes.extend_from_slice(root.as_bytes());
+        }
+        write_field_elements(&mut bytes, &self.last_layer_coefficients);
+        bytes.extend_from_slice(&self.pow_nonce.to_be_bytes());
+        for decommitment in std::iter::once(&self.trace_decommitment)
+            .chain(std::iter::once(&self.constraint_decommitment))
+            .chain(&self.fri_decommitments)
+        {
+            for row in &decommitment.values {
+                write_field_elements(&mut bytes, row);
+            }
+            for hash in decommitment.hashes.iter().chain(&decommitment.salts) {
+                bytes.extend_from_slice(hash.as_bytes());
+            }
+        }
+        Proof::from_bytes(bytes)
+    }
+
+    /// Versioned, self-describing binary encoding.
+    ///
+    /// The encoding starts with four magic bytes and a big-endian `u16`
+    /// version, followed by the sections in transcript order. Each section is
+    /// a one byte tag, a big-endian `u32` payload length and the payload.
+    /// Hashes and field elements take 32 bytes each, with field elements in
+    /// big-endian Montgomery form as in the transcript. A decommitment payload
+    /// is a `u32` row count, then for each row a `u64` index, a `u32` value
+    /// count and the values, then a `u32` hash count and the hashes, and
+    /// finally the salts.
+    ///
+    /// Fails with [`Error::LengthOverflow`] if a section or list is too long
+    /// for its `u32` length.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&Self::VERSION.to_be_bytes());
+
+        let mut payload = Vec::new();
+        payload.extend_from_slice(self.trace_commitment.as_bytes());
+        write_section(&mut bytes, Section::TraceCommitment, &mut payload)?;
+        payload.extend_from_slice(self.constraint_commitment.as_bytes());
+        write_section(&mut bytes, Section::ConstraintCommitment, &mut payload)?;
+        write_field_elements(&mut payload, &self.oods_values);
+        write_section(&mut bytes, Section::OodsValues, &mut payload)?;
+        for root in &self.fri_roots {
+            payload.extend_from_slice(root.as_bytes());
+        }
+        write_section(&mut bytes, Section::FriRoots, &mut payload)?;
+        write_field_elements(&mut payload, &self.last_layer_coefficients);
+        write_section(&mut bytes, Section::LastLayerCoefficients, &mut payload)?;
+        payload.extend_from_slice(&self.pow_nonce.to_be_bytes());
+        write_section(&mut bytes, Section::PowNonce, &mut payload)?;
+        write_decommitment(
+            &mut payload,
+            &self.trace_decommitment,
+            Section::TraceDecommitment,
+        )?;
+        write_section(&mut bytes, Section::TraceDecommitment, &mut payload)?;
+        write_decommitment(
+            &mut payload,
+            &self.constraint_decommitment,
+            Section::ConstraintDecommitment,
+        )?;
+        write_section(&mut bytes, Section::ConstraintDecommitment, &mut payload)?;
+        for (layer, decommitment) in self.fri_decommitments.iter().enumerate() {
+            let section = Section::FriDecommitment(layer);
+            write_decommitment(&mut payload, decommitment, section)?;
+            write_section(&mut bytes, section, &mut payload)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Parse the encoding produced by [`ProofSections::to_bytes`].
+    ///
+    /// There must be exactly one FRI decommitment per FRI root and no
+    /// trailing data.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+        if reader.take(MAGIC.len(), Section::Header)? != MAGIC {
+            return Err(Error::InvalidMagic);
+        }
+        let version = reader.read_u16(Section::Header)?;
+        if version != Self::VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+
+        let mut section = reader.section(Section::TraceCommitment)?;
+        section.expect_length(32, Section::TraceCommitment)?;
+        let trace_commitment = section.read_hash(Section::TraceCommitment)?;
+
+        let mut section = reader.section(Section::ConstraintCommitment)?;
+        section.expect_length(32, Section::ConstraintCommitment)?;
+        let constraint_commitment = section.read_hash(Section::ConstraintCommitment)?;
+
+        let oods_values = reader
+            .section(Section::OodsValues)?
+            .read_field_element_list(Section::OodsValues)?;
+        let fri_roots = reader
+            .section(Section::FriRoots)?
+            .read_hash_list(Section::FriRoots)?;
+        let last_layer_coefficients = reader
+            .section(Section::LastLayerCoefficients)?
+            .read_field_element_list(Section::LastLayerCoefficients)?;
+
+        let mut section = reader.section(Section::PowNonce)?;
+        section.expect_length(8, Section::PowNonce)?;
+        let pow_nonce = section.read_u64(Section::PowNonce)?;
+
+        let trace_decommitment = reader
+            .section(Section::TraceDecommitment)?
+            .read_decommitment(Section::TraceDecommitment)?;
+        let constraint_decommitment = reader
+            .section(Section::ConstraintDecommitment)?
+            .read_decommitment(Section::ConstraintDecommitment)?;
+        let fri_decommitments = (0..fri_roots.len())
+            .map(|layer| {
+                reader
+                    .section(Section::FriDecommitment(layer))?
+                    .read_decommitment(Section::FriDecommitment(layer))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if !reader.is_empty() {
+            return Err(Error::TrailingBytes {
+                offset: reader.offset,
+            });
+        }
+
+        Ok(Self {
+            trace_commitment,
+            constraint_commitment,
+            oods_values,
+            fri_roots,
+            last_layer_coefficients,
+            pow_nonce,
+            trace_decommitment,
+            constraint_decommitment,
+            fri_decommitments,
+        })
+    }
+}
+
+fn require_bytes(channel: &VerifierChannel<&[u8]>, len: usize, section: Section) -> Result<()> {
+    if channel.remaining() < len {
+        return Err(Error::UnexpectedEnd {
+            section,
+            offset: channel.position() + channel.remaining(),
+        });
+    }
+    Ok(())
+}
+
+fn replay_hash(channel: &mut VerifierChannel<&[u8]>, section: Section) -> Result<Hash> {
+    require_bytes(channel, 32, section)?;
+    Ok(Replayable::<Hash>::replay(channel))
+}
+
+fn replay_hashes(
+    channel: &mut VerifierChannel<&[u8]>,
+    len: usize,
+    section: Section,
+) -> Result<Vec<Hash>> {
+    require_bytes(channel, 32 * len, section)?;
+    Ok(Replayable::<Hash>::replay_many(channel, len))
+}
+
+fn replay_field_element(
+    channel: &mut VerifierChannel<&[u8]>,
+    section: Section,
+) -> Result<FieldElement> {
+    require_bytes(channel, 32, section)?;
+    let offset = channel.position();
+    let value = Replayable::<U256>::replay(channel);
+    canonical_field_element(value, section, offset)
+}
+
+// The coin must absorb these in a single write, as `verify` does.
+fn replay_field_elements(
+    channel: &mut VerifierChannel<&[u8]>,
+    len: usize,
+    section: Section,
+) -> Result<Vec<FieldElement>> {
+    require_bytes(channel, 32 * len, section)?;
+    let offset = channel.position();
+    let values = Replayable::<FieldElement>::replay_many(channel, len);
+    for (i, value) in values.iter().enumerate() {
+        let _ = canonical_field_element(value.as_montgomery().clone(), section, offset + 32 * i)?;
+    }
+    Ok(values)
+}
+
+fn replay_decommitment(
+    channel: &mut VerifierChannel<&[u8]>,
+    commitment: &Commitment,
+    queries: &[usize],
+    width: usize,
+    hiding: bool,
+    section: Section,
+) -> Result<Decommitment> {
+    let values = queries
+        .iter()
+        .map(|_| {
+            (0..width)
+                .map(|_| replay_field_element(channel, section))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let hashes = replay_hashes(channel, commitment.proof_size(queries)?, section)?;
+    let salts = if hiding {
+        replay_hashes(channel, commitment.sort_indices(queries)?.len(), section)?
+    } else {
+        Vec::new()
+    };
+    Ok(Decommitment {
+        indices: queries.to_vec(),
+        values,
+        hashes,
+        salts,
+    })
+}
+
+fn replay_fri_decommitments(
+    channel: &mut VerifierChannel<&[u8]>,
+    constraints: &Constraints,
+    fri_roots: &[Hash],
+    queries: Vec<usize>,
+    eval_domain_size: usize,
+) -> Result<Vec<Decommitment>> {
+    let mut fri_decommitments = Vec::with_capacity(fri_roots.len());
+    let mut previous_indices = queries;
+    let mut fri_size = eval_domain_size;
+    for (layer, (&reduction, root)) in constraints.fri_layout.iter().zip(fri_roots).enumerate() {
+        let section = Section::FriDecommitment(layer);
+        let coset_size = 1_usize << reduction;
+        fri_size >>= reduction;
+        let mut indices: Vec<usize> = previous_indices.iter().map(|i| i / coset_size).collect();
+        indices.dedup();
+        // Values that are computed from the previous layer are not in the proof.
+        let mut values = Vec::with_capacity(indices.len());
+        for index in &indices {
+            let mut coset = Vec::new();
+            for j in 0..coset_size {
+                if previous_indices
+                    .binary_search(&(index * coset_size + j))
+                    .is_err()
+                {
+                    coset.push(replay_field_element(channel, section)?);
+                }
+            }
+            values.push(coset);
+        }
+        let commitment = Commitment::from_size_hash(fri_size, root)?;
+        let hashes = replay_hashes(channel, commitment.proof_size(&indices)?, section)?;
+        fri_decommitments.push(Decommitment {
+            indices: indices.clone(),
+            values,
+            hashes,
+            salts: Vec::new(),
+        });
+        previous_indices = indices;
+    }
+    Ok(fri_decommitments)
+}
+
+fn canonical_field_element(value: U256, section: Section, offset: usize) -> Result<FieldElement> {
+    if value >= FieldElement::MODULUS {
+        return Err(Error::InvalidFieldElement { section, offset });
+    }
+    Ok(FieldElement::from_montgomery(value))
+}
+
+fn write_field_elements(bytes: &mut Vec<u8>, values: &[FieldElement]) {
+    for value in values {
+        bytes.extend_from_slice(&value.as_montgomery().to_bytes_be());
+    }
+}
+
+fn write_u32(bytes: &mut Vec<u8>, value: usize, section: Section) -> Result<()> {
+    let value = u32::try_from(value).map_err(|_| Error::LengthOverflow { section })?;
+    bytes.extend_from_slice(&value.to_be_bytes());
+    Ok(())
+}
+
+fn write_section(bytes: &mut Vec<u8>, section: Section, payload: &mut Vec<u8>) -> Result<()> {
+    bytes.push(section.tag());
+    write_u32(bytes, payload.len(), section)?;
+    bytes.append(payload);
+    Ok(())
+}
+
+fn write_decommitment(
+    bytes: &mut Vec<u8>,
+    decommitment: &Decommitment,
+    section: Section,
+) -> Result<()> {
+    write_u32(bytes, decommitment.indices.len(), section)?;
+    for (index, row) in decommitment.indices.iter().zip(&decommitment.values) {
+        bytes.extend_from_slice(&(*index as u64).to_be_bytes());
+        write_u32(bytes, row.len(), section)?;
+        write_field_elements(bytes, row);
+    }
+    write_u32(bytes, decommitment.hashes.len(), section)?;
+    for hash in decommitment.hashes.iter().chain(&decommitment.salts) {
+        bytes.extend_from_slice(hash.as_bytes());
+    }
+    Ok(())
+}
+
+/// Cursor over the binary encoding. Offsets are always relative to the start
+/// of the full encoding so errors point at the exact byte.
+struct Reader<'a> {
+    bytes:  &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    fn take(&mut self, len: usize, section: Section) -> Result<&'a [u8]> {
+        if self.remaining() < len {
+            return Err(Error::UnexpectedEnd {
+                section,
+                offset: self.bytes.len(),
+            });
+        }
+        let result = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(result)
+    }
+
+    fn read_u16(&mut self, section: Section) -> Result<u16> {
+        let mut holder = [0_u8; 2];
+        holder.copy_from_slice(self.take(2, section)?);
+        Ok(u16::from_be_bytes(holder))
+    }
+
+    fn read_u32(&mut self, section: Section) -> Result<u32> {
+        let mut holder = [0_u8; 4];
+        holder.copy_from_slice(self.take(4, section)?);
+        Ok(u32::from_be_bytes(holder))
+    }
+
+    fn read_u64(&mut self, section: Section) -> Result<u64> {
+        let mut holder = [0_u8; 8];
+        holder.copy_from_slice(self.take(8, section)?);
+        Ok(u64::from_be_bytes(holder))
+    }
+
+    fn read_length(&mut self, section: Section) -> Result<usize> {
+        let offset = self.offset;
+        usize::try_from(self.read_u32(section)?)
+            .map_err(|_| Error::InvalidSectionLength { section, offset })
+    }
+
+    fn read_hash(&mut self, section: Section) -> Result<Hash> {
+        let mut holder = [0_u8; 32];
+        holder.copy_from_slice(self.take(32, section)?);
+        Ok(Hash::new(holder))
+    }
+
+    fn read_field_element(&mut self, section: Section) -> Result<FieldElement> {
+        let offset = self.offset;
+        let mut holder = [0_u8; 32];
+        holder.copy_from_slice(self.take(32, section)?);
+        canonical_field_element(U256::from_bytes_be(&holder), section, offset)
+    }
+
+    /// Reads the header of the next section and returns a reader limited to
+    /// its payload.
+    fn section(&mut self, expected: Section) -> Result<Self> {
+        let offset = self.offset;
+        let found = self.take(1, expected)?[0];
+        if found != expected.tag() {
+            return Err(Error::UnexpectedSection {
+                expected,
+                found,
+                offset,
+            });
+        }
+        let length_offset = self.offset;
+        let length = self.read_length(expected)?;
+        if self.remaining() < length {
+            return Err(Error::InvalidSectionLength {
+                section: expected,
+                offset:  length_offset,
+            });
+        }
+        let start = self.offset;
+        self.offset += length;
+        Ok(Self {
+            bytes:  &self.bytes[..self.offset],
+            offset: start,
+        })
+    }
+
+    fn expect_length(&self, len: usize, section: Section) -> Result<()> {
+        if self.remaining() != len {
+            return Err(Error::InvalidSectionLength {
+                section,
+                offset: self.offset,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_multiple_of_32(&self, section: Section) -> Result<()> {
+        if self.remaining() % 32 != 0 {
+            return Err(Error::InvalidSectionLength {
+                section,
+                offset: self.offset,
+            });
+        }
+        Ok(())
+    }
+
+    fn read_hash_list(&mut self, section: Section) -> Result<Vec<Hash>> {
+        self.check_multiple_of_32(section)?;
+        let mut result = Vec::with_capacity(self.remaining() / 32);
+        while !self.is_empty() {
+            result.push(self.read_hash(section)?);
+        }
+        Ok(result)
+    }
+
+    fn read_field_element_list(&mut self, section: Section) -> Result<Vec<FieldElement>> {
+        self.check_multiple_of_32(section)?;
+        let mut result = Vec::with_capacity(self.remaining() / 32);
+        while !self.is_empty() {
+            result.push(self.read_field_element(section)?);
+        }
+        Ok(result)
+    }
+
+    fn read_decommitment(&mut self, section: Section) -> Result<Decommitment> {
+        let rows = self.read_length(section)?;
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        for _ in 0..rows {
+            let offset = self.offset;
+            let index = usize::try_from(self.read_u64(section)?)
+                .map_err(|_| Error::InvalidIndex { section, offset })?;
+            let len = self.read_length(section)?;
+            let row = (0..len)
+                .map(|_| self.read_field_element(section))
+                .collect::<Result<Vec<_>>>()?;
+            indices.push(index);
+            values.push(row);
+        }
+        let hashes = (0..self.read_length(section)?)
+            .map(|_| self.read_hash(section))
+            .collect::<Result<Vec<_>>>()?;
+        let salts = self.read_hash_list(section)?;
+        Ok(Decommitment {
+            indices,
+            values,
+            hashes,
+            salts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prove,
+        traits::tests::{example_proof, Recurrance},
+        verify, Provable, Verifiable,
+    };
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    #[allow(clippy::needless_pass_by_value)] // Cleaner than adding lifetime annotations.
+    fn proof_sections_roundtrip(r: Recurrance) -> bool {
+        let claim = r.claim();
+        let constraints = claim.constraints();
+        let proof = prove(&constraints, &claim.trace(&r.witness())).unwrap();
+        let sections = ProofSections::from_proof(&constraints, &proof).unwrap();
+        sections.to_proof() == proof
+            && ProofSections::from_bytes(&sections.to_bytes().unwrap()) == Ok(sections)
+    }
+
+    #[test]
+    fn proof_sections_shape() {
+        let (constraints, proof) = example_proof();
+        let sections = ProofSections::from_proof(&constraints, &proof).unwrap();
+        assert_eq!(sections.fri_roots.len(), constraints.fri_layout.len());
+        assert_eq!(
+            sections.fri_decommitments.len(),
+            constraints.fri_layout.len()
+        );
+        assert_eq!(
+            sections.trace_decommitment.indices.len(),
+            constraints.num_queries
+        );
+        assert!(sections
+            .trace_decommitment
+            .values
+            .iter()
+            .all(|row| row.len() == constraints.trace_ncolumns()));
+        assert!(verify(&constraints, &sections.to_proof()).is_ok());
+    }
+
+    #[test]
+    fn proof_sections_zero_knowledge() {
+        let recurrance = Recurrance {
+            exponent: 2,
+            ..Recurrance::example()
+        };
+        let claim = recurrance.claim();
+        let mut constraints = claim.constraints();
+        constraints.zero_knowledge = true;
+        let proof = prove(&constraints, &claim.trace(&recurrance.witness())).unwrap();
+        let sections = ProofSections::from_proof(&constraints, &proof).unwrap();
+        assert!(sections
+            .trace_decommitment
+            .values
+            .iter()
+            .all(|row| row.len() == constraints.trace_ncolumns()));
+        assert!(!sections.trace_decommitment.salts.is_empty());
+        assert!(!sections.constraint_decommitment.salts.is_empty());
+        assert_eq!(
+            ProofSections::from_bytes(&sections.to_bytes().unwrap()).as_ref(),
+            Ok(&sections)
+        );
+        assert_eq!(sections.to_proof(), proof);
+    }
+
+    #[test]
+    fn proof_sections_truncated_proof() {
+        let (constraints, proof) = example_proof();
+        let mut bytes = proof.as_bytes().to_vec();
+        bytes.truncate(40);
+        assert_eq!(
+            ProofSections::from_proof(&constraints, &Proof::from_bytes(bytes)),
+            Err(Error::UnexpectedEnd {
+                section: Section::ConstraintCommitment,
+                offset:  40,
+            })
+        );
+    }
+
+    #[test]
+    fn proof_sections_trailing_bytes() {
+        let (constraints, proof) = example_proof();
+        let mut bytes = proof.as_bytes().to_vec();
+        let offset = bytes.len();
+        bytes.push(0);
+        assert_eq!(
+            ProofSections::from_proof(&constraints, &Proof::from_bytes(bytes)),
+            Err(Error::TrailingBytes { offset })
+        );
+    }
+
+    #[test]
+    fn proof_sections_non_canonical_oods_value() {
+        let (constraints, proof) = example_proof();
+        let mut bytes = proof.as_bytes().to_vec();
+        for byte in &mut bytes[64..96] {
+            *byte = 0xff;
+        }
+        assert_eq!(
+            ProofSections::from_proof(&constraints, &Proof::from_bytes(bytes)),
+            Err(Error::InvalidFieldElement {
+                section: Section::OodsValues,
+                offset:  64,
+            })
+        );
+    }
+
+    #[test]
+    fn proof_sections_malformed_encoding() {
+        let (constraints, proof) = example_proof();
+        let bytes = ProofSections::from_proof(&constraints, &proof)
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+
+        let mut magic = bytes.clone();
+        magic[0] = b'X';
+        assert_eq!(ProofSections::from_bytes(&magic), Err(Error::InvalidMagic));
+
+        let mut version = bytes.clone();
+        version[5] = 1;
+        assert_eq!(
+            ProofSections::from_bytes(&version),
+            Err(Error::UnsupportedVersion(1))
+        );
+
+        let mut tag = bytes.clone();
+        tag[6] = 2;
+        assert_eq!(
+            ProofSections::from_bytes(&tag),
+            Err(Error::UnexpectedSection {
+                expected: Section::TraceCommitment,
+                found:    2,
+                offset:   6,
+            })
+        );
+
+        let mut length = bytes.clone();
+        length[10] = 31;
+        assert_eq!(
+            ProofSections::from_bytes(&length),
+            Err(Error::InvalidSectionLength {
+                section: Section::TraceCommitment,
+                offset:  11,
+            })
+        );
+
+        assert_eq!(
+            ProofSections::from_bytes(&bytes[..6]),
+            Err(Error::UnexpectedEnd {
+                section: Section::TraceCommitment,
+                offset:  6,
+            })
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            ProofSections::from_bytes(&trailing),
+            Err(Error::TrailingBytes {
+                offset: bytes.len(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn proof_sections_length_overflow() {
+        let mut bytes = Vec::new();
+        assert_eq!(
+            write_u32(&mut bytes, usize::max_value(), Section::OodsValues),
+            Err(Error::LengthOverflow {
+                section: Section::OodsValues,
+            })
+        );
+        assert!(bytes.is_empty());
+    }
+}
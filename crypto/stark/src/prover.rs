@@ -1,16 +1,30 @@
 use crate::{
-    algebraic_dag::AlgebraicGraph,
+    algebraic_dag::{AlgebraicGraph, LookupCache},
     channel::{ProverChannel, RandomGenerator, Writable},
+    check_constraints,
     constraints::Constraints,
     polynomial::DensePolynomial,
+    progress::{CancellationToken, Observer, Stage},
     proof_of_work, verify, Proof, TraceTable, VerifierError,
 };
 use itertools::Itertools;
 use log::info;
+use rand::{thread_rng, Rng};
 use rayon::prelude::*;
-use std::{fmt, prelude::v1::*, vec};
-use zkp_hash::{Hash, Hashable, MaskedKeccak};
-use zkp_merkle_tree::{Error as MerkleError, Tree, VectorCommitment};
+use std::{
+    cmp::{max, min},
+    collections::BTreeMap,
+    fmt,
+    mem::size_of,
+    prelude::v1::*,
+    sync::{
+        atomic::{AtomicUsize, Ordering::Relaxed},
+        Arc, Mutex,
+    },
+    vec,
+};
+use zkp_hash::{Hash, HashFunction, Hashable, Hasher};
+use zkp_merkle_tree::{Commitment, Error as MerkleError, Tree, VectorCommitment};
 use zkp_mmap_vec::MmapVec;
 use zkp_primefield::{
     fft::{ifft_permuted, permute, permute_index},
@@ -21,9 +35,35 @@ use zkp_u256::U256;
 
 type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Error {
-    RootUnavailable,
+    RootUnavailable(usize),
+    /// The trace table is not of the (rows, columns) shape in `Constraints`.
+    TraceShapeMismatch {
+        expected: (usize, usize),
+        actual:   (usize, usize),
+    },
+    InvalidFriLayout,
+    /// The evaluation domain is too small for the constraint degree.
+    BlowupTooSmall,
+    /// The coset offset is zero or lies in the evaluation domain.
+    InvalidCosetOffset,
+    /// `Constraints::num_oods_points` is zero.
+    NoOodsPoints,
+    ColumnOutOfRange {
+        constraint:  usize,
+        /// The constraint's [`Constraints::describe_constraint`].
+        description: String,
+        column:      usize,
+    },
+    UnsatisfiedConstraint {
+        row:         usize,
+        constraint:  usize,
+        /// The constraint's [`Constraints::describe_constraint`].
+        description: String,
+    },
+    /// The [`CancellationToken`] was cancelled.
+    Cancelled,
     MerkleFailed(MerkleError),
     VerificationFailed(VerifierError),
 }
@@ -32,7 +72,55 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Error::*;
         match *self {
-            RootUnavailable => write!(f, "The prime field doesn't have a root of this order"),
+            RootUnavailable(order) => {
+                write!(f, "The prime field doesn't have a root of order {}", order)
+            }
+            TraceShapeMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Trace table has {} rows and {} columns, but the constraints require {} rows \
+                     and {} columns",
+                    actual.0, actual.1, expected.0, expected.1
+                )
+            }
+            InvalidFriLayout => {
+                write!(
+                    f,
+                    "FRI layout must be non-empty, fold 1 to 4 times per layer and not reduce \
+                     below the blowup"
+                )
+            }
+            BlowupTooSmall => {
+                write!(
+                    f,
+                    "Blowup is too small for the constraint degree (zero-knowledge mode needs \
+                     twice the blowup)"
+                )
+            }
+            InvalidCosetOffset => {
+                write!(
+                    f,
+                    "The coset offset must be non-zero and not in the evaluation domain"
+                )
+            }
+            NoOodsPoints => write!(f, "At least one out-of-domain point is required"),
+            ColumnOutOfRange {
+                ref description,
+                column,
+                ..
+            } => {
+                write!(
+                    f,
+                    "Constraint {} refers to trace column {} which does not exist",
+                    description, column
+                )
+            }
+            UnsatisfiedConstraint {
+                row,
+                ref description,
+                ..
+            } => write!(f, "Constraint {} is not satisfied on row {}", description, row),
+            Cancelled => write!(f, "Proof generation was cancelled"),
             MerkleFailed(ref e) => std::fmt::Display::fmt(e, f),
             VerificationFailed(ref e) => std::fmt::Display::fmt(e, f),
         }
@@ -73,13 +161,13 @@ impl VectorCommitment for PolyLDE {
         ret
     }
 
-    fn leaf_hash(&self, index: usize) -> Hash {
+    fn leaf_hash_with(&self, index: usize, hash_function: HashFunction) -> Hash {
         if self.0.len() == 1 {
             // For a single element, return its hash.
             self.0[0][index].hash()
         } else {
             // Concatenate the element hashes and hash the result.
-            let mut hasher = MaskedKeccak::new();
+            let mut hasher = hash_function.hasher();
             for value in &self.0 {
                 hasher.update(value[index].hash().as_bytes());
             }
@@ -88,6 +176,48 @@ impl VectorCommitment for PolyLDE {
     }
 }
 
+/// Tables that only depend on the shape of the proof, shared between proofs.
+#[derive(Debug, Default)]
+struct ProverCache {
+    lookups: LookupCache,
+    /// Inverses of the even elements of the evaluation domain, by domain size.
+    x_inv:   Mutex<BTreeMap<usize, Arc<MmapVec<FieldElement>>>>,
+}
+
+impl ProverCache {
+    fn x_inv(&self, n: usize) -> Result<Arc<MmapVec<FieldElement>>> {
+        if let Some(x_inv) = self.x_inv.lock().unwrap().get(&n) {
+            return Ok(x_inv.clone());
+        }
+        let root_inv = FieldElement::root(n)
+            .ok_or(Error::RootUnavailable(n))?
+            .inv()
+            .unwrap();
+        let mut x_inv = MmapVec::with_capacity(n / 2);
+        let mut accumulator = FieldElement::ONE;
+        for _ in 0..n / 2 {
+            x_inv.push(accumulator.clone());
+            accumulator *= &root_inv;
+        }
+        permute(&mut x_inv);
+        let x_inv = Arc::new(x_inv);
+        let _ = self.x_inv.lock().unwrap().insert(n, x_inv.clone());
+        Ok(x_inv)
+    }
+
+    /// Memory used by the tables, in bytes.
+    fn size(&self) -> usize {
+        let x_inv = self.x_inv.lock().unwrap();
+        let x_inv = x_inv.values().map(|x_inv| x_inv.len()).sum::<usize>();
+        self.lookups.size() + x_inv * size_of::<FieldElement>()
+    }
+
+    fn clear(&self) {
+        self.lookups.clear();
+        self.x_inv.lock().unwrap().clear();
+    }
+}
+
 #[derive(Clone, Debug)]
 struct FriLeaves {
     coset_size: usize,
@@ -117,13 +247,13 @@ impl VectorCommitment for FriLeaves {
         internal_leaf
     }
 
-    fn leaf_hash(&self, index: usize) -> Hash {
+    fn leaf_hash_with(&self, index: usize, hash_function: HashFunction) -> Hash {
         if self.coset_size == 1 {
             // For a single element, return its hash.
             self.layer[index].hash()
         } else {
             // Concatenate the element hashes and hash the result.
-            let mut hasher = MaskedKeccak::new();
+            let mut hasher = hash_function.hasher();
             for j in 0..self.coset_size {
                 hasher.update(self.layer[(index * self.coset_size + j)].hash().as_bytes());
             }
@@ -340,16 +470,122 @@ impl VectorCommitment for FriLeaves {
 ///
 /// with merkle proofs to that layer. This process is repeated for all FRI layer
 /// commitments.
+pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
+    prove_with_observer(constraints, trace, &(), &CancellationToken::new())
+}
+
+/// Like [`prove`], but reports progress to `observer` and stops with
+/// [`Error::Cancelled`] when `cancel` is set.
+pub fn prove_with_observer(
+    constraints: &Constraints,
+    trace: &TraceTable,
+    observer: &dyn Observer,
+    cancel: &CancellationToken,
+) -> Result<Proof> {
+    let progress = Progress { observer, cancel };
+    prove_with_cache(constraints, trace, &ProverCache::default(), &progress)
+}
+
+/// Prove many claims, sharing work between claims of the same shape.
+///
+/// The claims are proven in parallel, but no more at a time than fit in
+/// `memory_budget` bytes according to a rough estimate of the memory used by
+/// [`prove`]. At least one claim is proven at a time. Lookup tables for
+/// periodic sub-expressions of the constraints and the FRI folding tables are
+/// computed once and shared between the claims. The constraint graph itself
+/// depends on random coefficients drawn from each claim's channel, so it is
+/// still built per claim.
+///
+/// The shared tables count against `memory_budget`. They grow with each
+/// distinct shape of claim and are dropped between waves of claims when no
+/// further claim would fit next to them.
+///
+/// Returns the results in the order of `claims`.
+pub fn prove_batch(
+    claims: &[(Constraints, TraceTable)],
+    memory_budget: usize,
+) -> Vec<Result<Proof>> {
+    prove_batch_with_cache(claims, memory_budget, &ProverCache::default())
+}
+
+fn prove_batch_with_cache(
+    claims: &[(Constraints, TraceTable)],
+    memory_budget: usize,
+    cache: &ProverCache,
+) -> Vec<Result<Proof>> {
+    let cancel = CancellationToken::new();
+    let progress = Progress {
+        observer: &(),
+        cancel:   &cancel,
+    };
+    let claim_memory = claims
+        .iter()
+        .map(|(constraints, _)| memory_estimate(constraints))
+        .max()
+        .unwrap_or(1);
+    info!(
+        "Proving {} claims, at most {} at a time.",
+        claims.len(),
+        max(1, memory_budget / claim_memory)
+    );
+    let mut results = Vec::with_capacity(claims.len());
+    let mut remaining = claims;
+    while !remaining.is_empty() {
+        if cache.size() + claim_memory > memory_budget {
+            cache.clear();
+        }
+        let concurrency = max(1, (memory_budget - cache.size()) / claim_memory);
+        let (wave, rest) = remaining.split_at(min(concurrency, remaining.len()));
+        results.extend(
+            wave.par_iter()
+                .map(|(constraints, trace)| prove_with_cache(constraints, trace, cache, &progress))
+                .collect::<Vec<_>>(),
+        );
+        remaining = rest;
+    }
+    results
+}
+
+/// Reports stages to the observer and checks for cancellation in between.
+struct Progress<'a> {
+    observer: &'a dyn Observer,
+    cancel:   &'a CancellationToken,
+}
+
+impl Progress<'_> {
+    fn start(&self, stage: Stage) -> Result<()> {
+        if self.cancel.is_cancelled() {
+            info!("Cancelled before {}.", stage);
+            return Err(Error::Cancelled);
+        }
+        self.observer.stage_start(stage);
+        Ok(())
+    }
+
+    fn end(&self, stage: Stage) {
+        self.observer.stage_end(stage)
+    }
+}
+
+/// Rough estimate of the memory used by [`prove`], in bytes.
+fn memory_estimate(constraints: &Constraints) -> usize {
+    // The trace and constraint LDEs, the first FRI layer and a Merkle tree
+    // over each of them.
+    let columns = constraints.trace_ncolumns() + constraints.degree().next_power_of_two() + 2;
+    32 * eval_domain_size(constraints) * (columns + 3)
+}
+
 // TODO: Simplify
 #[allow(clippy::cognitive_complexity)]
 // TODO: Split up
 #[allow(clippy::too_many_lines)]
-pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
-    // TODO: Verify input
-    //  * Constraint trace length matches trace table length
-    //  * Fri layout is less than trace length * blowup
-    //  * Trace(_, _) items in constraint are valid.
-    //  * Trace table satisfies constraints (expensive check, should be optional)
+fn prove_with_cache(
+    constraints: &Constraints,
+    trace: &TraceTable,
+    cache: &ProverCache,
+    progress: &Progress<'_>,
+) -> Result<Proof> {
+    validate_input(constraints, trace)?;
 
     info!("Starting Stark proof.");
     info!("Proof constraints: {:?}", constraints);
@@ -366,13 +602,27 @@ pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
 
     info!("Initialize channel with claim.");
     let mut proof = ProverChannel::new();
-    proof.initialize(constraints.channel_seed());
+    proof.initialize(&constraints.transcript_seed(), constraints.hash_function);
+
+    // In zero-knowledge mode the committed polynomials are evaluated on a
+    // domain that is only `lde_blowup` times larger than their length.
+    let lde_blowup = constraints.lde_blowup();
+    let mut rng = thread_rng();
 
     // 1. Trace commitment.
 
     // Compute the low degree extension of the trace table.
+    progress.start(Stage::TraceLde)?;
     info!("Compute the low degree extension of the trace table.");
-    let trace_polynomials = trace.interpolate();
+    // Polynomials cached by a `TraceBuilder` are used without copying.
+    let mut trace_polynomials = trace.polynomials();
+    if constraints.zero_knowledge {
+        info!("Mask the trace polynomials.");
+        trace_polynomials = trace_polynomials
+            .iter()
+            .map(|p| mask_trace_polynomial(p, constraints.trace_poly_len(), &mut rng))
+            .collect();
+    }
     info!(
         "Trace degrees: {:?}",
         trace_polynomials
@@ -383,28 +633,33 @@ pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
     let trace_lde = PolyLDE(
         trace_polynomials
             .par_iter()
-            .map(|p| p.low_degree_extension(constraints.blowup))
+            .map(|p| p.low_degree_extension(lde_blowup, &constraints.coset_offset))
             .collect::<Vec<_>>(),
     );
+    progress.end(Stage::TraceLde);
 
     // Construct a merkle tree over the LDE trace
     // and write the root to the channel.
+    progress.start(Stage::TraceCommit)?;
     info!("Construct a merkle tree over the LDE trace and write the root to the channel.");
-    let (commitment, tree) = trace_lde.commit()?;
+    let (commitment, tree) = commit(trace_lde, constraints, &mut rng)?;
     proof.write(&commitment);
+    progress.end(Stage::TraceCommit);
 
     // 2. Constraint commitment
 
     // Read constraint coefficients from the channel.
+    progress.start(Stage::ConstraintEvaluation)?;
     info!("Read constraint coefficients from the channel.");
     let constraint_coefficients = get_coefficients(&mut proof, 2 * constraints.len());
 
     info!("Compute constraint polynomials.");
     let constraint_polynomials = get_constraint_polynomials(
-        &tree.leaves(),
-        &constraints,
+        tree.leaves(),
+        constraints,
         &constraint_coefficients,
-        trace.num_rows(),
+        &cache.lookups,
+        progress.observer,
     );
     info!(
         "Constraint degrees: {:?}",
@@ -413,60 +668,90 @@ pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
             .map(DensePolynomial::degree)
             .collect::<Vec<_>>()
     );
+    progress.end(Stage::ConstraintEvaluation);
 
     // OPT: It may be faster to compute the constraint LDE from the trace LDE,
     // instead of using an FFT.
+    progress.start(Stage::ConstraintCommit)?;
     info!("Compute the low degree extension of constraint polynomials.");
+    // In zero-knowledge mode a random polynomial is committed alongside the
+    // constraint polynomials and added to the out of domain combination.
+    let mask_polynomial = if constraints.zero_knowledge {
+        Some(random_polynomial(constraints.trace_poly_len(), &mut rng))
+    } else {
+        None
+    };
     let constraint_lde = PolyLDE(
         constraint_polynomials
+            .iter()
+            .chain(mask_polynomial.iter())
+            .collect::<Vec<_>>()
             .par_iter()
-            .map(|p| p.low_degree_extension(constraints.blowup))
+            .map(|p| p.low_degree_extension(lde_blowup, &constraints.coset_offset))
             .collect::<Vec<_>>(),
     );
     // Construct a merkle tree over the LDE combined constraints
     // and write the root to the channel.
     info!("Compute the merkle tree over the LDE constraint polynomials.");
-    let (commitment, c_tree) = constraint_lde.commit()?;
+    let (commitment, c_tree) = commit(constraint_lde, constraints, &mut rng)?;
     proof.write(&commitment);
+    progress.end(Stage::ConstraintCommit);
 
     // 3. Out of domain sampling
+    progress.start(Stage::Oods)?;
     info!("Divide out OODS point and combine polynomials.");
     let oods_polynomial = oods_combine(
         &mut proof,
         &trace_polynomials,
         &constraints.trace_arguments(),
         &constraint_polynomials,
+        mask_polynomial.as_ref(),
+        constraints.num_oods_points,
+        &FieldElement::root(constraints.trace_nrows())
+            .ok_or(Error::RootUnavailable(constraints.trace_nrows()))?,
     );
     info!("Oods poly degree: {}", oods_polynomial.degree());
+    progress.end(Stage::Oods);
 
     // 4. FRI layers with trees
+    progress.start(Stage::FriLayers)?;
     info!("LDE extension of final polynomial.");
-    let first_fri_layer = oods_polynomial.low_degree_extension(constraints.blowup);
+    let first_fri_layer =
+        oods_polynomial.low_degree_extension(lde_blowup, &constraints.coset_offset);
     info!("Fri layers.");
+    let x_inv = cache.x_inv(eval_domain_size(constraints))?;
     let fri_trees = perform_fri_layering(
         first_fri_layer,
         &mut proof,
         &constraints.fri_layout,
-        constraints.blowup,
+        lde_blowup,
+        constraints.hash_function,
+        &x_inv,
+        progress.observer,
     )?;
+    progress.end(Stage::FriLayers);
 
     // 5. Proof of work
+    progress.start(Stage::ProofOfWork)?;
     info!("Proof of work.");
     let pow_seed: proof_of_work::ChallengeSeed = proof.get_random();
     let pow_challenge = pow_seed.with_difficulty(constraints.pow_bits);
-    let pow_response = pow_challenge.solve();
+    let pow_response = pow_challenge
+        .solve_cancellable(progress.cancel)
+        .ok_or(Error::Cancelled)?;
     debug_assert!(pow_challenge.verify(pow_response));
     proof.write(pow_response);
+    progress.end(Stage::ProofOfWork);
 
     // 6. Query decommitments
     //
 
     // Fetch query indices from channel.
+    progress.start(Stage::Decommit)?;
     info!("Fetch query indices from channel.");
-    let eval_domain_size = trace.num_rows() * constraints.blowup;
     let query_indices = get_indices(
         constraints.num_queries,
-        64 - eval_domain_size.leading_zeros() - 1,
+        64 - eval_domain_size(constraints).leading_zeros() - 1,
         &mut proof,
     );
     info!("Query indices: {:?}", query_indices);
@@ -477,6 +762,10 @@ pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
         proof.write(tree.leaf(index));
     }
     proof.write(&tree.open(&query_indices)?);
+    let decommit_total = 2 + fri_trees.len();
+    progress
+        .observer
+        .progress(Stage::Decommit, 1, decommit_total);
 
     // Decommit the constraint values
     info!("Decommit the constraint values.");
@@ -484,10 +773,23 @@ pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
         proof.write(c_tree.leaf(index));
     }
     proof.write(&c_tree.open(&query_indices)?);
+    progress
+        .observer
+        .progress(Stage::Decommit, 2, decommit_total);
 
     // Decommit the FRI layer values
     info!("Decommit the FRI layer values.");
-    decommit_fri_layers_and_trees(fri_trees.as_slice(), query_indices.as_slice(), &mut proof)?;
+    decommit_fri_layers_and_trees(
+        fri_trees.as_slice(),
+        query_indices.as_slice(),
+        &mut proof,
+        &|layer| {
+            progress
+                .observer
+                .progress(Stage::Decommit, 3 + layer, decommit_total);
+        },
+    )?;
+    progress.end(Stage::Decommit);
 
     // Verify proof
     info!("Verify proof.");
@@ -497,6 +799,135 @@ pub fn prove(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
     Ok(proof)
 }
 
+/// Like [`prove`], but first checks that the trace table satisfies the
+/// constraints.
+///
+/// The check evaluates every constraint on every row, which is expensive for
+/// large traces, but it fails fast with the offending row and constraint
+/// instead of producing a proof that does not verify.
+pub fn prove_checked(constraints: &Constraints, trace: &TraceTable) -> Result<Proof> {
+    validate_input(constraints, trace)?;
+    info!("Checking constraints on trace table.");
+    check_constraints(constraints, trace).map_err(|(row, constraint)| {
+        Error::UnsatisfiedConstraint {
+            row,
+            constraint,
+            description: constraints.describe_constraint(constraint),
+        }
+    })?;
+    prove(constraints, trace)
+}
+
+/// Cheap sanity checks on the inputs so that we fail before doing any work.
+fn validate_input(constraints: &Constraints, trace: &TraceTable) -> Result<()> {
+    let expected = (constraints.trace_nrows(), constraints.trace_ncolumns());
+    let actual = (trace.num_rows(), trace.num_columns());
+    if expected != actual {
+        return Err(Error::TraceShapeMismatch { expected, actual });
+    }
+
+    // The constraint polynomials are evaluated on a coset of the evaluation
+    // domain, so it needs to be large enough to hold them.
+    if constraints.degree().next_power_of_two() * constraints.trace_poly_len()
+        > eval_domain_size(constraints)
+    {
+        return Err(Error::BlowupTooSmall);
+    }
+
+    // The final FRI layer must have at least one coefficient, i.e. we can not
+    // reduce below the blowup.
+    let reductions: usize = constraints.fri_layout.iter().sum();
+    if constraints.fri_layout.is_empty()
+        || constraints.fri_layout.iter().any(|x| !(1..=4).contains(x))
+        || reductions > constraints.trace_poly_len().trailing_zeros() as usize
+    {
+        return Err(Error::InvalidFriLayout);
+    }
+
+    // The coset must be disjoint from the evaluation domain (and hence from the
+    // trace domain), otherwise we divide by zero evaluating the constraints.
+    if constraints.coset_offset == FieldElement::ZERO
+        || constraints.coset_offset.pow(eval_domain_size(constraints)) == FieldElement::ONE
+    {
+        return Err(Error::InvalidCosetOffset);
+    }
+
+    if constraints.num_oods_points == 0 {
+        return Err(Error::NoOodsPoints);
+    }
+
+    for (constraint, expression) in constraints.expressions().iter().enumerate() {
+        if let Some(&(column, _)) = expression
+            .trace_arguments()
+            .iter()
+            .find(|(column, _)| *column >= constraints.trace_ncolumns())
+        {
+            return Err(Error::ColumnOutOfRange {
+                constraint,
+                description: constraints.describe_constraint(constraint),
+                column,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn eval_domain_size(constraints: &Constraints) -> usize {
+    constraints.trace_nrows() * constraints.blowup
+}
+
+/// Add a random multiple of the vanishing polynomial of the trace domain.
+///
+/// Computes $T(X) + (X^n - 1) \cdot R(X)$ for a random $R$ of degree
+/// $\mathtt{length} - n - 1$, which agrees with $T$ on the trace domain. As
+/// long as $R$ has more coefficients than there are revealed evaluations, they
+/// reveal nothing about $T$ elsewhere.
+fn mask_trace_polynomial<R: Rng>(
+    polynomial: &DensePolynomial,
+    length: usize,
+    rng: &mut R,
+) -> DensePolynomial {
+    let n = polynomial.len();
+    let mask: Vec<FieldElement> = (n..length).map(|_| rng.gen()).collect();
+    let mut coefficients = MmapVec::with_capacity(length);
+    for i in 0..length {
+        let coefficient = polynomial
+            .coefficients()
+            .get(i)
+            .unwrap_or(&FieldElement::ZERO)
+            - mask.get(i).unwrap_or(&FieldElement::ZERO);
+        let shifted = i.checked_sub(n).map_or(&FieldElement::ZERO, |j| &mask[j]);
+        coefficients.push(coefficient + shifted);
+    }
+    DensePolynomial::from_mmap_vec(coefficients)
+}
+
+fn random_polynomial<R: Rng>(length: usize, rng: &mut R) -> DensePolynomial {
+    let mut coefficients = MmapVec::with_capacity(length);
+    for _ in 0..length {
+        coefficients.push(rng.gen());
+    }
+    DensePolynomial::from_mmap_vec(coefficients)
+}
+
+/// Commit to the LDE, using a hiding commitment in zero-knowledge mode.
+fn commit<R: Rng>(
+    lde: PolyLDE,
+    constraints: &Constraints,
+    rng: &mut R,
+) -> Result<(Commitment, Tree<PolyLDE>)> {
+    let salts = if constraints.zero_knowledge {
+        let mut salts = MmapVec::with_capacity(lde.len());
+        for _ in 0..lde.len() {
+            salts.push(Hash::new(rng.gen()));
+        }
+        Some(salts)
+    } else {
+        None
+    };
+    Ok(lde.commit_with(salts, constraints.hash_function)?)
+}
+
 fn extract_trace_coset(trace_lde: &PolyLDE, size: usize) -> TraceTable {
     let trace_lde: &[MmapVec<FieldElement>] = &trace_lde.0;
     let lde_size = trace_lde[0].len();
@@ -532,7 +963,8 @@ fn get_constraint_polynomials(
     trace_lde: &PolyLDE,
     constraints: &Constraints,
     constraint_coefficients: &[FieldElement],
-    trace_length: usize,
+    lookups: &LookupCache,
+    observer: &dyn Observer,
 ) -> Vec<DensePolynomial> {
     // OPT: Better parallelization strategies. Probably the best would be to
     // split to domain up in smaller cosets and solve their expressions
@@ -541,6 +973,7 @@ fn get_constraint_polynomials(
     const CHUNK_SIZE: usize = 65536;
 
     // We need to evaluate on a power of two degree
+    let trace_length = constraints.trace_poly_len();
     let constraint_degree = constraints.degree();
     let eval_degree = constraint_degree.next_power_of_two();
     let coset_size = trace_length * eval_degree;
@@ -551,12 +984,12 @@ fn get_constraint_polynomials(
     info!("Combine rational expressions");
     let combined_constraints = constraints.combine(constraint_coefficients);
     let mut dag = AlgebraicGraph::new(
-        &FieldElement::GENERATOR,
+        &constraints.coset_offset,
         trace_coset.num_rows(),
-        eval_degree,
+        coset_size / constraints.trace_nrows(),
     );
     let result = dag.expression(combined_constraints);
-    dag.lookup_tables();
+    dag.lookup_tables(lookups);
     // TODO: Track and use result reference.
     let _ = dag.tree_shake(result);
     dag.init(0);
@@ -566,27 +999,28 @@ fn get_constraint_polynomials(
     let mut result: MmapVec<FieldElement> = MmapVec::with_capacity(coset_size);
     result.resize(coset_size, FieldElement::ZERO);
     let values = &mut result;
-    values
-        .par_chunks_mut(CHUNK_SIZE)
-        .enumerate()
-        .for_each(|(mut i, chunk)| {
-            i *= CHUNK_SIZE;
-            let mut dag = dag.clone();
-            dag.init(i);
-            for value in chunk {
-                *value = dag.next(&trace_coset);
-                i += 1;
-            }
-        });
+    let chunks = values.par_chunks_mut(CHUNK_SIZE);
+    let num_chunks = chunks.len();
+    let chunks_done = AtomicUsize::new(0);
+    chunks.enumerate().for_each(|(mut i, chunk)| {
+        i *= CHUNK_SIZE;
+        let mut dag = dag.clone();
+        dag.init(i);
+        for value in chunk {
+            *value = dag.next(&trace_coset);
+            i += 1;
+        }
+        let done = chunks_done.fetch_add(1, Relaxed) + 1;
+        observer.progress(Stage::ConstraintEvaluation, done, num_chunks);
+    });
 
     info!("Convert from values to coefficients");
     ifft_permuted(values);
     permute(values);
     // OPT: Merge with even-odd separation loop.
-    for (f, y) in geometric_series(&FieldElement::ONE, &FieldElement::GENERATOR.inv().unwrap())
-        .zip(values.iter_mut())
-    {
-        // Shift out the generator from the evaluation domain.
+    let offset_inv = constraints.coset_offset.inv().unwrap();
+    for (f, y) in geometric_series(&FieldElement::ONE, &offset_inv).zip(values.iter_mut()) {
+        // Shift out the coset offset from the evaluation domain.
         *y *= &f;
     }
 
@@ -611,44 +1045,54 @@ fn oods_combine(
     trace_polynomials: &[DensePolynomial],
     trace_arguments: &[(usize, isize)],
     constraint_polynomials: &[DensePolynomial],
+    mask_polynomial: Option<&DensePolynomial>,
+    num_oods_points: usize,
+    g: &FieldElement,
 ) -> DensePolynomial {
-    // Fetch the oods sampling point
     let trace_length = trace_polynomials[0].len();
-    let oods_point: FieldElement = proof.get_random();
-    let g = FieldElement::root(trace_length).expect("No root for trace polynomial length.");
 
-    // Write point evaluations to proof
+    // Fetch the oods sampling points and write point evaluations to proof
     // OPT: Parallelization
-    for (column, offset) in trace_arguments {
-        proof.write(&trace_polynomials[*column].evaluate(&(&oods_point * &g.pow(*offset))));
-    }
-
-    let oods_point_pow = oods_point.pow(constraint_polynomials.len());
-    for constraint_polynomial in constraint_polynomials {
-        proof.write(&constraint_polynomial.evaluate(&oods_point_pow));
+    let mut oods_points: Vec<FieldElement> = Vec::with_capacity(num_oods_points);
+    for _ in 0..num_oods_points {
+        let oods_point: FieldElement = proof.get_random();
+        for (column, offset) in trace_arguments {
+            proof.write(&trace_polynomials[*column].evaluate(&(&oods_point * &g.pow(*offset))));
+        }
+        let oods_point_pow = oods_point.pow(constraint_polynomials.len());
+        for constraint_polynomial in constraint_polynomials {
+            proof.write(&constraint_polynomial.evaluate(&oods_point_pow));
+        }
+        oods_points.push(oods_point);
     }
 
     // Divide out points and linear sum the polynomials
     // OPT: Parallelization
-    let trace_coefficients = get_coefficients(proof, trace_arguments.len());
-    let constraint_coefficients = get_coefficients(proof, constraint_polynomials.len());
-
     let mut combined_polynomial = DensePolynomial::zeros(trace_length);
-    for ((column, offset), coefficient) in trace_arguments.iter().zip(&trace_coefficients) {
-        trace_polynomials[*column].divide_out_point_into(
-            &(&oods_point * &g.pow(*offset)),
-            coefficient,
-            &mut combined_polynomial,
-        );
+    for oods_point in &oods_points {
+        let trace_coefficients = get_coefficients(proof, trace_arguments.len());
+        let constraint_coefficients = get_coefficients(proof, constraint_polynomials.len());
+        for ((column, offset), coefficient) in trace_arguments.iter().zip(&trace_coefficients) {
+            trace_polynomials[*column].divide_out_point_into(
+                &(oods_point * &g.pow(*offset)),
+                coefficient,
+                &mut combined_polynomial,
+            );
+        }
+        let oods_point_pow = oods_point.pow(constraint_polynomials.len());
+        for (constraint_polynomial, coefficient) in
+            constraint_polynomials.iter().zip(&constraint_coefficients)
+        {
+            constraint_polynomial.divide_out_point_into(
+                &oods_point_pow,
+                coefficient,
+                &mut combined_polynomial,
+            );
+        }
     }
-    for (constraint_polynomial, coefficient) in
-        constraint_polynomials.iter().zip(&constraint_coefficients)
-    {
-        constraint_polynomial.divide_out_point_into(
-            &oods_point_pow,
-            coefficient,
-            &mut combined_polynomial,
-        );
+    let mask_coefficient: Option<FieldElement> = mask_polynomial.map(|_| proof.get_random());
+    if let (Some(mask), Some(coefficient)) = (mask_polynomial, &mask_coefficient) {
+        mask.add_scaled_into(coefficient, &mut combined_polynomial);
     }
     combined_polynomial
 }
@@ -663,29 +1107,17 @@ fn perform_fri_layering(
     proof: &mut ProverChannel,
     fri_layout: &[usize],
     blowup: usize,
+    hash_function: HashFunction,
+    // 1/x for the first layer, only the even coordinates.
+    // OPT: Can these be efficiently computed on the fly?
+    x_inv: &MmapVec<FieldElement>,
+    observer: &dyn Observer,
 ) -> Result<Vec<FriTree>> {
     let mut fri_trees: Vec<FriTree> = Vec::with_capacity(fri_layout.len());
-
-    // Compute 1/x for the fri layer. We only compute the even coordinates.
-    // OPT: Can these be efficiently computed on the fly?
-    let x_inv = {
-        let n = first_layer.len();
-        let root_inv = FieldElement::root(n)
-            .ok_or(Error::RootUnavailable)?
-            .inv()
-            .unwrap();
-        let mut x_inv = MmapVec::with_capacity(n / 2);
-        let mut accumulator = FieldElement::ONE;
-        for _ in 0..n / 2 {
-            x_inv.push(accumulator.clone());
-            accumulator *= &root_inv;
-        }
-        permute(&mut x_inv);
-        x_inv
-    };
+    debug_assert_eq!(x_inv.len(), first_layer.len() / 2);
 
     let mut next_layer = first_layer;
-    for &n_reductions in fri_layout {
+    for (layer_index, &n_reductions) in fri_layout.iter().enumerate() {
         // Allocate next and swap ownership
         let mut layer = MmapVec::with_capacity(next_layer.len() / (1 << n_reductions));
         std::mem::swap(&mut layer, &mut next_layer);
@@ -694,7 +1126,7 @@ fn perform_fri_layering(
         // FRI layout values are small.
         #[allow(clippy::cast_possible_truncation)]
         let coset_size = 2_usize.pow(n_reductions as u32);
-        let tree = FriTree::from_leaves(FriLeaves { coset_size, layer })?;
+        let tree = FriTree::from_leaves_with(FriLeaves { coset_size, layer }, None, hash_function)?;
         fri_trees.push(tree);
         let tree = fri_trees.last().unwrap();
         let layer = &tree.leaves().layer;
@@ -769,6 +1201,7 @@ fn perform_fri_layering(
             }
             _ => unimplemented!(),
         };
+        observer.progress(Stage::FriLayers, layer_index + 1, fri_layout.len());
     }
 
     // Write the final layer coefficients
@@ -786,10 +1219,11 @@ fn decommit_fri_layers_and_trees(
     fri_trees: &[FriTree],
     query_indices: &[usize],
     proof: &mut ProverChannel,
+    layer_done: &dyn Fn(usize),
 ) -> Result<()> {
     let mut previous_indices: Vec<usize> = query_indices.to_vec();
 
-    for tree in fri_trees {
+    for (layer, tree) in fri_trees.iter().enumerate() {
         let coset_size = tree.leaves().coset_size;
 
         let new_indices: Vec<usize> = previous_indices
@@ -810,6 +1244,7 @@ fn decommit_fri_layers_and_trees(
         }
         proof.write(&tree.open(&new_indices)?);
         previous_indices = new_indices;
+        layer_done(layer);
     }
     Ok(())
 }
@@ -817,12 +1252,371 @@ fn decommit_fri_layers_and_trees(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{traits::tests::Recurrance, verify, Provable, Verifiable};
+    use crate::{
+        traits::tests::Recurrance, verify, ProofSections, Provable, TraceBuilder,
+        TranscriptVersion, Verifiable,
+    };
     use tiny_keccak::sha3_256;
     use zkp_macros_decl::{field_element, hex, u256h};
     use zkp_primefield::{fft::permute_index, geometric_series::geometric_series};
     use zkp_u256::U256;
 
+    #[test]
+    fn prove_rejects_trace_shape() {
+        let recurrance = Recurrance::example();
+        let constraints = recurrance.claim().constraints();
+        let trace = TraceTable::new(64, 2);
+        assert_eq!(
+            prove(&constraints, &trace),
+            Err(Error::TraceShapeMismatch {
+                expected: (32, 2),
+                actual:   (64, 2),
+            })
+        );
+    }
+
+    #[test]
+    fn prove_rejects_fri_layout() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+        for layout in &[vec![], vec![3, 0], vec![5], vec![3, 3]] {
+            constraints.fri_layout = layout.clone();
+            assert_eq!(prove(&constraints, &trace), Err(Error::InvalidFriLayout));
+        }
+        constraints.fri_layout = vec![4, 1];
+        assert!(prove(&constraints, &trace).is_ok());
+    }
+
+    #[test]
+    fn prove_rejects_coset_offset() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+        let root = FieldElement::root(32 * constraints.blowup).unwrap();
+        for offset in &[FieldElement::ZERO, FieldElement::ONE, root] {
+            constraints.coset_offset = offset.clone();
+            assert_eq!(prove(&constraints, &trace), Err(Error::InvalidCosetOffset));
+        }
+    }
+
+    #[test]
+    fn prove_built_trace() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let constraints = claim.constraints();
+        let trace = claim.trace(&recurrance.witness());
+        let mut builder = TraceBuilder::new(trace.num_rows(), trace.num_columns());
+        builder.extend_rows((0..trace.num_rows()).map(|i| &trace[i]));
+        let proof = prove(&constraints, &builder.build()).unwrap();
+        assert_eq!(proof, prove(&constraints, &trace).unwrap());
+    }
+
+    #[test]
+    fn prove_batch_matches_prove() {
+        let claims = (0..5_u64)
+            .map(|i| {
+                let recurrance = Recurrance {
+                    initial_value: (723 + i).into(),
+                    ..Recurrance::example()
+                };
+                let claim = recurrance.claim();
+                (claim.constraints(), claim.trace(&recurrance.witness()))
+            })
+            .collect::<Vec<_>>();
+        let mut invalid = claims[1].clone();
+        invalid.0.fri_layout = vec![];
+        let mut claims = claims;
+        claims.push(invalid);
+        for &budget in &[0, 1 << 40] {
+            let proofs = prove_batch(&claims, budget);
+            assert_eq!(proofs.len(), claims.len());
+            for ((constraints, trace), proof) in claims.iter().zip(proofs) {
+                assert_eq!(proof, prove(constraints, trace));
+            }
+        }
+    }
+
+    #[test]
+    fn prove_batch_mixed_shapes() {
+        let claims = [25, 100, 26, 500, 101]
+            .iter()
+            .map(|&index| {
+                let recurrance = Recurrance {
+                    index,
+                    ..Recurrance::example()
+                };
+                let claim = recurrance.claim();
+                (claim.constraints(), claim.trace(&recurrance.witness()))
+            })
+            .collect::<Vec<_>>();
+        let largest = claims
+            .iter()
+            .map(|(constraints, _)| memory_estimate(constraints))
+            .max()
+            .unwrap();
+        for &budget in &[0, largest, 1 << 40] {
+            let cache = ProverCache::default();
+            let proofs = prove_batch_with_cache(&claims, budget, &cache);
+            for ((constraints, trace), proof) in claims.iter().zip(proofs) {
+                assert_eq!(proof, prove(constraints, trace));
+            }
+            // Tables of earlier shapes are only kept when they fit the budget
+            let shapes = cache.x_inv.lock().unwrap().len();
+            assert_eq!(shapes, if budget == 1 << 40 { 3 } else { 1 });
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        stages: Mutex<Vec<(bool, Stage)>>,
+        cancel: Option<(Stage, CancellationToken)>,
+    }
+
+    impl Observer for Recorder {
+        fn stage_start(&self, stage: Stage) {
+            self.stages.lock().unwrap().push((true, stage));
+            if let Some((cancel_stage, token)) = &self.cancel {
+                if *cancel_stage == stage {
+                    token.cancel();
+                }
+            }
+        }
+
+        fn stage_end(&self, stage: Stage) {
+            self.stages.lock().unwrap().push((false, stage));
+        }
+
+        fn progress(&self, stage: Stage, done: usize, total: usize) {
+            assert!(self.stages.lock().unwrap().last() == Some(&(true, stage)));
+            assert!(done <= total);
+        }
+    }
+
+    #[test]
+    fn prove_with_observer_stages() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let constraints = claim.constraints();
+        let trace = claim.trace(&recurrance.witness());
+        let observer = Recorder::default();
+        let proof = prove_with_observer(&constraints, &trace, &observer, &CancellationToken::new());
+        assert_eq!(proof, prove(&constraints, &trace));
+        let expected = [
+            Stage::TraceLde,
+            Stage::TraceCommit,
+            Stage::ConstraintEvaluation,
+            Stage::ConstraintCommit,
+            Stage::Oods,
+            Stage::FriLayers,
+            Stage::ProofOfWork,
+            Stage::Decommit,
+        ]
+        .iter()
+        .flat_map(|&stage| vec![(true, stage), (false, stage)])
+        .collect::<Vec<_>>();
+        assert_eq!(*observer.stages.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn prove_with_observer_cancelled() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let constraints = claim.constraints();
+        let trace = claim.trace(&recurrance.witness());
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let observer = Recorder::default();
+        assert_eq!(
+            prove_with_observer(&constraints, &trace, &observer, &cancel),
+            Err(Error::Cancelled)
+        );
+        assert!(observer.stages.lock().unwrap().is_empty());
+
+        let cancel = CancellationToken::new();
+        let observer = Recorder {
+            cancel: Some((Stage::Oods, cancel.clone())),
+            ..Recorder::default()
+        };
+        assert_eq!(
+            prove_with_observer(&constraints, &trace, &observer, &cancel),
+            Err(Error::Cancelled)
+        );
+        assert_eq!(
+            observer.stages.lock().unwrap().last(),
+            Some(&(false, Stage::Oods))
+        );
+    }
+
+    #[test]
+    fn prove_rejects_small_blowup() {
+        let recurrance = Recurrance {
+            exponent: 16,
+            ..Recurrance::example()
+        };
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+        constraints.blowup = 16;
+        constraints.zero_knowledge = true;
+        assert_eq!(prove(&constraints, &trace), Err(Error::BlowupTooSmall));
+        constraints.zero_knowledge = false;
+        assert!(prove(&constraints, &trace).is_ok());
+    }
+
+    #[test]
+    fn prove_zero_knowledge_mask_size() {
+        // A small trace opened at many points needs more randomness than it
+        // has rows.
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+        constraints.zero_knowledge = true;
+        constraints.num_oods_points = 2;
+        assert_eq!(
+            constraints.num_trace_openings(),
+            2 * 2 + constraints.num_queries
+        );
+        assert!(constraints.num_trace_openings() > trace.num_rows());
+
+        let polynomials = trace.interpolate();
+        let polynomial = &polynomials[0];
+        let masked =
+            mask_trace_polynomial(polynomial, constraints.trace_poly_len(), &mut thread_rng());
+        assert!(masked.len() - polynomial.len() >= constraints.num_trace_openings());
+        let omega = FieldElement::root(trace.num_rows()).unwrap();
+        for row in 0..trace.num_rows() {
+            let x = omega.pow(row);
+            assert_eq!(masked.evaluate(&x), polynomial.evaluate(&x));
+        }
+
+        let proof = prove(&constraints, &trace).unwrap();
+        assert_eq!(verify(&constraints, &proof), Ok(()));
+    }
+
+    #[test]
+    fn prove_zero_knowledge() {
+        for exponent in 1..=3 {
+            let recurrance = Recurrance {
+                exponent,
+                ..Recurrance::example()
+            };
+            let claim = recurrance.claim();
+            let trace = claim.trace(&recurrance.witness());
+            let mut constraints = claim.constraints();
+            constraints.zero_knowledge = true;
+            let proof = prove(&constraints, &trace).unwrap();
+            assert_eq!(verify(&constraints, &proof), Ok(()));
+
+            // Proofs are randomized and bound to the mode.
+            let other = prove(&constraints, &trace).unwrap();
+            assert_ne!(proof.as_bytes(), other.as_bytes());
+            constraints.zero_knowledge = false;
+            assert!(verify(&constraints, &proof).is_err());
+        }
+    }
+
+    #[test]
+    fn prove_hash_functions() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+        let keccak = prove(&constraints, &trace).unwrap();
+        for &hash_function in &[HashFunction::Sha256, HashFunction::Blake2s256] {
+            constraints.hash_function = hash_function;
+            let proof = prove(&constraints, &trace).unwrap();
+            assert_eq!(verify(&constraints, &proof), Ok(()));
+            assert_ne!(proof.as_bytes(), keccak.as_bytes());
+            constraints.hash_function = HashFunction::Keccak256;
+            assert!(verify(&constraints, &proof).is_err());
+        }
+    }
+
+    #[test]
+    fn prove_multiple_oods_points() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+        let single = prove(&constraints, &trace).unwrap();
+        let single_sections = ProofSections::from_proof(&constraints, &single).unwrap();
+
+        constraints.num_oods_points = 3;
+        let proof = prove(&constraints, &trace).unwrap();
+        assert_eq!(verify(&constraints, &proof), Ok(()));
+        let sections = ProofSections::from_proof(&constraints, &proof).unwrap();
+        assert_eq!(
+            sections.oods_values.len(),
+            3 * single_sections.oods_values.len()
+        );
+        assert!(proof.as_bytes().len() <= constraints.max_proof_size());
+
+        // The number of points is bound into the transcript
+        constraints.num_oods_points = 1;
+        assert!(verify(&constraints, &proof).is_err());
+
+        constraints.num_oods_points = 0;
+        assert_eq!(prove(&constraints, &trace), Err(Error::NoOodsPoints));
+        assert_eq!(
+            verify(&constraints, &single),
+            Err(VerifierError::NoOodsPoints)
+        );
+    }
+
+    #[test]
+    fn prove_rejects_column_out_of_range() {
+        use crate::RationalExpression::*;
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let constraints = claim.constraints();
+        let mut expressions = constraints.expressions().to_vec();
+        expressions.push(Trace(2, 0) * Trace(0, 1));
+        let constraints = Constraints::from_expressions(
+            (constraints.trace_nrows(), constraints.trace_ncolumns()),
+            constraints.channel_seed().to_vec(),
+            expressions,
+        )
+        .unwrap();
+        assert_eq!(
+            prove(&constraints, &claim.trace(&recurrance.witness())),
+            Err(Error::ColumnOutOfRange {
+                constraint:  4,
+                description: "4".to_owned(),
+                column:      2,
+            })
+        );
+    }
+
+    #[test]
+    fn prove_checked_rejects_unsatisfied_constraint() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let mut constraints = claim.constraints();
+        let mut trace = claim.trace(&recurrance.witness());
+        assert!(prove_checked(&constraints, &trace).is_ok());
+        trace[(5, 1)] += FieldElement::ONE;
+        assert_eq!(
+            prove_checked(&constraints, &trace),
+            Err(Error::UnsatisfiedConstraint {
+                row:         4,
+                constraint:  1,
+                description: "1".to_owned(),
+            })
+        );
+
+        constraints.set_constraint_name(1, "fibonacci/sum");
+        let error = prove_checked(&constraints, &trace).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Constraint `fibonacci/sum` is not satisfied on row 4"
+        );
+    }
+
     #[test]
     fn starkware_fibonacci() {
         // All the constants for this tests are copied from files in
@@ -835,11 +1629,12 @@ mod tests {
         };
         let witness = recurrance.witness();
         let claim = recurrance.claim();
-        let mut constraints = claim.constraints();
+        let mut constraints = claim.legacy_constraints();
         constraints.blowup = 16;
         constraints.pow_bits = 0;
         constraints.num_queries = 20;
         constraints.fri_layout = vec![3, 2];
+        constraints.transcript_version = TranscriptVersion::Legacy;
 
         let trace = claim.trace(&witness);
         let actual = prove(&constraints, &trace).unwrap();
@@ -874,12 +1669,13 @@ mod tests {
         let witness = recurrance.witness();
         let claim = recurrance.claim();
 
-        let mut constraints = claim.constraints();
+        let mut constraints = claim.legacy_constraints();
         let trace = claim.trace(&witness);
         constraints.blowup = 16;
         constraints.pow_bits = 12;
         constraints.num_queries = 20;
         constraints.fri_layout = vec![3, 2];
+        constraints.transcript_version = TranscriptVersion::Legacy;
         let proof = prove(&constraints, &trace).unwrap();
         assert_eq!(
             sha3_256(proof.as_bytes()),
@@ -925,7 +1721,7 @@ mod tests {
 
         let claim = recurrance.claim();
         let witness = recurrance.witness();
-        let mut constraints = claim.constraints();
+        let mut constraints = claim.legacy_constraints();
         constraints.blowup = 16;
         constraints.pow_bits = 12;
         constraints.num_queries = 20;
@@ -953,7 +1749,7 @@ mod tests {
 
         let LDEn = PolyLDE(
             TPn.par_iter()
-                .map(|p| p.low_degree_extension(constraints.blowup))
+                .map(|p| p.low_degree_extension(constraints.blowup, &constraints.coset_offset))
                 .collect::<Vec<_>>(),
         );
 
@@ -985,7 +1781,7 @@ mod tests {
         );
 
         let mut proof = ProverChannel::new();
-        proof.initialize(&claim.seed());
+        proof.initialize(&claim.legacy_seed(), HashFunction::default());
         // Checks that the channel is inited properly
         assert_eq!(
             proof.coin.digest,
@@ -998,7 +1794,7 @@ mod tests {
             hex!("b7d80385fa0c8879473cdf987ea7970bb807aec78bb91af39a1504d965ad8e92")
         );
 
-        let mut constraints = claim.constraints();
+        let mut constraints = claim.legacy_constraints();
         constraints.blowup = 16;
         constraints.pow_bits = 12;
         constraints.num_queries = 20;
@@ -1012,17 +1808,18 @@ mod tests {
         }
 
         let constraint_polynomials = get_constraint_polynomials(
-            &tree.leaves(),
+            tree.leaves(),
             &constraints,
             &constraint_coefficients,
-            trace.num_rows(),
+            &LookupCache::default(),
+            &(),
         );
         assert_eq!(constraint_polynomials.len(), 1);
         assert_eq!(constraint_polynomials[0].len(), 1024);
         let CC = PolyLDE(
             constraint_polynomials
                 .par_iter()
-                .map(|p| p.low_degree_extension(constraints.blowup))
+                .map(|p| p.low_degree_extension(constraints.blowup, &constraints.coset_offset))
                 .collect::<Vec<_>>(),
         );
         // Checks that our constraints are properly calculated on the domain
@@ -1041,7 +1838,15 @@ mod tests {
         proof.write(&commitment);
 
         let trace_arguments = constraints.trace_arguments();
-        let CO = oods_combine(&mut proof, &TPn, &trace_arguments, &constraint_polynomials);
+        let CO = oods_combine(
+            &mut proof,
+            &TPn,
+            &trace_arguments,
+            &constraint_polynomials,
+            None,
+            1,
+            &FieldElement::root(trace_len).unwrap(),
+        );
         // Checks that our get out of domain function call has written the right values
         // to the proof
         assert_eq!(
@@ -1057,10 +1862,13 @@ mod tests {
         );
 
         let fri_trees = perform_fri_layering(
-            CO.low_degree_extension(constraints.blowup),
+            CO.low_degree_extension(constraints.blowup, &constraints.coset_offset),
             &mut proof,
             &constraints.fri_layout,
             constraints.blowup,
+            constraints.hash_function,
+            &ProverCache::default().x_inv(eval_domain_size).unwrap(),
+            &(),
         )
         .unwrap();
 
@@ -1121,8 +1929,13 @@ mod tests {
             "f2d3e6593dc23fa32655040ad5023739e15fff1d645bb809467cfccb676d6343"
         );
 
-        decommit_fri_layers_and_trees(fri_trees.as_slice(), query_indices.as_slice(), &mut proof)
-            .unwrap();
+        decommit_fri_layers_and_trees(
+            fri_trees.as_slice(),
+            query_indices.as_slice(),
+            &mut proof,
+            &|_| {},
+        )
+        .unwrap();
         // Checks that our fri decommitment is successful
         assert_eq!(
             hex::encode(proof.coin.digest),
@@ -5,10 +5,11 @@ use std::{
     ops::{Add, Div, Mul, Sub},
     prelude::v1::*,
 };
-use zkp_primefield::FieldElement;
+use zkp_primefield::{fft::ifft, invert_batch, FieldElement};
+use zkp_u256::U256;
 
 // TODO: Rename to algebraic expression
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum RationalExpression {
     X,
@@ -20,6 +21,18 @@ pub enum RationalExpression {
     Mul(Box<RationalExpression>, Box<RationalExpression>),
     Inv(Box<RationalExpression>),
     Exp(Box<RationalExpression>, usize),
+    /// A periodic column repeating `values` on a trace of `period` rows.
+    ///
+    /// The number of values must be a power of two dividing `period`, and
+    /// `period` must divide the trace length. The expression is the
+    /// polynomial interpolating `values` on the roots of unity of order
+    /// `values.len()`, evaluated at `X^(period / values.len())`. On a trace
+    /// longer than `period`, as produced by [`fold`](crate::fold), the values
+    /// repeat on every `trace_nrows / period`-th row.
+    Periodic {
+        values: Vec<FieldElement>,
+        period: usize,
+    },
 }
 
 impl RationalExpression {
@@ -35,6 +48,33 @@ impl RationalExpression {
         Self::Exp(Box::new(self.clone()), exponent)
     }
 
+    /// The polynomial interpolating a periodic column's `values`.
+    pub(crate) fn periodic_polynomial(values: &[FieldElement]) -> DensePolynomial {
+        DensePolynomial::new(&ifft(values))
+    }
+
+    /// Whether all periodic columns have a power of two number of values
+    /// dividing their period, and a period dividing `trace_nrows`.
+    pub(crate) fn has_valid_periodic_columns(&self, trace_nrows: usize) -> bool {
+        use RationalExpression::*;
+        match self {
+            X | Constant(_) | Trace(..) => true,
+            Periodic { values, period } => {
+                values.len().is_power_of_two()
+                    && period.is_power_of_two()
+                    && values.len() <= *period
+                    && *period <= trace_nrows
+            }
+            Polynomial(_, a) | Neg(a) | Inv(a) | Exp(a, _) => {
+                a.has_valid_periodic_columns(trace_nrows)
+            }
+            Add(a, b) | Mul(a, b) => {
+                a.has_valid_periodic_columns(trace_nrows)
+                    && b.has_valid_periodic_columns(trace_nrows)
+            }
+        }
+    }
+
     /// Apply a function bottom up on the expression.
     ///
     /// **Note.** Unlike the conventional generalization of `map` to tree
@@ -139,6 +179,10 @@ impl RationalExpression {
             X => (x_degree, 0),
             Constant(_) => (0, 0),
             Trace(..) => (trace_degree, 0),
+            Periodic { values, period } => {
+                let degree = Self::periodic_polynomial(values).degree();
+                (degree * (period / values.len()) * x_degree, 0)
+            }
             Polynomial(p, a) => {
                 let (n, d) = a.degree_impl(x_degree, trace_degree);
                 (p.degree() * n, p.degree() * d)
@@ -182,6 +226,7 @@ impl RationalExpression {
             X => (x.clone(), true),
             Constant(c) => (c.clone(), true),
             &Trace(i, j) => (trace(i, j), true),
+            Periodic { values, period } => (periodic_value(values, *period, x), true),
 
             Polynomial(p, a) => {
                 let (res, is_ok) = a.check(x, trace);
@@ -265,6 +310,7 @@ impl RationalExpression {
             X => x.clone(),
             Constant(c) => c.clone(),
             &Trace(i, j) => trace(i, j),
+            Periodic { values, period } => periodic_value(values, *period, x),
             Polynomial(p, a) => p.evaluate(&a.evaluate(x, trace)),
             Add(a, b) => a.evaluate(x, trace) + b.evaluate(x, trace),
             Neg(a) => -&a.evaluate(x, trace),
@@ -286,7 +332,7 @@ impl RationalExpression {
             &Trace(i, j) => {
                 let _ = s.insert((i, j));
             }
-            X | Constant(_) => (),
+            X | Constant(_) | Periodic { .. } => (),
             Polynomial(_, a) | Exp(a, _) | Neg(a) | Inv(a) => a.trace_arguments_impl(s),
             Add(a, b) | Mul(a, b) => {
                 a.trace_arguments_impl(s);
@@ -294,4 +340,100 @@ impl RationalExpression {
             }
         }
     }
+
+    /// Append a canonical binary encoding of the expression to `out`.
+    ///
+    /// The encoding is a prefix notation with a one byte tag per node,
+    /// integers as big-endian `u64`s and constants in standard (not
+    /// Montgomery) form. Structurally different expressions have different
+    /// encodings, even if they evaluate to the same values.
+    pub(crate) fn write_canonical(&self, out: &mut Vec<u8>) {
+        use RationalExpression::*;
+        match self {
+            X => out.push(0),
+            Constant(c) => {
+                out.push(1);
+                out.extend_from_slice(&U256::from(c).to_bytes_be());
+            }
+            &Trace(i, j) => {
+                out.push(2);
+                out.extend_from_slice(&(i as u64).to_be_bytes());
+                out.extend_from_slice(&(j as i64).to_be_bytes());
+            }
+            Polynomial(p, a) => {
+                out.push(3);
+                out.extend_from_slice(&(p.len() as u64).to_be_bytes());
+                for coefficient in p.coefficients() {
+                    out.extend_from_slice(&U256::from(coefficient).to_bytes_be());
+                }
+                a.write_canonical(out);
+            }
+            Add(a, b) => {
+                out.push(4);
+                a.write_canonical(out);
+                b.write_canonical(out);
+            }
+            Neg(a) => {
+                out.push(5);
+                a.write_canonical(out);
+            }
+            Mul(a, b) => {
+                out.push(6);
+                a.write_canonical(out);
+                b.write_canonical(out);
+            }
+            Inv(a) => {
+                out.push(7);
+                a.write_canonical(out);
+            }
+            Exp(a, e) => {
+                out.push(8);
+                a.write_canonical(out);
+                out.extend_from_slice(&(*e as u64).to_be_bytes());
+            }
+            Periodic { values, period } => {
+                out.push(9);
+                out.extend_from_slice(&(values.len() as u64).to_be_bytes());
+                for value in values {
+                    out.extend_from_slice(&U256::from(value).to_bytes_be());
+                }
+                out.extend_from_slice(&(*period as u64).to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Evaluate the periodic column repeating `values` on a trace of `period`
+/// rows at `x`.
+///
+/// Uses the barycentric formula for interpolation on the roots of unity
+///
+/// ```text
+/// P(z) = (z^n - 1) / n * sum_i values[i] * ω^i / (z - ω^i)
+/// ```
+///
+/// with `z = x^(period / n)`, so it takes `O(n)` operations and a single
+/// inversion. On the roots of unity the value is looked up directly.
+fn periodic_value(values: &[FieldElement], period: usize, x: &FieldElement) -> FieldElement {
+    let n = values.len();
+    let z = x.pow(period / n);
+    let omega = FieldElement::root(n).expect("No root of unity for periodic column");
+    let mut roots = Vec::with_capacity(n);
+    let mut root = FieldElement::ONE;
+    for _ in 0..n {
+        if root == z {
+            return values[roots.len()].clone();
+        }
+        roots.push(root.clone());
+        root *= &omega;
+    }
+    let denominators = roots.iter().map(|root| &z - root).collect::<Vec<_>>();
+    let sum = values
+        .iter()
+        .zip(roots.iter().zip(invert_batch(&denominators)))
+        .fold(FieldElement::ZERO, |sum, (value, (root, inverse))| {
+            sum + value * root * inverse
+        });
+    let n_inverse = FieldElement::from(n).inv().unwrap();
+    (z.pow(n) - FieldElement::ONE) * n_inverse * sum
 }
@@ -0,0 +1,436 @@
+//! Soundness of Stark proofs.
+//!
+//! The bounds follow the ethSTARK documentation (<https://eprint.iacr.org/2021/582>).
+//! A proof with `b` bits of security convinces a verifier of a false claim
+//! with probability at most `2^-b`.
+use crate::constraints::{Constraints, Shape};
+use std::prelude::v1::*;
+
+/// Number of bits in the field size, rounded down.
+///
+/// The modulus is $2^{251} + 17 \cdot 2^{192} + 1$.
+pub(crate) const FIELD_BITS: usize = 251;
+
+/// Conjectured security level in bits.
+///
+/// This is the minimum of
+///
+/// * the query phase, `log2(blowup)` bits per query plus the proof of work
+///   bits,
+/// * the commit phases, `FIELD_BITS - log2(domain size)` bits, and
+/// * the out-of-domain check, see [`Constraints::oods_security_bits`].
+///
+/// The query phase bound relies on the conjecture that FRI is sound up to
+/// the Reed-Solomon code's minimum distance.
+pub fn conjectured_security(constraints: &Constraints) -> usize {
+    conjectured_security_with(constraints, &constraints.shape())
+}
+
+fn conjectured_security_with(constraints: &Constraints, shape: &Shape) -> usize {
+    let blowup_log = constraints.lde_blowup_with(shape).trailing_zeros() as usize;
+    let query_bits = blowup_log * constraints.num_queries + constraints.pow_bits;
+    let domain_log = (constraints.trace_nrows() * constraints.blowup).trailing_zeros() as usize;
+    let field_bits = FIELD_BITS.saturating_sub(domain_log);
+    query_bits
+        .min(field_bits)
+        .min(constraints.oods_security_bits_with(shape))
+}
+
+/// Proven security level in bits.
+///
+/// Uses the list-decoding regime bounds of ethSTARK theorem 2, optimized over
+/// the proximity parameter `m`. These require no conjectures, but need about
+/// twice the number of queries as [`conjectured_security`] for the same level.
+#[cfg(feature = "std")]
+pub fn proven_security(constraints: &Constraints) -> usize {
+    proven_security_with(constraints, &constraints.shape())
+}
+
+#[cfg(feature = "std")]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn proven_security_with(constraints: &Constraints, shape: &Shape) -> usize {
+    let bound = ProvenBound::new(constraints, shape);
+    (3..=100)
+        .map(|m| bound.bits(f64::from(m)))
+        .fold(0.0, f64::max)
+        .floor() as usize
+}
+
+/// The parameters of [`proven_security`] that do not depend on `m`.
+#[cfg(feature = "std")]
+struct ProvenBound {
+    trace_length:    f64,
+    domain_size:     f64,
+    degree:          f64,
+    rows:            f64,
+    num_oods_points: f64,
+    folding:         f64,
+    num_queries:     f64,
+    pow_bits:        f64,
+}
+
+#[cfg(feature = "std")]
+#[allow(clippy::cast_precision_loss)]
+impl ProvenBound {
+    fn new(constraints: &Constraints, shape: &Shape) -> Self {
+        Self {
+            trace_length:    constraints.trace_poly_len_with(shape) as f64,
+            domain_size:     (constraints.trace_nrows() * constraints.blowup) as f64,
+            degree:          shape.degree as f64,
+            rows:            shape.num_offsets as f64,
+            num_oods_points: constraints.num_oods_points as f64,
+            folding:         constraints
+                .fri_layout
+                .iter()
+                .map(|&reductions| (1_u64 << reductions) as f64)
+                .sum(),
+            num_queries:     constraints.num_queries as f64,
+            pow_bits:        constraints.pow_bits as f64,
+        }
+    }
+
+    fn bits(&self, m: f64) -> f64 {
+        let field_bits = FIELD_BITS as f64;
+        let domain_size = self.domain_size;
+        let rate = self.trace_length / domain_size;
+
+        // Size of the list of codewords close to the committed word
+        if rate <= 2.0 * m / domain_size {
+            return 0.0;
+        }
+        let list_size = m / (rate - 2.0 * m / domain_size);
+
+        // Random linear combination of the constraints
+        let ali = field_bits - list_size.log2();
+
+        // Out-of-domain sampling
+        let deep = self.num_oods_points
+            * (field_bits
+                - (self.degree * (self.trace_length + self.rows - 1.0) + self.trace_length - 1.0)
+                    .log2())
+            - 2.0 * list_size.log2();
+
+        // FRI commit phase
+        let commit = field_bits
+            - ((m + 0.5).powi(7) / (3.0 * rate.powf(1.5)) * domain_size.powi(2)
+                + (2.0 * m + 1.0) * (domain_size + 1.0) / rate.sqrt() * self.folding)
+                .log2();
+
+        // FRI query phase
+        let alpha = (1.0 + 0.5 / m) * rate.sqrt();
+        let query = -self.num_queries * alpha.log2() + self.pow_bits;
+
+        ali.min(deep).min(commit).min(query).max(0.0)
+    }
+}
+
+/// Security bound to use in [`search_parameters`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bound {
+    Conjectured,
+    Proven,
+}
+
+/// What [`search_parameters`] optimizes for.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Objective {
+    /// The smallest [`Constraints::max_proof_size`].
+    ProofSize,
+    /// The least prover work, counting FFTs, hashing and proof of work.
+    ProverTime,
+}
+
+/// Proof parameters found by [`search_parameters`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    pub blowup:      usize,
+    pub num_queries: usize,
+    pub pow_bits:    usize,
+    pub fri_layout:  Vec<usize>,
+}
+
+#[cfg(feature = "std")]
+impl Parameters {
+    /// Largest blowup considered.
+    ///
+    /// The evaluation domain, and with it the prover's memory use, grows
+    /// linearly in the blowup.
+    pub const MAX_BLOWUP: usize = 256;
+    /// Largest proof of work difficulty considered.
+    ///
+    /// At `2^20` hashes the proof of work takes well under a second.
+    pub const MAX_POW_BITS: usize = 20;
+    /// Largest number of queries considered.
+    ///
+    /// Past this, even a blowup of two gives more than the field can support.
+    pub const MAX_QUERIES: usize = 512;
+
+    /// Set the blowup, number of queries, proof of work difficulty and FRI
+    /// layout of `constraints` to these parameters.
+    ///
+    /// The other fields are left as they are. These should be the same as
+    /// in the constraints passed to [`search_parameters`], otherwise the
+    /// security level may not be reached.
+    pub fn apply(&self, constraints: &mut Constraints) {
+        constraints.blowup = self.blowup;
+        constraints.num_queries = self.num_queries;
+        constraints.pow_bits = self.pow_bits;
+        constraints.fri_layout.clone_from(&self.fri_layout);
+    }
+
+    fn security(constraints: &Constraints, shape: &Shape, bound: Bound) -> usize {
+        match bound {
+            Bound::Conjectured => conjectured_security_with(constraints, shape),
+            Bound::Proven => proven_security_with(constraints, shape),
+        }
+    }
+
+    /// Rough prover cost in field operations.
+    fn prover_cost(constraints: &Constraints, shape: &Shape) -> usize {
+        let domain_size = constraints.trace_nrows() * constraints.blowup;
+        let domain_log = domain_size.trailing_zeros() as usize;
+        let columns = constraints.trace_ncolumns() + shape.degree.next_power_of_two() + 1;
+        // Low degree extensions and hashing of the trace and constraint
+        // polynomials, plus the proof of work.
+        columns * domain_size * (domain_log + 1) + (1 << constraints.pow_bits)
+    }
+
+    /// Lower bound on the objective for any parameters with the blowup of
+    /// `constraints`.
+    fn objective_bound(
+        constraints: &Constraints,
+        shape: &Shape,
+        target_bits: usize,
+        objective: Objective,
+    ) -> usize {
+        match objective {
+            Objective::ProofSize => {
+                // Each query gives at most `log2(blowup)` bits under either
+                // bound, and zero-knowledge halves the effective blowup.
+                let bits_per_query = (constraints.blowup.trailing_zeros() as usize)
+                    .saturating_sub(usize::from(constraints.zero_knowledge));
+                if bits_per_query == 0 {
+                    return 0;
+                }
+                let query_bits = target_bits.saturating_sub(Self::MAX_POW_BITS);
+                let min_queries = (query_bits + bits_per_query - 1) / bits_per_query;
+                // Every query opens a trace and a constraint value with an
+                // authentication path, see `Constraints::max_proof_size`.
+                let domain_size = constraints.trace_nrows() * constraints.blowup;
+                let domain_log = domain_size.trailing_zeros() as usize;
+                let values = constraints.trace_ncolumns() + shape.degree.next_power_of_two();
+                32 * min_queries * (values + 2 * domain_log)
+            }
+            Objective::ProverTime => {
+                // The cost without proof of work
+                Self::prover_cost(constraints, shape) + 1 - (1 << constraints.pow_bits)
+            }
+        }
+    }
+}
+
+/// FRI layouts of equal sized steps that are accepted by the prover.
+#[cfg(feature = "std")]
+fn fri_layouts(constraints: &Constraints, shape: &Shape) -> Vec<Vec<usize>> {
+    let max_reductions = constraints.trace_poly_len_with(shape).trailing_zeros() as usize;
+    let mut layouts = Vec::new();
+    for reductions in 1..=max_reductions {
+        for step in 1..=4 {
+            let mut layout = vec![step; reductions / step];
+            if reductions % step != 0 {
+                layout.push(reductions % step);
+            }
+            if !layouts.contains(&layout) {
+                layouts.push(layout);
+            }
+        }
+    }
+    layouts
+}
+
+/// Find the proof parameters that reach `target_bits` of security at the
+/// lowest cost.
+///
+/// The trace shape, constraints, zero-knowledge mode and number of
+/// out-of-domain points are taken from `constraints`. For each blowup, FRI
+/// layout and proof of work difficulty the smallest sufficient number of
+/// queries is used. Ties are broken by the other objective. Returns `None`
+/// if the target can not be reached within the limits in [`Parameters`].
+#[cfg(feature = "std")]
+pub fn search_parameters(
+    constraints: &Constraints,
+    target_bits: usize,
+    bound: Bound,
+    objective: Objective,
+) -> Option<Parameters> {
+    let mut constraints = constraints.clone();
+    let shape = constraints.shape();
+    let mut min_blowup = shape.degree.next_power_of_two();
+    if constraints.zero_knowledge {
+        min_blowup *= 2;
+    }
+
+    let mut best: Option<((usize, usize), Parameters)> = None;
+    let mut blowup = min_blowup.max(2);
+    while blowup <= Parameters::MAX_BLOWUP {
+        constraints.blowup = blowup;
+        blowup *= 2;
+
+        // Both bounds are capped by the commit phase, which only gets weaker
+        // as the evaluation domain grows.
+        let domain_log = (constraints.trace_nrows() * constraints.blowup).trailing_zeros() as usize;
+        if FIELD_BITS.saturating_sub(domain_log) < target_bits {
+            break;
+        }
+
+        // Skip blowups that can not improve on the best parameters so far.
+        // The prover cost only grows with the blowup, so neither can any
+        // larger blowup.
+        if let Some(((best_objective, _), _)) = &best {
+            if Parameters::objective_bound(&constraints, &shape, target_bits, objective)
+                > *best_objective
+            {
+                match objective {
+                    Objective::ProofSize => continue,
+                    Objective::ProverTime => break,
+                }
+            }
+        }
+
+        for fri_layout in fri_layouts(&constraints, &shape) {
+            constraints.fri_layout = fri_layout;
+            for pow_bits in 0..=Parameters::MAX_POW_BITS {
+                constraints.pow_bits = pow_bits;
+
+                // Security increases with the number of queries
+                let (mut low, mut high) = (1, Parameters::MAX_QUERIES);
+                constraints.num_queries = high;
+                if Parameters::security(&constraints, &shape, bound) < target_bits {
+                    continue;
+                }
+                while low < high {
+                    constraints.num_queries = low + (high - low) / 2;
+                    if Parameters::security(&constraints, &shape, bound) >= target_bits {
+                        high = constraints.num_queries;
+                    } else {
+                        low = constraints.num_queries + 1;
+                    }
+                }
+                constraints.num_queries = high;
+
+                let size = constraints.max_proof_size_with(&shape);
+                let cost = Parameters::prover_cost(&constraints, &shape);
+                let key = match objective {
+                    Objective::ProofSize => (size, cost),
+                    Objective::ProverTime => (cost, size),
+                };
+                let better = match &best {
+                    Some((best_key, _)) => key < *best_key,
+                    None => true,
+                };
+                if better {
+                    best = Some((key, Parameters {
+                        blowup: constraints.blowup,
+                        num_queries: constraints.num_queries,
+                        pow_bits,
+                        fri_layout: constraints.fri_layout.clone(),
+                    }));
+                }
+            }
+        }
+    }
+    best.map(|(_, parameters)| parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prove, traits::tests::Recurrance, verify, Provable, Verifiable};
+    use zkp_macros_decl::field_element;
+    use zkp_primefield::FieldElement;
+    use zkp_u256::U256;
+
+    fn recurrance() -> Recurrance {
+        Recurrance {
+            index:         1000,
+            initial_value: field_element!("cafebabe"),
+            exponent:      1,
+        }
+    }
+
+    #[test]
+    fn test_conjectured_security() {
+        let mut constraints = recurrance().claim().constraints();
+        constraints.blowup = 16;
+        constraints.pow_bits = 10;
+        constraints.num_queries = 20;
+        assert_eq!(conjectured_security(&constraints), 4 * 20 + 10);
+        assert_eq!(constraints.security_bits(), 4 * 20 + 10);
+
+        // Limited by the field size for many queries
+        constraints.num_queries = 100;
+        assert_eq!(conjectured_security(&constraints), FIELD_BITS - 14);
+
+        // Zero-knowledge halves the effective blowup
+        constraints.num_queries = 20;
+        constraints.zero_knowledge = true;
+        assert_eq!(conjectured_security(&constraints), 3 * 20 + 10);
+    }
+
+    #[test]
+    fn test_proven_security() {
+        let mut constraints = recurrance().claim().constraints();
+        constraints.blowup = 16;
+        constraints.pow_bits = 0;
+        for &num_queries in &[10, 20, 40, 80] {
+            constraints.num_queries = num_queries;
+            let proven = proven_security(&constraints);
+            assert!(proven > 0);
+            assert!(proven < conjectured_security(&constraints));
+        }
+
+        // More out-of-domain points do not reduce security
+        let single = proven_security(&constraints);
+        constraints.num_oods_points = 2;
+        assert!(proven_security(&constraints) >= single);
+    }
+
+    #[test]
+    fn test_search_parameters() {
+        let recurrance = recurrance();
+        let claim = recurrance.claim();
+        let trace = claim.trace(&recurrance.witness());
+        let mut constraints = claim.constraints();
+
+        for &objective in &[Objective::ProofSize, Objective::ProverTime] {
+            let parameters =
+                search_parameters(&constraints, 80, Bound::Conjectured, objective).unwrap();
+            parameters.apply(&mut constraints);
+            assert!(constraints.security_bits() >= 80);
+            constraints.num_queries -= 1;
+            assert!(constraints.security_bits() < 80);
+            constraints.num_queries += 1;
+
+            let proof = prove(&constraints, &trace).unwrap();
+            assert_eq!(verify(&constraints, &proof), Ok(()));
+            assert!(proof.as_bytes().len() <= constraints.max_proof_size());
+        }
+
+        // The fastest prover uses the smallest blowup and no proof of work
+        let parameters =
+            search_parameters(&constraints, 80, Bound::Proven, Objective::ProverTime).unwrap();
+        assert_eq!(parameters.blowup, 2);
+        assert_eq!(parameters.pow_bits, 0);
+        parameters.apply(&mut constraints);
+        assert!(proven_security(&constraints) >= 80);
+
+        // Out of reach
+        assert_eq!(
+            search_parameters(&constraints, 250, Bound::Conjectured, Objective::ProofSize),
+            None
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use crate::{polynomial::DensePolynomial, rational_expression::RationalExpression};
+use std::{collections::BTreeMap, prelude::v1::*};
+use zkp_primefield::FieldElement;
+use zkp_u256::U256;
+
+/// A node in an [`ExpressionGraph`], referring to its operands by index.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Node {
+    X,
+    /// A constant in Montgomery form.
+    Constant(U256),
+    Trace(usize, isize),
+    /// Index into the polynomials and the argument.
+    Polynomial(usize, usize),
+    /// Index into the periodic columns and the period.
+    Periodic(usize, usize),
+    Add(usize, usize),
+    Neg(usize),
+    Mul(usize, usize),
+    Inv(usize),
+    Exp(usize, usize),
+}
+
+/// Hash-consed and simplified form of [`RationalExpression`]s.
+///
+/// Every distinct subexpression is stored once, so shared subexpressions like
+/// the vanishing polynomial of the trace domain are only evaluated once.
+/// Nodes are simplified as they are inserted by
+///
+/// * folding constants,
+/// * removing `a + 0`, `a * 1`, `a * 0`, `a - a`, `--a` and `1 / (1 / a)`,
+/// * merging exponents, as in `a * a = a^2` and `(a^2)^3 = a^6`, and
+/// * moving inverses to the top, so that `a / b * c / d = (a * c) / (b * d)`
+///   and `a / d + b / d = (a + b) / d`.
+///
+/// These preserve the value wherever the original expression is defined.
+/// Operands of sums and products keep their order.
+#[derive(Default)]
+pub(crate) struct ExpressionGraph {
+    nodes:       Vec<Node>,
+    polynomials: Vec<DensePolynomial>,
+    /// Values of the periodic columns with their interpolating polynomial.
+    periodic:    Vec<(Vec<FieldElement>, DensePolynomial)>,
+    index:       BTreeMap<Node, usize>,
+}
+
+impl ExpressionGraph {
+    /// Insert an expression and return the index of its root node.
+    pub(crate) fn insert(&mut self, expression: &RationalExpression) -> usize {
+        use RationalExpression as RE;
+        match expression {
+            RE::X => self.intern(Node::X),
+            RE::Constant(value) => self.constant(value),
+            RE::Trace(i, j) => self.intern(Node::Trace(*i, *j)),
+            RE::Periodic { values, period } => self.periodic(values, *period),
+            RE::Polynomial(p, a) => {
+                let a = self.insert(a);
+                self.polynomial(p, a)
+            }
+            RE::Add(a, b) => {
+                let a = self.insert(a);
+                let b = self.insert(b);
+                self.add(a, b)
+            }
+            RE::Neg(a) => {
+                let a = self.insert(a);
+                self.neg(a)
+            }
+            RE::Mul(a, b) => {
+                let a = self.insert(a);
+                let b = self.insert(b);
+                self.mul(a, b)
+            }
+            RE::Inv(a) => {
+                let a = self.insert(a);
+                self.inv(a)
+            }
+            RE::Exp(a, e) => {
+                let a = self.insert(a);
+                self.exp(a, *e)
+            }
+        }
+    }
+
+    /// The expression tree rooted at `index`.
+    pub(crate) fn expression(&self, index: usize) -> RationalExpression {
+        use RationalExpression as RE;
+        match &self.nodes[index] {
+            Node::X => RE::X,
+            Node::Constant(value) => RE::Constant(FieldElement::from_montgomery(value.clone())),
+            Node::Trace(i, j) => RE::Trace(*i, *j),
+            Node::Periodic(p, period) => {
+                RE::Periodic {
+                    values: self.periodic[*p].0.clone(),
+                    period: *period,
+                }
+            }
+            Node::Polynomial(p, a) => {
+                RE::Polynomial(self.polynomials[*p].clone(), Box::new(self.expression(*a)))
+            }
+            Node::Add(a, b) => self.expression(*a) + self.expression(*b),
+            Node::Neg(a) => self.expression(*a).neg(),
+            Node::Mul(a, b) => self.expression(*a) * self.expression(*b),
+            Node::Inv(a) => self.expression(*a).inv(),
+            Node::Exp(a, e) => self.expression(*a).pow(*e),
+        }
+    }
+
+    /// Evaluate the node at `index`, evaluating each subexpression once.
+    pub(crate) fn evaluate(
+        &self,
+        index: usize,
+        x: &FieldElement,
+        trace: &dyn Fn(usize, isize) -> FieldElement,
+    ) -> FieldElement {
+        // Operands always precede the nodes using them.
+        let mut values: Vec<FieldElement> = Vec::with_capacity(index + 1);
+        for node in &self.nodes[..=index] {
+            let value = match node {
+                Node::X => x.clone(),
+                Node::Constant(value) => FieldElement::from_montgomery(value.clone()),
+                Node::Trace(i, j) => trace(*i, *j),
+                Node::Periodic(p, period) => {
+                    let (values, polynomial) = &self.periodic[*p];
+                    polynomial.evaluate(&x.pow(period / values.len()))
+                }
+                Node::Polynomial(p, a) => self.polynomials[*p].evaluate(&values[*a]),
+                Node::Add(a, b) => &values[*a] + &values[*b],
+                Node::Neg(a) => -&values[*a],
+                Node::Mul(a, b) => &values[*a] * &values[*b],
+                Node::Inv(a) => values[*a].inv().expect("divided by zero"),
+                Node::Exp(a, e) => values[*a].pow(*e),
+            };
+            values.push(value);
+        }
+        values.pop().unwrap()
+    }
+
+    fn intern(&mut self, node: Node) -> usize {
+        if let Some(&index) = self.index.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        let _ = self.index.insert(node, index);
+        index
+    }
+
+    fn constant(&mut self, value: &FieldElement) -> usize {
+        self.intern(Node::Constant(value.as_montgomery().clone()))
+    }
+
+    fn value(&self, index: usize) -> Option<FieldElement> {
+        match &self.nodes[index] {
+            Node::Constant(value) => Some(FieldElement::from_montgomery(value.clone())),
+            _ => None,
+        }
+    }
+
+    /// Split a node into an optional numerator and the index of an optional
+    /// `Inv` node, such that it equals `numerator * inverse`.
+    fn fraction(&self, index: usize) -> (Option<usize>, Option<usize>) {
+        match self.nodes[index] {
+            Node::Inv(_) => (None, Some(index)),
+            Node::Mul(n, d) if matches!(self.nodes[d], Node::Inv(_)) => (Some(n), Some(d)),
+            _ => (Some(index), None),
+        }
+    }
+
+    /// Split a node into a base and exponent.
+    fn power(&self, index: usize) -> (usize, usize) {
+        match self.nodes[index] {
+            Node::Exp(a, e) => (a, e),
+            _ => (index, 1),
+        }
+    }
+
+    fn denominator(&self, inverse: usize) -> usize {
+        match self.nodes[inverse] {
+            Node::Inv(a) => a,
+            _ => panic!("Node is not an inverse"),
+        }
+    }
+
+    fn product(&mut self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(self.mul(a, b)),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    fn polynomial(&mut self, polynomial: &DensePolynomial, a: usize) -> usize {
+        if polynomial.degree() == 0 {
+            return self.constant(&polynomial.coefficients()[0]);
+        }
+        if let Some(a) = self.value(a) {
+            return self.constant(&polynomial.evaluate(&a));
+        }
+        let p = if let Some(p) = self.polynomials.iter().position(|p| p == polynomial) {
+            p
+        } else {
+            self.polynomials.push(polynomial.clone());
+            self.polynomials.len() - 1
+        };
+        self.intern(Node::Polynomial(p, a))
+    }
+
+    /// Periodic columns are interpolated once, so that evaluating them takes
+    /// a single polynomial evaluation.
+    fn periodic(&mut self, values: &[FieldElement], period: usize) -> usize {
+        let p = if let Some(p) = self.periodic.iter().position(|(v, _)| v == values) {
+            p
+        } else {
+            let polynomial = RationalExpression::periodic_polynomial(values);
+            self.periodic.push((values.to_vec(), polynomial));
+            self.periodic.len() - 1
+        };
+        if self.periodic[p].1.degree() == 0 {
+            return self.constant(&values[0]);
+        }
+        self.intern(Node::Periodic(p, period))
+    }
+
+    fn add(&mut self, a: usize, b: usize) -> usize {
+        match (self.value(a), self.value(b)) {
+            (Some(a), Some(b)) => return self.constant(&(a + b)),
+            (Some(a), _) if a.is_zero() => return b,
+            (_, Some(b)) if b.is_zero() => return a,
+            _ => {}
+        }
+        if self.nodes[a] == Node::Neg(b) || self.nodes[b] == Node::Neg(a) {
+            return self.constant(&FieldElement::ZERO);
+        }
+        match (self.fraction(a), self.fraction(b)) {
+            ((n1, Some(d1)), (n2, Some(d2))) if d1 == d2 => {
+                let one = self.constant(&FieldElement::ONE);
+                let numerator = self.add(n1.unwrap_or(one), n2.unwrap_or(one));
+                self.mul(numerator, d1)
+            }
+            _ => self.intern(Node::Add(a, b)),
+        }
+    }
+
+    fn neg(&mut self, a: usize) -> usize {
+        if let Some(a) = self.value(a) {
+            return self.constant(&-&a);
+        }
+        match self.nodes[a] {
+            Node::Neg(b) => b,
+            _ => self.intern(Node::Neg(a)),
+        }
+    }
+
+    fn mul(&mut self, a: usize, b: usize) -> usize {
+        match (self.value(a), self.value(b)) {
+            (Some(a), Some(b)) => return self.constant(&(a * b)),
+            (Some(c), _) if c.is_zero() => return a,
+            (_, Some(c)) if c.is_zero() => return b,
+            (Some(c), _) if c.is_one() => return b,
+            (_, Some(c)) if c.is_one() => return a,
+            _ => {}
+        }
+        let (n1, d1) = self.fraction(a);
+        let (n2, d2) = self.fraction(b);
+        let canonical = d1.is_none() && n2.is_none();
+        if (d1.is_some() || d2.is_some()) && !canonical {
+            let d1 = d1.map(|d| self.denominator(d));
+            let d2 = d2.map(|d| self.denominator(d));
+            let numerator = self.product(n1, n2);
+            let denominator = self.product(d1, d2).unwrap();
+            let inverse = self.inv(denominator);
+            return match numerator {
+                Some(numerator) => self.mul(numerator, inverse),
+                None => inverse,
+            };
+        }
+        let (base1, e1) = self.power(a);
+        let (base2, e2) = self.power(b);
+        if base1 == base2 {
+            if let Some(e) = e1.checked_add(e2) {
+                return self.exp(base1, e);
+            }
+        }
+        self.intern(Node::Mul(a, b))
+    }
+
+    fn inv(&mut self, a: usize) -> usize {
+        if let Some(inverse) = self.value(a).as_ref().and_then(FieldElement::inv) {
+            return self.constant(&inverse);
+        }
+        match self.nodes[a] {
+            Node::Inv(b) => b,
+            Node::Mul(n, d) if matches!(self.nodes[d], Node::Inv(_)) => {
+                let d = self.denominator(d);
+                let inverse = self.inv(n);
+                self.mul(d, inverse)
+            }
+            _ => self.intern(Node::Inv(a)),
+        }
+    }
+
+    fn exp(&mut self, a: usize, e: usize) -> usize {
+        if e == 0 {
+            return self.constant(&FieldElement::ONE);
+        }
+        if e == 1 {
+            return a;
+        }
+        if let Some(a) = self.value(a) {
+            return self.constant(&a.pow(e));
+        }
+        match self.nodes[a] {
+            Node::Exp(b, f) if f.checked_mul(e).is_some() => self.exp(b, f * e),
+            Node::Inv(b) => {
+                let power = self.exp(b, e);
+                self.inv(power)
+            }
+            _ => self.intern(Node::Exp(a, e)),
+        }
+    }
+}
+
+impl RationalExpression {
+    /// Simplify the expression.
+    ///
+    /// Folds constants, removes trivial operations like `a * 1`, merges
+    /// exponents and moves inverses to the top, combining common
+    /// denominators. Identical subexpressions are recognized, so `a / d + b /
+    /// d` becomes `(a + b) / d` even if `d` is a large expression. The result
+    /// has the same value wherever the original is defined, and its
+    /// [`degree`](Self::degree) is never higher.
+    pub fn simplify(&self) -> Self {
+        let mut graph = ExpressionGraph::default();
+        let root = graph.insert(self);
+        graph.expression(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::tests::Recurrance, Verifiable};
+    use quickcheck_macros::quickcheck;
+    use zkp_macros_decl::field_element;
+
+    fn trace(i: usize, j: isize) -> FieldElement {
+        FieldElement::from(3 + i * 7) + FieldElement::from(j).pow(3_usize)
+    }
+
+    /// Number of nodes in the expression tree.
+    fn size(expression: &RationalExpression) -> usize {
+        use RationalExpression::*;
+        match expression {
+            X | Constant(_) | Trace(..) | Periodic { .. } => 1,
+            Polynomial(_, a) | Neg(a) | Inv(a) | Exp(a, _) => 1 + size(a),
+            Add(a, b) | Mul(a, b) => 1 + size(a) + size(b),
+        }
+    }
+
+    fn assert_equivalent(expression: &RationalExpression, x: &FieldElement) {
+        let simplified = expression.simplify();
+        assert_eq!(
+            simplified.evaluate(x, &trace),
+            expression.evaluate(x, &trace)
+        );
+        let (n, d) = simplified.degree(15);
+        let (n_original, d_original) = expression.degree(15);
+        assert!(n <= n_original && d <= d_original);
+    }
+
+    #[test]
+    fn test_simplify() {
+        use RationalExpression::*;
+        let simplify = |e: RationalExpression| e.simplify();
+        assert_eq!(
+            simplify((Constant(3.into()) + 4.into()) * 2.into()),
+            14.into()
+        );
+        assert_eq!(simplify(X * 1.into() + 0.into()), X);
+        assert_eq!(simplify(X - X), 0.into());
+        assert_eq!(simplify(X.neg().neg()), X);
+        assert_eq!(simplify(X.inv().inv()), X);
+        assert_eq!(simplify(X.pow(2) * X.pow(3)), X.pow(5));
+        assert_eq!(simplify(X.pow(2).pow(3) * X), X.pow(7));
+        assert_eq!(simplify(Trace(0, 1) * Trace(0, 1)), Trace(0, 1).pow(2));
+        assert_eq!(
+            simplify(X.pow(0) + Trace(0, 0)),
+            Constant(1.into()) + Trace(0, 0)
+        );
+        assert_eq!(
+            simplify(Polynomial(
+                DensePolynomial::new(&[1.into(), 2.into()]),
+                Box::new(Constant(3.into()))
+            )),
+            7.into()
+        );
+        assert_eq!(
+            simplify(Trace(0, 0) / X + Trace(1, 0) / X),
+            (Trace(0, 0) + Trace(1, 0)) / X
+        );
+        assert_eq!(
+            simplify(Trace(0, 0) / X * (Trace(1, 0) / (X - 1.into()))),
+            (Trace(0, 0) * Trace(1, 0)) / (X * (X + Constant(-&FieldElement::ONE)))
+        );
+        assert_eq!(simplify(X.inv().pow(3)), X.pow(3).inv());
+    }
+
+    #[test]
+    fn test_periodic() {
+        use RationalExpression::*;
+        let values = (0..4)
+            .map(|i| FieldElement::from(i + 7))
+            .collect::<Vec<_>>();
+        let periodic = Periodic {
+            values: values.clone(),
+            period: 16,
+        };
+        let mut graph = ExpressionGraph::default();
+        let root = graph.insert(&(periodic.clone() * Trace(0, 0)));
+        let x = field_element!("0123456789abcdef");
+        assert_eq!(
+            graph.evaluate(root, &x, &trace),
+            periodic.evaluate(&x, &trace) * trace(0, 0)
+        );
+        // On row `i` of the trace the value is `values[i % 4]`
+        let omega = FieldElement::root(16).unwrap();
+        for i in 0..16 {
+            let x = omega.pow(i);
+            assert_eq!(periodic.evaluate(&x, &trace), values[i % 4]);
+            assert_eq!(
+                graph.evaluate(root, &x, &trace),
+                &values[i % 4] * trace(0, 0)
+            );
+        }
+        assert_eq!(periodic.simplify(), periodic);
+        let constant = Periodic {
+            values: vec![5.into(); 4],
+            period: 16,
+        };
+        assert_eq!(constant.simplify(), 5.into());
+        assert_eq!(periodic.degree(1), (3 * 4, 0));
+    }
+
+    #[test]
+    fn test_common_subexpressions() {
+        let constraints = Recurrance {
+            index:         1000,
+            initial_value: field_element!("cafebabe"),
+            exponent:      3,
+        }
+        .claim()
+        .constraints();
+        let coefficients = (0..2 * constraints.len())
+            .map(|i| FieldElement::from(i + 5))
+            .collect::<Vec<_>>();
+        let combined = constraints.combine(&coefficients);
+        let x = field_element!("0123456789abcdef");
+        let mut graph = ExpressionGraph::default();
+        let root = graph.insert(&combined);
+        assert_eq!(
+            graph.evaluate(root, &x, &trace),
+            combined.evaluate(&x, &trace)
+        );
+        assert!(graph.nodes.len() < size(&combined));
+        // The two transition constraints share a denominator
+        let inversions = graph
+            .nodes
+            .iter()
+            .filter(|node| matches!(node, Node::Inv(_)));
+        assert_eq!(inversions.count(), 3);
+        for expression in constraints.expressions() {
+            assert_equivalent(expression, &x);
+        }
+        assert_equivalent(&combined, &x);
+    }
+
+    #[quickcheck]
+    #[allow(clippy::needless_pass_by_value)] // Cleaner than adding lifetime annotations.
+    fn simplify_preserves_value(x: FieldElement, seeds: Vec<u8>) -> bool {
+        use RationalExpression::*;
+        // Build an expression from a sequence of operations. The expression
+        // can double in size each step.
+        let mut expression = X;
+        for &seed in seeds.iter().take(12) {
+            let operand = match seed % 4 {
+                0 => X,
+                1 => Trace(usize::from(seed % 3), isize::from(seed % 5) - 2),
+                2 => Constant(FieldElement::from(usize::from(seed / 16))),
+                _ => expression.clone(),
+            };
+            expression = match (seed / 4) % 6 {
+                0 => expression + operand,
+                1 => expression - operand,
+                2 => expression * operand,
+                3 => expression / (operand + X.pow(100)),
+                4 => expression.pow(usize::from(seed % 4)),
+                _ => expression.neg(),
+            }
+        }
+        let simplified = expression.simplify();
+        simplified.evaluate(&x, &trace) == expression.evaluate(&x, &trace)
+    }
+}
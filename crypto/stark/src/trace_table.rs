@@ -1,6 +1,7 @@
 use crate::polynomial::DensePolynomial;
 use rayon::prelude::*;
 use std::{
+    borrow::Cow,
     ops::{Index, IndexMut},
     prelude::v1::*,
 };
@@ -16,6 +17,26 @@ pub struct TraceTable {
     trace_length: usize,
     num_columns:  usize,
     values:       MmapVec<FieldElement>,
+    /// Column polynomials computed by [`TraceBuilder`], cleared on mutation.
+    polynomials:  Vec<DensePolynomial>,
+}
+
+/// Incrementally build a [`TraceTable`].
+///
+/// Values can be written a row at a time or in chunks per column, in any mix
+/// as long as each column is filled in order. The values are stored in a
+/// memory mapped temporary file, and each column is interpolated as soon as
+/// it is complete. The resulting table carries the column polynomials, which
+/// the prover uses without copying them. Together with the values they take
+/// the same space as interpolating a [`TraceTable`] in the prover, but the
+/// interpolation is spread out over the trace generation.
+#[derive(Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TraceBuilder {
+    table:       TraceTable,
+    /// Number of values written to each column.
+    filled:      Vec<usize>,
+    polynomials: Vec<Option<DensePolynomial>>,
 }
 
 impl TraceTable {
@@ -29,6 +50,7 @@ impl TraceTable {
             trace_length,
             num_columns,
             values,
+            polynomials: Vec::new(),
         }
     }
 
@@ -68,26 +90,129 @@ impl TraceTable {
         result
     }
 
+    /// Interpolate the columns.
+    ///
+    /// This copies the column polynomials if the table was built using a
+    /// [`TraceBuilder`].
     pub fn interpolate(&self) -> Vec<DensePolynomial> {
-        (0..self.num_columns())
+        self.polynomials().into_owned()
+    }
+
+    /// The column polynomials, borrowed if they were computed by a
+    /// [`TraceBuilder`].
+    pub(crate) fn polynomials(&self) -> Cow<'_, [DensePolynomial]> {
+        if self.polynomials.len() == self.num_columns() {
+            return Cow::Borrowed(&self.polynomials);
+        }
+        let polynomials = (0..self.num_columns())
             .into_par_iter()
             // OPT: Use and FFT that can transform the entire table in one pass,
             // working on whole rows at a time. That is, it is vectorized over rows.
             // OPT: Use an in-place FFT. We don't need the trace table after this,
             // so it can be replaced by a matrix of coefficients.
-            .map(|j| {
-                // Copy column to vec
-                let mut vec = MmapVec::with_capacity(self.num_rows());
-                for v in self.iter_column(j) {
-                    vec.push(v.clone());
-                }
-
-                // Transform to coefficients
-                ifft_permuted(&mut vec);
-                permute(&mut vec);
-                DensePolynomial::from_mmap_vec(vec)
-            })
-            .collect::<Vec<DensePolynomial>>()
+            .map(|j| self.interpolate_column(j))
+            .collect::<Vec<DensePolynomial>>();
+        Cow::Owned(polynomials)
+    }
+
+    fn interpolate_column(&self, j: usize) -> DensePolynomial {
+        // Copy column to vec
+        let mut vec = self.column_to_mmapvec(j);
+
+        // Transform to coefficients
+        ifft_permuted(&mut vec);
+        permute(&mut vec);
+        DensePolynomial::from_mmap_vec(vec)
+    }
+}
+
+impl TraceBuilder {
+    /// # Panics
+    ///
+    /// Panics if `trace_length` is not a power of two.
+    pub fn new(trace_length: usize, num_columns: usize) -> Self {
+        assert!(trace_length.is_power_of_two());
+        Self {
+            table:       TraceTable::new(trace_length, num_columns),
+            filled:      vec![0; num_columns],
+            polynomials: vec![None; num_columns],
+        }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.table.num_rows()
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.table.num_columns()
+    }
+
+    /// Number of complete rows written so far.
+    pub fn rows_written(&self) -> usize {
+        self.filled.iter().copied().min().unwrap_or(0)
+    }
+
+    /// Append a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row has the wrong number of values or if the columns are
+    /// not all filled up to the same row.
+    pub fn push_row(&mut self, row: &[FieldElement]) {
+        assert_eq!(row.len(), self.num_columns());
+        let i = self.rows_written();
+        assert!(self.filled.iter().all(|&filled| filled == i));
+        self.table[i].clone_from_slice(row);
+        for j in 0..self.num_columns() {
+            self.column_written(j, 1);
+        }
+    }
+
+    /// Append all rows from an iterator.
+    pub fn extend_rows<I, R>(&mut self, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[FieldElement]>,
+    {
+        for row in rows {
+            self.push_row(row.as_ref());
+        }
+    }
+
+    /// Append values to a column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this would write past the end of the column.
+    pub fn push_column_chunk(&mut self, j: usize, chunk: &[FieldElement]) {
+        let start = self.filled[j];
+        assert!(start + chunk.len() <= self.num_rows());
+        for (i, value) in chunk.iter().enumerate() {
+            self.table[(start + i, j)] = value.clone();
+        }
+        self.column_written(j, chunk.len());
+    }
+
+    /// Finish the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not all values have been written.
+    pub fn build(self) -> TraceTable {
+        let mut table = self.table;
+        table.polynomials = self
+            .polynomials
+            .into_iter()
+            .map(|polynomial| polynomial.expect("Trace column is not complete."))
+            .collect();
+        table
+    }
+
+    fn column_written(&mut self, j: usize, count: usize) {
+        self.filled[j] += count;
+        if count > 0 && self.filled[j] == self.num_rows() {
+            self.polynomials[j] = Some(self.table.interpolate_column(j));
+        }
     }
 }
 
@@ -105,6 +230,7 @@ impl Index<(usize, usize)> for TraceTable {
 /// Returns a mutable field
 impl IndexMut<(usize, usize)> for TraceTable {
     fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        self.polynomials.clear();
         assert!(i < self.trace_length);
         assert!(j < self.num_columns);
         &mut self.values[i * self.num_columns + j]
@@ -124,7 +250,67 @@ impl Index<usize> for TraceTable {
 /// Returns a mutable row as a slice
 impl IndexMut<usize> for TraceTable {
     fn index_mut(&mut self, i: usize) -> &mut [FieldElement] {
+        self.polynomials.clear();
         assert!(i < self.trace_length);
         &mut self.values[i * self.num_columns..(i + 1) * self.num_columns]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(i: usize, j: usize) -> FieldElement {
+        FieldElement::from(i * i + 7 * j)
+    }
+
+    fn table(trace_length: usize, num_columns: usize) -> TraceTable {
+        let mut table = TraceTable::new(trace_length, num_columns);
+        for i in 0..trace_length {
+            for j in 0..num_columns {
+                table[(i, j)] = value(i, j);
+            }
+        }
+        table
+    }
+
+    #[test]
+    fn build_from_rows() {
+        let mut builder = TraceBuilder::new(16, 3);
+        builder.extend_rows((0..16).map(|i| (0..3).map(|j| value(i, j)).collect::<Vec<_>>()));
+        let built = builder.build();
+        let expected = table(16, 3);
+        assert_eq!(built.values, expected.values);
+        assert!(matches!(built.polynomials(), Cow::Borrowed(_)));
+        assert!(matches!(expected.polynomials(), Cow::Owned(_)));
+        assert_eq!(built.interpolate(), expected.interpolate());
+    }
+
+    #[test]
+    fn build_from_column_chunks() {
+        let mut builder = TraceBuilder::new(16, 2);
+        for j in 0..2 {
+            let column = (0..16).map(|i| value(i, j)).collect::<Vec<_>>();
+            for chunk in column.chunks(5) {
+                builder.push_column_chunk(j, chunk);
+            }
+            assert!(builder.polynomials[j].is_some());
+        }
+        let mut built = builder.build();
+        let expected = table(16, 2);
+        assert_eq!(built.interpolate(), expected.interpolate());
+
+        // Mutating the table invalidates the cached polynomials
+        built[(3, 1)] = FieldElement::ZERO;
+        assert!(built.polynomials.is_empty());
+        assert_ne!(built.interpolate(), expected.interpolate());
+    }
+
+    #[test]
+    #[should_panic(expected = "Trace column is not complete.")]
+    fn build_incomplete() {
+        let mut builder = TraceBuilder::new(16, 2);
+        builder.push_row(&[FieldElement::ONE, FieldElement::ZERO]);
+        let _ = builder.build();
+    }
+}
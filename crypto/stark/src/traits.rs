@@ -15,6 +15,8 @@ pub trait Verifiable {
 
 #[cfg(feature = "prover")]
 pub trait Provable<T>: Verifiable {
+    /// Large traces can be built incrementally using a
+    /// [`TraceBuilder`](crate::TraceBuilder).
     fn trace(&self, witness: T) -> TraceTable;
 
     fn prove(&self, witness: T) -> Result<Proof, ProverError> {
@@ -43,10 +45,11 @@ pub trait Provable<T>: Verifiable {
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
-    use crate::RationalExpression;
+    use crate::{BoundaryConstraints, RationalExpression};
     use quickcheck::{Arbitrary, Gen};
     use std::convert::TryInto;
     use zkp_primefield::FieldElement;
+    use zkp_u256::U256;
 
     // False positives on the Latex math.
     #[allow(clippy::doc_markdown)]
@@ -62,9 +65,9 @@ pub(crate) mod tests {
 
     #[derive(Clone, PartialEq, Debug)]
     pub(crate) struct Claim {
-        index:    usize,
-        value:    FieldElement,
-        exponent: usize,
+        index:            usize,
+        pub(crate) value: FieldElement,
+        exponent:         usize,
     }
 
     #[derive(Clone, PartialEq, Debug)]
@@ -73,6 +76,16 @@ pub(crate) mod tests {
     }
 
     impl Recurrance {
+        /// The instance shared by the proof format, prover and verifier
+        /// tests.
+        pub(crate) fn example() -> Self {
+            Self {
+                index:         25,
+                initial_value: 723.into(),
+                exponent:      1,
+            }
+        }
+
         pub(crate) fn claim(&self) -> Claim {
             Claim {
                 index:    self.index,
@@ -97,15 +110,56 @@ pub(crate) mod tests {
     }
 
     impl Claim {
-        pub(crate) fn seed(&self) -> Vec<u8> {
+        fn trace_length(&self) -> usize {
+            (self.index + 1).next_power_of_two()
+        }
+
+        fn transition_constraints(&self) -> Vec<RationalExpression> {
+            use RationalExpression::*;
+            let trace_length = self.trace_length();
+            let g = Constant(FieldElement::root(trace_length).unwrap());
+            let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
+            vec![
+                (Trace(0, 1) - Trace(1, 0).pow(self.exponent)) * every_row(),
+                (Trace(1, 1) - Trace(0, 0) - Trace(1, 0)) * every_row(),
+            ]
+        }
+
+        /// The seed of the `StarkWare` fibonacci example.
+        ///
+        /// It omits the exponent when it is one and only exists to reproduce
+        /// their proofs, use [`Verifiable::constraints`] otherwise.
+        pub(crate) fn legacy_seed(&self) -> Vec<u8> {
             let mut seed = self.index.to_be_bytes().to_vec();
             seed.extend_from_slice(&self.value.as_montgomery().to_bytes_be());
-            // For backwards compatibility, don't include exponent in seed when it's 1.
             if self.exponent != 1 {
                 seed.extend_from_slice(&self.exponent.to_be_bytes());
             }
             seed
         }
+
+        /// Constraints compatible with the `StarkWare` fibonacci example.
+        pub(crate) fn legacy_constraints(&self) -> Constraints {
+            use RationalExpression::*;
+            let trace_length = self.trace_length();
+            let g = Constant(FieldElement::root(trace_length).unwrap());
+            let on_row = |index| (X - g.pow(index)).inv();
+            let mut expressions = self.transition_constraints();
+            expressions.push((Trace(0, 0) - 1.into()) * on_row(trace_length));
+            expressions.push((Trace(0, 0) - (&self.value).into()) * on_row(self.index));
+            Constraints::from_expressions((trace_length, 2), self.legacy_seed(), expressions)
+                .unwrap()
+        }
+    }
+
+    /// Constraints and a valid proof for [`Recurrance::example`].
+    pub(crate) fn example_proof() -> (Constraints, Proof) {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let constraints = claim.constraints();
+        let trace = claim.trace(&recurrance.witness());
+        let proof = prove(&constraints, &trace).unwrap();
+        (constraints, proof)
     }
 
     impl Arbitrary for Recurrance {
@@ -121,23 +175,12 @@ pub(crate) mod tests {
 
     impl Verifiable for Claim {
         fn constraints(&self) -> Constraints {
-            use RationalExpression::*;
-
-            // Constraint repetitions
-            let trace_length = (self.index + 1).next_power_of_two();
-            let trace_generator = FieldElement::root(trace_length).unwrap();
-            let g = Constant(trace_generator);
-            let on_row = |index| (X - g.pow(index)).inv();
-            let every_row = || (X - g.pow(trace_length - 1)) / (X.pow(trace_length) - 1.into());
-
-            // Constraints
-            Constraints::from_expressions((trace_length, 2), self.seed(), vec![
-                (Trace(0, 1) - Trace(1, 0).pow(self.exponent)) * every_row(),
-                (Trace(1, 1) - Trace(0, 0) - Trace(1, 0)) * every_row(),
-                (Trace(0, 0) - 1.into()) * on_row(trace_length),
-                (Trace(0, 0) - (&self.value).into()) * on_row(self.index),
-            ])
-            .unwrap()
+            let mut boundary = BoundaryConstraints::new((self.trace_length(), 2)).unwrap();
+            boundary.add(0, 0, 1.into()).unwrap();
+            boundary.add(0, self.index, self.value.clone()).unwrap();
+            boundary.add_public(&(self.exponent as u64).to_be_bytes());
+            Constraints::from_boundary_constraints(&boundary, self.transition_constraints())
+                .unwrap()
         }
     }
 
@@ -214,19 +257,6 @@ pub(crate) mod tests {
     }
 
     impl Claim2 {
-        fn seed(&self) -> Vec<u8> {
-            let mut seed = self.index.to_be_bytes().to_vec();
-            for coefficient in &self.coefficients {
-                seed.extend_from_slice(&coefficient.as_montgomery().to_bytes_be());
-            }
-            for exponent in &self.exponents {
-                seed.extend_from_slice(&exponent.to_be_bytes());
-            }
-            seed.extend_from_slice(&self.index.to_be_bytes());
-            seed.extend_from_slice(&self.value.as_montgomery().to_bytes_be());
-            seed
-        }
-
         fn trace_length(&self) -> usize {
             (self.index + 1).next_power_of_two()
         }
@@ -257,11 +287,6 @@ pub(crate) mod tests {
                 FieldElement::root(trace_length).expect("trace length is not power of two"),
             );
 
-            let on_row = |index| (X - trace_generator.pow(index)).inv();
-
-            let mut constraints: Vec<RationalExpression> =
-                vec![(Trace(0, 0) - (&self.value).into()) * on_row(self.index - 1)];
-
             let mut recurrance_constraint = Constant(FieldElement::ZERO);
             for (i, (coefficient, exponent)) in
                 self.coefficients.iter().zip(&self.exponents).enumerate()
@@ -276,9 +301,16 @@ pub(crate) mod tests {
                 recurrance_constraint =
                     recurrance_constraint * (X - trace_generator.pow(i + 1).inv());
             }
-            constraints.push(recurrance_constraint);
 
-            Constraints::from_expressions((trace_length, 1), self.seed(), constraints).unwrap()
+            let mut boundary = BoundaryConstraints::new((trace_length, 1)).unwrap();
+            boundary.add(0, self.index - 1, self.value.clone()).unwrap();
+            for coefficient in &self.coefficients {
+                boundary.add_public(&U256::from(coefficient).to_bytes_be());
+            }
+            for exponent in &self.exponents {
+                boundary.add_public(&(*exponent as u64).to_be_bytes());
+            }
+            Constraints::from_boundary_constraints(&boundary, vec![recurrance_constraint]).unwrap()
         }
     }
 
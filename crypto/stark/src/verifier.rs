@@ -1,28 +1,61 @@
 use crate::{
-    channel::*, constraints::Constraints, polynomial::DensePolynomial, proof_of_work, Proof,
+    channel::*, constraints::Constraints, polynomial::DensePolynomial, proof_of_work,
+    simplify::ExpressionGraph, Proof,
 };
 #[cfg(feature = "std")]
 use std::error;
 use std::{collections::BTreeMap, fmt, prelude::v1::*};
 use zkp_hash::Hash;
 use zkp_merkle_tree::{Commitment, Error as MerkleError, Proof as MerkleProof};
-use zkp_primefield::{fft, geometric_series::root_series, FieldElement};
+use zkp_primefield::{fft, FieldElement};
 use zkp_u256::U256;
 
 type Result<T> = std::result::Result<T, Error>;
 
-// TODO - We could parametrize root unavailable with the size asked for and fri
-// error with which layer failed.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Error {
-    RootUnavailable,
+    /// The field has no root of unity of the requested order.
+    RootUnavailable(usize),
     InvalidPoW,
-    InvalidLDECommitment,
-    InvalidConstraintCommitment,
-    InvalidFriCommitment,
-    HashMapFailure,
+    /// The coset offset is zero or lies in the evaluation domain.
+    InvalidCosetOffset,
+    /// `Constraints::num_oods_points` is zero.
+    NoOodsPoints,
+    /// The trace decommitment does not match the committed root. `actual`
+    /// is `None` if the decommitment is malformed and no root can be
+    /// computed from it.
+    InvalidLDECommitment {
+        expected: Hash,
+        actual:   Option<Hash>,
+    },
+    InvalidConstraintCommitment {
+        expected: Hash,
+        actual:   Option<Hash>,
+    },
+    InvalidFriCommitment {
+        layer:    usize,
+        expected: Hash,
+        actual:   Option<Hash>,
+    },
+    HashMapFailure {
+        layer: usize,
+        index: usize,
+    },
+    /// The proof has bytes left after all values were read.
     ProofTooLong,
-    OodsCalculationFailure,
+    /// The proof ends at `offset` before all values were read.
+    ProofTruncated {
+        offset: usize,
+    },
+    /// Reading the proof from the source failed at `offset`.
+    Io {
+        offset: usize,
+        error:  ReadError,
+    },
+    /// The folded value for `query` does not match the last FRI layer.
+    OodsCalculationFailure {
+        query: usize,
+    },
     OodsMismatch,
     FriCalculationFailure,
     Merkle(MerkleError),
@@ -31,23 +64,58 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Error::*;
-        match *self {
-            RootUnavailable => write!(f, "The prime field doesn't have a root of this order"),
+        match self {
+            RootUnavailable(order) => {
+                write!(f, "The prime field doesn't have a root of order {}", order)
+            }
             InvalidPoW => write!(f, "The suggested proof of work failed to verify"),
-            InvalidLDECommitment => write!(f, "The LDE merkle proof is incorrect"),
-            InvalidConstraintCommitment => write!(f, "The constraint merkle proof is incorrect"),
-            InvalidFriCommitment => write!(f, "A FRI layer commitment is incorrect"),
-            HashMapFailure => {
+            InvalidCosetOffset => {
+                write!(
+                    f,
+                    "The coset offset must be non-zero and not in the evaluation domain"
+                )
+            }
+            NoOodsPoints => write!(f, "At least one out-of-domain point is required"),
+            InvalidLDECommitment { expected, actual } => {
+                write!(f, "The LDE merkle proof is incorrect: ")?;
+                write_root_mismatch(f, expected, actual.as_ref())
+            }
+            InvalidConstraintCommitment { expected, actual } => {
+                write!(f, "The constraint merkle proof is incorrect: ")?;
+                write_root_mismatch(f, expected, actual.as_ref())
+            }
+            InvalidFriCommitment {
+                layer,
+                expected,
+                actual,
+            } => {
+                write!(f, "The FRI layer {} commitment is incorrect: ", layer)?;
+                write_root_mismatch(f, expected, actual.as_ref())
+            }
+            HashMapFailure { layer, index } => {
                 write!(
                     f,
-                    "Verifier attempted to look up an empty entry in the hash map"
+                    "Verifier attempted to look up an empty entry in the hash map for FRI layer \
+                     {} index {}",
+                    layer, index
                 )
             }
             ProofTooLong => write!(f, "The proof length doesn't match the specification"),
-            OodsCalculationFailure => {
+            ProofTruncated { offset } => {
+                write!(
+                    f,
+                    "The proof ends at byte {} before all values were read",
+                    offset
+                )
+            }
+            Io { offset, error } => {
+                write!(f, "Reading the proof failed at byte {}: {}", offset, error)
+            }
+            OodsCalculationFailure { query } => {
                 write!(
                     f,
-                    "The calculated odds value doesn't match the committed one"
+                    "The calculated odds value doesn't match the committed one for query {}",
+                    query
                 )
             }
             FriCalculationFailure => {
@@ -58,11 +126,22 @@ impl fmt::Display for Error {
             }
             OodsMismatch => write!(f, "Calculated oods value doesn't match the committed one"),
             // This is a wrapper, so defer to the underlying types' implementation of `fmt`.
-            Merkle(ref e) => std::fmt::Display::fmt(e, f),
+            Merkle(e) => std::fmt::Display::fmt(e, f),
         }
     }
 }
 
+fn write_root_mismatch(
+    f: &mut fmt::Formatter<'_>,
+    expected: &Hash,
+    actual: Option<&Hash>,
+) -> fmt::Result {
+    match actual {
+        Some(actual) => write!(f, "expected root {}, computed {}", expected, actual),
+        None => write!(f, "expected root {}, decommitment is malformed", expected),
+    }
+}
+
 impl From<MerkleError> for Error {
     fn from(err: MerkleError) -> Self {
         Self::Merkle(err)
@@ -90,8 +169,10 @@ impl From<MerkleError> for Error {
 /// * Read the deep values of the trace polynomials $T_i(z)$ ,$T_i(\omega \cdot
 /// z)$.
 /// * Read the deep values of the combined constraint polynomial
-/// $A_i(z^\mathrm{d})$ Draw the coefficients for the final combination
-/// $\alpha_i$, $\beta_i$ and $\gamma_i$.
+///   $A_i(z^\mathrm{d})$.
+/// * Repeat for each of the `num_oods_points` deep points.
+/// * Draw the coefficients for the final combination $\alpha_i$, $\beta_i$ and
+///   $\gamma_i$ for each deep point.
 /// * Read the final polynomial commitment.
 /// * Draw the FRI folding coefficient.
 /// * Repeatedly read the FRI layer commitments and folding coefficients.
@@ -158,6 +239,9 @@ impl From<MerkleError> for Error {
 /// \right) + \sum_i \gamma_i \cdot A_i'(x_j)
 /// $$
 ///
+/// With multiple deep points, step 5 is done for each point and the sum in
+/// step 6 runs over all of them.
+///
 /// ### Step 7: Verify FRI proof
 ///
 /// * Draw coeffient
@@ -167,22 +251,46 @@ impl From<MerkleError> for Error {
 /// * Evaluate the final layer
 ///
 /// <!-- TODO: ellaborate FRI verification -->
+pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
+    verify_stream(constraints, proof.as_bytes())
+}
+
+/// Like [`verify`], but reads the proof incrementally from `source`.
+///
+/// Memory use is bounded by the number of queries and the size of the last
+/// FRI layer, not by the size of the proof or the evaluation domain. This
+/// allows verifying proofs from flash or a network connection in chunks.
 // TODO: Refactor into smaller function
 #[allow(clippy::too_many_lines)]
-pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
-    let proof = proof.as_bytes();
+pub fn verify_stream<S: ProofSource>(constraints: &Constraints, source: S) -> Result<()> {
     let trace_length = constraints.trace_nrows();
     let trace_cols = constraints.trace_ncolumns();
+    // In zero-knowledge mode the trace and constraint commitments are hiding
+    // and the constraint leaves carry the value of a masking polynomial.
+    let zero_knowledge = usize::from(constraints.zero_knowledge);
     let eval_domain_size = trace_length * constraints.blowup;
-    let eval_x = root_series(eval_domain_size).collect::<Vec<_>>();
+    let omega =
+        FieldElement::root(eval_domain_size).ok_or(Error::RootUnavailable(eval_domain_size))?;
+
+    // The evaluation coset must be disjoint from the evaluation domain.
+    if constraints.coset_offset == FieldElement::ZERO
+        || constraints.coset_offset.pow(eval_domain_size) == FieldElement::ONE
+    {
+        return Err(Error::InvalidCosetOffset);
+    }
+
+    if constraints.num_oods_points == 0 {
+        return Err(Error::NoOodsPoints);
+    }
 
-    let mut channel = VerifierChannel::new(proof.to_vec());
-    channel.initialize(constraints.channel_seed());
+    let mut channel = VerifierChannel::new(source);
+    channel.initialize(&constraints.transcript_seed(), constraints.hash_function);
 
     // Get the low degree root commitment, and constraint root commitment
     // TODO: Make it work as channel.read()
     let low_degree_extension_root = Replayable::<Hash>::replay(&mut channel);
-    let lde_commitment = Commitment::from_size_hash(eval_domain_size, &low_degree_extension_root)?;
+    let lde_commitment = Commitment::from_size_hash(eval_domain_size, &low_degree_extension_root)?
+        .with_hash_function(constraints.hash_function);
     let mut constraint_coefficients: Vec<FieldElement> =
         Vec::with_capacity(constraints.trace_arguments().len());
     for _ in 0..constraints.len() {
@@ -191,18 +299,24 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
     }
     let constraint_evaluated_root = Replayable::<Hash>::replay(&mut channel);
     let constraint_commitment =
-        Commitment::from_size_hash(eval_domain_size, &constraint_evaluated_root)?;
+        Commitment::from_size_hash(eval_domain_size, &constraint_evaluated_root)?
+            .with_hash_function(constraints.hash_function);
 
     // Get the oods information from the proof and random
-    let oods_point: FieldElement = channel.get_random();
     let constraints_trace_degree = constraints.degree().next_power_of_two();
+    let oods_values_per_point = constraints.trace_arguments().len() + constraints_trace_degree;
+    let mut oods_points: Vec<FieldElement> = Vec::with_capacity(constraints.num_oods_points);
     let mut oods_values: Vec<FieldElement> =
-        Vec::with_capacity(constraints.trace_arguments().len() + constraints_trace_degree);
-    for _ in 0..(constraints.trace_arguments().len() + constraints_trace_degree) {
-        oods_values.push(Replayable::<FieldElement>::replay(&mut channel));
+        Vec::with_capacity(constraints.num_oods_points * oods_values_per_point);
+    for _ in 0..constraints.num_oods_points {
+        oods_points.push(channel.get_random());
+        for _ in 0..oods_values_per_point {
+            oods_values.push(Replayable::<FieldElement>::replay(&mut channel));
+        }
     }
-    let mut oods_coefficients: Vec<FieldElement> = Vec::with_capacity(oods_values.len());
-    for _ in &oods_values {
+    let mut oods_coefficients: Vec<FieldElement> =
+        Vec::with_capacity(oods_values.len() + zero_knowledge);
+    for _ in 0..(oods_values.len() + zero_knowledge) {
         oods_coefficients.push(channel.get_random());
     }
 
@@ -210,10 +324,10 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
     let mut eval_points: Vec<FieldElement> = Vec::with_capacity(constraints.fri_layout.len() + 1);
     let mut fri_size = eval_domain_size >> constraints.fri_layout[0];
     // Get first fri root:
-    fri_commitments.push(Commitment::from_size_hash(
-        fri_size,
-        &Replayable::<Hash>::replay(&mut channel),
-    )?);
+    fri_commitments.push(
+        Commitment::from_size_hash(fri_size, &Replayable::<Hash>::replay(&mut channel))?
+            .with_hash_function(constraints.hash_function),
+    );
     // Get fri roots and eval points from the channel random
     for &x in constraints.fri_layout.iter().skip(1) {
         fri_size >>= x;
@@ -224,20 +338,21 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
             channel.get_random()
         };
         eval_points.push(eval_point);
-        fri_commitments.push(Commitment::from_size_hash(
-            fri_size,
-            &Replayable::<Hash>::replay(&mut channel),
-        )?);
+        fri_commitments.push(
+            Commitment::from_size_hash(fri_size, &Replayable::<Hash>::replay(&mut channel))?
+                .with_hash_function(constraints.hash_function),
+        );
     }
     // Gets the last layer and the polynomial coefficients
     eval_points.push(channel.get_random());
     let last_layer_coefficient: Vec<FieldElement> =
-        Replayable::<FieldElement>::replay_many(&mut channel, fri_size / constraints.blowup);
+        Replayable::<FieldElement>::replay_many(&mut channel, fri_size / constraints.lde_blowup());
 
     // Gets the proof of work from the proof.
     let pow_seed: proof_of_work::ChallengeSeed = channel.get_random();
     let pow_challenge = pow_seed.with_difficulty(constraints.pow_bits);
     let pow_response = Replayable::<proof_of_work::Response>::replay(&mut channel);
+    require_complete(&channel)?;
     if !pow_challenge.verify(pow_response) {
         return Err(Error::InvalidPoW);
     }
@@ -259,10 +374,21 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
         .collect();
     let lde_proof_length = lde_commitment.proof_size(&queries)?;
     let lde_hashes = Replayable::<Hash>::replay_many(&mut channel, lde_proof_length);
-    let lde_proof = MerkleProof::from_hashes(&lde_commitment, &queries, &lde_hashes)?;
+    let lde_proof = replay_proof(
+        &mut channel,
+        &lde_commitment,
+        &queries,
+        &lde_hashes,
+        constraints,
+    );
+    require_complete(&channel)?;
     // Note - we could express this a merkle error instead but this adds specificity
-    if lde_proof.verify(&lde_values).is_err() {
-        return Err(Error::InvalidLDECommitment);
+    let lde_root = lde_proof.and_then(|proof| Ok(proof.root(&lde_values)?)).ok();
+    if lde_root.as_ref() != Some(&low_degree_extension_root) {
+        return Err(Error::InvalidLDECommitment {
+            expected: low_degree_extension_root,
+            actual:   lde_root,
+        });
     }
 
     // Gets the values and checks the constraint decommitment
@@ -270,17 +396,32 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
     for query_index in &queries {
         constraint_values.push((
             *query_index,
-            Replayable::<FieldElement>::replay_many(&mut channel, constraints_trace_degree),
+            Replayable::<FieldElement>::replay_many(
+                &mut channel,
+                constraints_trace_degree + zero_knowledge,
+            ),
         ));
     }
     let constraint_proof_length = constraint_commitment.proof_size(&queries)?;
     let constraint_hashes: Vec<Hash> =
         Replayable::<Hash>::replay_many(&mut channel, constraint_proof_length);
-    let constraint_proof =
-        MerkleProof::from_hashes(&constraint_commitment, &queries, &constraint_hashes)?;
+    let constraint_proof = replay_proof(
+        &mut channel,
+        &constraint_commitment,
+        &queries,
+        &constraint_hashes,
+        constraints,
+    );
+    require_complete(&channel)?;
     // Note - we could express this a merkle error instead but this adds specificity
-    if constraint_proof.verify(&constraint_values).is_err() {
-        return Err(Error::InvalidConstraintCommitment);
+    let constraint_root = constraint_proof
+        .and_then(|proof| Ok(proof.root(&constraint_values)?))
+        .ok();
+    if constraint_root.as_ref() != Some(&constraint_evaluated_root) {
+        return Err(Error::InvalidConstraintCommitment {
+            expected: constraint_evaluated_root,
+            actual:   constraint_root,
+        });
     }
 
     let coset_sizes = constraints
@@ -312,21 +453,34 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
                     if k > 0 {
                         coset.push(match fri_folds.get(&n) {
                             Some(x) => x.clone(),
-                            None => return Err(Error::HashMapFailure),
+                            None => return Err(Error::HashMapFailure { layer: k, index: n }),
                         });
                     } else {
                         let z_reverse = fft::permute_index(eval_domain_size, queries[z]);
-                        coset.push(out_of_domain_element(
-                            lde_values[z].1.as_slice(),
-                            &constraint_values[z].1,
-                            &eval_x[z_reverse],
-                            &oods_point,
-                            oods_values.as_slice(),
-                            oods_coefficients.as_slice(),
-                            eval_domain_size,
-                            constraints.blowup,
-                            &constraints.trace_arguments(),
-                        )?);
+                        let mut value = FieldElement::ZERO;
+                        for ((oods_point, values), coefficients) in oods_points
+                            .iter()
+                            .zip(oods_values.chunks(oods_values_per_point))
+                            .zip(oods_coefficients.chunks(oods_values_per_point))
+                        {
+                            value += out_of_domain_element(
+                                lde_values[z].1.as_slice(),
+                                &constraint_values[z].1[..constraints_trace_degree],
+                                &(omega.pow(z_reverse) * &constraints.coset_offset),
+                                oods_point,
+                                values,
+                                coefficients,
+                                eval_domain_size,
+                                constraints.blowup,
+                                &constraints.trace_arguments(),
+                            )?;
+                        }
+                        if constraints.zero_knowledge {
+                            // Add the opened value of the masking polynomial.
+                            value += &oods_coefficients[oods_values.len()]
+                                * &constraint_values[z].1[constraints_trace_degree];
+                        }
+                        coset.push(value);
                     }
                 } else {
                     coset.push(Replayable::<FieldElement>::replay(&mut channel));
@@ -345,14 +499,15 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
                     step,
                     (coset_sizes[k] / 2) * i,
                     len,
-                    eval_x.as_slice(),
+                    &omega,
                 ),
             );
         }
 
         let merkle_proof_length = commitment.proof_size(&fri_indices)?;
         let merkle_hashes = Replayable::<Hash>::replay_many(&mut channel, merkle_proof_length);
-        let merkle_proof = MerkleProof::from_hashes(commitment, &fri_indices, &merkle_hashes)?;
+        require_complete(&channel)?;
+        let merkle_proof = MerkleProof::from_hashes(commitment, &fri_indices, &merkle_hashes);
         fri_folds = layer_folds;
 
         for _ in 0..constraints.fri_layout[k] {
@@ -361,8 +516,15 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
         len /= coset_sizes[k];
 
         // Note - we could express this a merkle error instead but this adds specificity
-        if merkle_proof.verify(&fri_layer_values).is_err() {
-            return Err(Error::InvalidFriCommitment);
+        let fri_root = merkle_proof
+            .and_then(|proof| proof.root(&fri_layer_values))
+            .ok();
+        if fri_root.as_ref() != Some(commitment.hash()) {
+            return Err(Error::InvalidFriCommitment {
+                layer:    k,
+                expected: commitment.hash().clone(),
+                actual:   fri_root,
+            });
         };
 
         previous_indices = fri_indices.clone();
@@ -373,7 +535,9 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
                 .collect();
         }
     }
-    if !channel.at_end() {
+    let at_end = channel.at_end();
+    require_complete(&channel)?;
+    if !at_end {
         return Err(Error::ProofTooLong);
     }
 
@@ -381,7 +545,7 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
     // the decommited polynomial.
     let interp_root = match FieldElement::root(len) {
         Some(x) => x,
-        None => return Err(Error::RootUnavailable),
+        None => return Err(Error::RootUnavailable(len)),
     };
     for key in &previous_indices {
         let calculated = fri_folds[key].clone();
@@ -389,29 +553,38 @@ pub fn verify(constraints: &Constraints, proof: &Proof) -> Result<()> {
         let committed = DensePolynomial::new(&last_layer_coefficient).evaluate(&x_pow);
 
         if committed != calculated.clone() {
-            return Err(Error::OodsCalculationFailure);
+            // Report the first query that folds into this position
+            let reductions: usize = constraints.fri_layout.iter().sum();
+            let query = queries
+                .iter()
+                .find(|&query| query >> reductions == *key)
+                .copied()
+                .unwrap_or(*key);
+            return Err(Error::OodsCalculationFailure { query });
         }
     }
 
     let trace_arguments = constraints.trace_arguments();
+    for (oods_point, values) in oods_points
+        .iter()
+        .zip(oods_values.chunks(oods_values_per_point))
+    {
+        let (trace_values, constraint_values) = values.split_at(trace_arguments.len());
 
-    let (trace_values, constraint_values) = oods_values.split_at(trace_arguments.len());
-
-    assert_eq!(trace_values.len(), trace_arguments.len());
-
-    let mut trace_map = BTreeMap::new();
-    for (argument, value) in trace_arguments.iter().zip(trace_values) {
-        let _ = trace_map.insert(*argument, value.clone());
-    }
+        let mut trace_map = BTreeMap::new();
+        for (argument, value) in trace_arguments.iter().zip(trace_values) {
+            let _ = trace_map.insert(*argument, value.clone());
+        }
 
-    if oods_value_from_trace_values(
-        &constraints,
-        &constraint_coefficients,
-        &trace_map,
-        &oods_point,
-    ) != oods_value_from_constraint_values(&constraint_values, &oods_point)
-    {
-        return Err(Error::OodsMismatch);
+        if oods_value_from_trace_values(
+            constraints,
+            &constraint_coefficients,
+            &trace_map,
+            oods_point,
+        ) != oods_value_from_constraint_values(constraint_values, oods_point)
+        {
+            return Err(Error::OodsMismatch);
+        }
     }
     Ok(())
 }
@@ -423,9 +596,10 @@ fn oods_value_from_trace_values(
     oods_point: &FieldElement,
 ) -> FieldElement {
     let trace = |i: usize, j: isize| trace_values.get(&(i, j)).unwrap().clone();
-    constraints
-        .combine(coefficients)
-        .evaluate(oods_point, &trace)
+    // The constraints share many subexpressions, evaluate them only once.
+    let mut graph = ExpressionGraph::default();
+    let root = graph.insert(&constraints.combine(coefficients));
+    graph.evaluate(root, oods_point, &trace)
 }
 
 fn oods_value_from_constraint_values(
@@ -443,7 +617,7 @@ fn oods_value_from_constraint_values(
 
 // TODO: Clean up
 #[allow(clippy::cast_possible_truncation)]
-fn get_indices(num: usize, bits: u32, proof: &mut VerifierChannel) -> Vec<usize> {
+pub(crate) fn get_indices<S>(num: usize, bits: u32, proof: &mut VerifierChannel<S>) -> Vec<usize> {
     let mut query_indices = Vec::with_capacity(num + 3);
     while query_indices.len() < num {
         let val: U256 = proof.get_random();
@@ -463,7 +637,7 @@ fn fri_fold(
     mut step: usize,
     mut index: usize,
     mut len: usize,
-    eval_x: &[FieldElement],
+    omega: &FieldElement,
 ) -> FieldElement {
     let mut mutable_eval_copy = eval_point.clone();
     let mut coset_full: Vec<FieldElement> = coset.to_vec();
@@ -471,8 +645,8 @@ fn fri_fold(
         let mut next_coset = Vec::with_capacity(coset.len() / 2);
 
         for (k, pair) in coset_full.chunks(2).enumerate() {
-            let x = &eval_x[fft::permute_index(len / 2, index + k) * step];
-            next_coset.push(fri_single_fold(&pair[0], &pair[1], x, &mutable_eval_copy));
+            let x = omega.pow(fft::permute_index(len / 2, index + k) * step);
+            next_coset.push(fri_single_fold(&pair[0], &pair[1], &x, &mutable_eval_copy));
         }
         len /= 2;
         index /= 2;
@@ -492,11 +666,40 @@ fn fri_single_fold(
     (poly_at_x + poly_at_neg_x) + eval_point / x * (poly_at_x - poly_at_neg_x)
 }
 
+/// Fails if reading the proof failed or a value was read past its end.
+fn require_complete<S>(channel: &VerifierChannel<S>) -> Result<()> {
+    if let Some((offset, error)) = channel.read_error() {
+        return Err(Error::Io { offset, error });
+    }
+    match channel.truncated() {
+        Some(offset) => Err(Error::ProofTruncated { offset }),
+        None => Ok(()),
+    }
+}
+
+/// Merkle proof for the trace or constraint commitment, reading the salts in
+/// zero-knowledge mode.
+fn replay_proof<S: ProofSource>(
+    channel: &mut VerifierChannel<S>,
+    commitment: &Commitment,
+    queries: &[usize],
+    hashes: &[Hash],
+    constraints: &Constraints,
+) -> Result<MerkleProof> {
+    Ok(if constraints.zero_knowledge {
+        let salts =
+            Replayable::<Hash>::replay_many(channel, commitment.sort_indices(queries)?.len());
+        MerkleProof::from_hashes_salts(commitment, queries, hashes, &salts)?
+    } else {
+        MerkleProof::from_hashes(commitment, queries, hashes)?
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn out_of_domain_element(
     poly_points_u: &[U256],
     constraint_oods_values: &[FieldElement],
-    x_cord: &FieldElement,
+    x_transform: &FieldElement,
     oods_point: &FieldElement,
     oods_values: &[FieldElement],
     oods_coefficients: &[FieldElement],
@@ -508,10 +711,9 @@ fn out_of_domain_element(
         .iter()
         .map(|i| FieldElement::from_montgomery(i.clone()))
         .collect();
-    let x_transform = x_cord * FieldElement::GENERATOR;
     let omega = match FieldElement::root(eval_domain_size) {
         Some(x) => x,
-        None => return Err(Error::RootUnavailable),
+        None => return Err(Error::RootUnavailable(eval_domain_size)),
     };
     let g = omega.pow(blowup);
     let mut r = FieldElement::ZERO;
@@ -521,13 +723,13 @@ fn out_of_domain_element(
         .zip(oods_values)
         .zip(trace_arguments)
     {
-        r += coefficient * (&poly_points[*i] - value) / (&x_transform - g.pow(*j) * oods_point);
+        r += coefficient * (&poly_points[*i] - value) / (x_transform - g.pow(*j) * oods_point);
     }
 
     for (i, constraint_oods_value) in constraint_oods_values.iter().enumerate() {
         r += &oods_coefficients[trace_arguments.len() + i]
             * (constraint_oods_value - &oods_values[trace_arguments.len() + i])
-            / (&x_transform - oods_point.pow(constraint_oods_values.len()));
+            / (x_transform - oods_point.pow(constraint_oods_values.len()));
     }
     Ok(r)
 }
@@ -548,7 +750,7 @@ mod tests {
     use crate::{
         prove,
         traits::tests::{Recurrance, Recurrance2},
-        Provable, Verifiable,
+        ProofSections, Provable, Verifiable,
     };
     use quickcheck_macros::quickcheck;
 
@@ -575,4 +777,171 @@ mod tests {
 
         verify(&constraints, &prove(&constraints, &trace).unwrap()).is_ok()
     }
+
+    #[test]
+    fn verify_coset_offset() {
+        let recurrance = Recurrance {
+            exponent: 3,
+            ..Recurrance::example()
+        };
+        let claim = recurrance.claim();
+        let mut constraints = claim.constraints();
+        constraints.coset_offset = 7.into();
+        let proof = prove(&constraints, &claim.trace(&recurrance.witness())).unwrap();
+        assert_eq!(verify(&constraints, &proof), Ok(()));
+
+        // A proof for one coset does not verify on another
+        for offset in &[FieldElement::GENERATOR, 5.into(), 11.into()] {
+            constraints.coset_offset = offset.clone();
+            assert!(verify(&constraints, &proof).is_err());
+        }
+
+        // The coset must not overlap the evaluation domain
+        constraints.coset_offset = FieldElement::root(32 * constraints.blowup).unwrap();
+        assert_eq!(verify(&constraints, &proof), Err(Error::InvalidCosetOffset));
+    }
+
+    #[test]
+    fn verify_reports_fri_layer() {
+        // Find a proof that has hashes in its second FRI decommitment
+        let (constraints, mut sections) = (0..)
+            .map(|i| {
+                let recurrance = Recurrance {
+                    initial_value: (723 + i).into(),
+                    ..Recurrance::example()
+                };
+                let claim = recurrance.claim();
+                let constraints = claim.constraints();
+                let proof = prove(&constraints, &claim.trace(&recurrance.witness())).unwrap();
+                let sections = ProofSections::from_proof(&constraints, &proof).unwrap();
+                (constraints, sections)
+            })
+            .find(|(_, sections)| !sections.fri_decommitments[1].hashes.is_empty())
+            .unwrap();
+        sections.fri_decommitments[1].hashes[0] = Hash::new([0; 32]);
+        match verify(&constraints, &sections.to_proof()) {
+            Err(Error::InvalidFriCommitment {
+                layer, expected, ..
+            }) => {
+                assert_eq!(layer, 1);
+                assert_eq!(expected, sections.fri_roots[1]);
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn verify_reports_malformed_trace_decommitment() {
+        // Find a proof that opens the same trace row for two queries
+        let (constraints, mut sections) = (1..)
+            .map(|i| {
+                let recurrance = Recurrance {
+                    initial_value: (723 + i).into(),
+                    ..Recurrance::example()
+                };
+                let claim = recurrance.claim();
+                let constraints = claim.constraints();
+                let proof = prove(&constraints, &claim.trace(&recurrance.witness())).unwrap();
+                let sections = ProofSections::from_proof(&constraints, &proof).unwrap();
+                (constraints, sections)
+            })
+            .find(|(_, sections)| {
+                let indices = &sections.trace_decommitment.indices;
+                (1..indices.len()).any(|i| indices[..i].contains(&indices[i]))
+            })
+            .unwrap();
+
+        // Opening it with two different values makes the decommitment malformed
+        let indices = sections.trace_decommitment.indices.clone();
+        let duplicate = (1..indices.len())
+            .find(|&i| indices[..i].contains(&indices[i]))
+            .unwrap();
+        let value = &mut sections.trace_decommitment.values[duplicate][0];
+        *value = value.clone() + FieldElement::ONE;
+        assert_eq!(
+            verify(&constraints, &sections.to_proof()),
+            Err(Error::InvalidLDECommitment {
+                expected: sections.trace_commitment,
+                actual:   None,
+            })
+        );
+    }
+
+    /// Reader that returns at most a few bytes at a time and is interrupted
+    /// once.
+    struct Chunked<'a> {
+        bytes: &'a [u8],
+        calls: usize,
+    }
+
+    impl std::io::Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+            if self.calls == 3 {
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            let n = buf.len().min(self.bytes.len()).min(7);
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+
+    /// Fails once the bytes run out, instead of ending the proof.
+    struct Failing<'a>(&'a [u8]);
+
+    impl std::io::Read for Failing<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(std::io::ErrorKind::ConnectionReset.into());
+            }
+            let n = buf.len().min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn verify_stream_reader() {
+        let recurrance = Recurrance::example();
+        let claim = recurrance.claim();
+        let constraints = claim.constraints();
+        let proof = prove(&constraints, &claim.trace(&recurrance.witness())).unwrap();
+        let bytes = proof.as_bytes();
+        let reader = |bytes| Chunked { bytes, calls: 0 };
+        assert_eq!(verify_stream(&constraints, reader(bytes)), Ok(()));
+
+        // Truncation is reported at the end of the available bytes
+        for &len in &[0, 40, bytes.len() / 2, bytes.len() - 1] {
+            assert_eq!(
+                verify_stream(&constraints, reader(&bytes[..len])),
+                Err(Error::ProofTruncated { offset: len })
+            );
+            assert_eq!(
+                verify(&constraints, &Proof::from_bytes(bytes[..len].to_vec())),
+                Err(Error::ProofTruncated { offset: len })
+            );
+        }
+
+        // Trailing bytes
+        let mut long = bytes.to_vec();
+        long.push(0);
+        assert_eq!(
+            verify_stream(&constraints, reader(&long)),
+            Err(Error::ProofTooLong)
+        );
+
+        // Read errors are reported where the failing value starts, also when
+        // checking for trailing bytes
+        for &(len, offset) in &[(40, 32), (bytes.len(), bytes.len())] {
+            assert_eq!(
+                verify_stream(&constraints, Failing(&bytes[..len])),
+                Err(Error::Io {
+                    offset,
+                    error: ReadError::Io(std::io::ErrorKind::ConnectionReset),
+                })
+            );
+        }
+    }
 }